@@ -102,7 +102,7 @@ use polymesh_common_utilities::{
     balances::Trait as BalancesTrait,
     compliance_manager::Trait as ComplianceManagerTrait,
     constants::*,
-    identity::Trait as IdentityTrait,
+    identity::{IdentityHoldings, Trait as IdentityTrait},
     protocol_fee::{ChargeProtocolFee, ProtocolOp},
     CommonTrait, Context,
 };
@@ -125,6 +125,7 @@ use frame_support::{
 use frame_system::{self as system, ensure_signed};
 use hex_literal::hex;
 use pallet_contracts::{ExecReturnValue, Gas};
+use sp_io::hashing::twox_128;
 use sp_runtime::traits::{CheckedAdd, CheckedSub, Verify};
 
 #[cfg(feature = "std")]
@@ -232,6 +233,17 @@ pub struct TickerRegistration<U> {
     pub link_id: u64,
 }
 
+/// struct to store the fee sponsorship of a ticker.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Debug)]
+pub struct FeeSponsorship<U> {
+    /// The identity whose balance pays the sponsored fees.
+    pub sponsor: IdentityId,
+    /// The remaining amount the sponsor is willing to pay.
+    pub budget: U,
+    /// The maximum amount sponsored for a single investor.
+    pub investor_cap: U,
+}
+
 /// struct to store the ticker registration config.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, Default, PartialEq, Debug)]
@@ -276,6 +288,11 @@ decl_storage! {
         /// Used to store the securityToken balance corresponds to ticker and Identity.
         /// (ticker, DID) -> Balance
         pub BalanceOf get(fn balance_of): double_map hasher(blake2_128_concat) Ticker, hasher(blake2_128_concat) IdentityId => T::Balance;
+        /// Tickers of the assets an identity has ever received. Balances of some of them may be zero.
+        /// (DID) -> [ticker]
+        pub AssetsHeld get(fn assets_held): map hasher(twox_64_concat) IdentityId => Vec<Ticker>;
+        /// Whether `AssetsHeld` has been filled in from the balances received before it existed.
+        pub AssetsHeldBackfilled get(fn assets_held_backfilled): bool;
         /// A map of pairs of a ticker name and an `IdentifierType` to asset identifiers.
         pub Identifiers get(fn identifiers): map hasher(blake2_128_concat) (Ticker, IdentifierType) => AssetIdentifier;
         /// (ticker, sender (DID), spender(DID)) -> allowance amount
@@ -316,6 +333,12 @@ decl_storage! {
         /// The set of frozen assets implemented as a membership map.
         /// ticker -> bool
         pub Frozen get(fn frozen): map hasher(blake2_128_concat) Ticker => bool;
+        /// Fee sponsorship of `transfer` and `claim` calls on a ticker.
+        /// ticker -> FeeSponsorship
+        pub FeeSponsorships get(fn fee_sponsorship): map hasher(blake2_128_concat) Ticker => Option<FeeSponsorship<T::Balance>>;
+        /// Fees already sponsored for an investor of a ticker.
+        /// (ticker, investor DID) -> sponsored amount
+        pub SponsoredFees get(fn sponsored_fees): double_map hasher(blake2_128_concat) Ticker, hasher(blake2_128_concat) IdentityId => T::Balance;
     }
 }
 
@@ -330,6 +353,10 @@ decl_module! {
         /// initialize the default event for this module
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() {
+            Self::backfill_assets_held();
+        }
+
         /// This function is used to either register a new ticker or extend validity of an existing ticker.
         /// NB: Ticker validity does not get carry forward when renewing ticker.
         ///
@@ -491,6 +518,7 @@ decl_module! {
             };
             <Tokens<T>>::insert(&ticker, token);
             <BalanceOf<T>>::insert(ticker, did, total_supply);
+            Self::track_holder(&ticker, did);
             Self::deposit_event(RawEvent::AssetCreated(
                 did,
                 ticker,
@@ -814,6 +842,7 @@ decl_module! {
             for i in 0..investor_dids.len() {
                 Self::_update_checkpoint(&ticker, investor_dids[i], current_balances[i]);
                 <BalanceOf<T>>::insert(ticker, investor_dids[i], updated_balances[i]);
+                Self::track_holder(&ticker, investor_dids[i]);
                 <statistics::Module<T>>::update_transfer_stats(&ticker, None, Some(updated_balances[i]), values[i]);
                 Self::deposit_event(RawEvent::Issued(
                     did,
@@ -1327,6 +1356,10 @@ decl_module! {
 
             // Verify the details of smart extension & store it
             ensure!(!<ExtensionDetails<T>>::contains_key((ticker, &extension_details.extension_id)), Error::<T>::ExtensionAlreadyPresent);
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee(
+                &Signatory::AccountKey(sender_key),
+                ProtocolOp::AssetAddExtension
+            )?;
             <ExtensionDetails<T>>::insert((ticker, &extension_details.extension_id), extension_details.clone());
             <Extensions<T>>::mutate((ticker, &extension_details.extension_type), |ids| {
                 ids.push(extension_details.extension_id.clone())
@@ -1382,6 +1415,51 @@ decl_module! {
             Self::deposit_event(RawEvent::ExtensionUnArchived(my_did, ticker, extension_id));
             Ok(())
         }
+
+        /// Sponsors the fees of investors calling `transfer` and `claim` on a ticker.
+        /// The fees are paid from the identity balance of the token owner. Replaces any
+        /// previous sponsorship while keeping the amounts already sponsored per investor.
+        ///
+        /// # Arguments
+        /// * `origin` - the signing key of the token owner.
+        /// * `ticker` - the ticker of the token.
+        /// * `budget` - the total amount of fees to sponsor.
+        /// * `investor_cap` - the maximum amount of fees to sponsor for a single investor.
+        #[weight = SimpleDispatchInfo::FixedNormal(150_000)]
+        pub fn set_fee_sponsorship(
+            origin,
+            ticker: Ticker,
+            budget: T::Balance,
+            investor_cap: T::Balance
+        ) -> DispatchResult {
+            let sender_key = AccountKey::try_from(ensure_signed(origin)?.encode())?;
+            let did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+            ensure!(Self::is_owner(&ticker, did), Error::<T>::NotAnOwner);
+            <FeeSponsorships<T>>::insert(ticker, FeeSponsorship {
+                sponsor: did,
+                budget,
+                investor_cap,
+            });
+            Self::deposit_event(RawEvent::FeeSponsorshipSet(did, ticker, budget, investor_cap));
+            Ok(())
+        }
+
+        /// Stops sponsoring fees on a ticker and forgets the amounts sponsored per investor.
+        ///
+        /// # Arguments
+        /// * `origin` - the signing key of the token owner.
+        /// * `ticker` - the ticker of the token.
+        #[weight = SimpleDispatchInfo::FixedNormal(150_000)]
+        pub fn remove_fee_sponsorship(origin, ticker: Ticker) -> DispatchResult {
+            let sender_key = AccountKey::try_from(ensure_signed(origin)?.encode())?;
+            let did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+            ensure!(Self::is_owner(&ticker, did), Error::<T>::NotAnOwner);
+            ensure!(<FeeSponsorships<T>>::contains_key(&ticker), Error::<T>::NoFeeSponsorship);
+            <FeeSponsorships<T>>::remove(&ticker);
+            <SponsoredFees<T>>::remove_prefix(&ticker);
+            Self::deposit_event(RawEvent::FeeSponsorshipRemoved(did, ticker));
+            Ok(())
+        }
     }
 }
 
@@ -1464,6 +1542,12 @@ decl_event! {
         /// Emitted event for Checkpoint creation.
         /// caller DID. ticker, checkpoint count.
         CheckpointCreated(IdentityId, Ticker, u64),
+        /// Fee sponsorship set.
+        /// caller DID, ticker, budget, investor cap.
+        FeeSponsorshipSet(IdentityId, Ticker, Balance, Balance),
+        /// Fee sponsorship removed.
+        /// caller DID, ticker.
+        FeeSponsorshipRemoved(IdentityId, Ticker),
     }
 }
 
@@ -1551,6 +1635,8 @@ decl_error! {
         AssetAlreadyDivisible,
         /// An invalid custodian DID.
         InvalidCustodianDid,
+        /// The ticker has no fee sponsorship.
+        NoFeeSponsorship,
     }
 }
 
@@ -1583,6 +1669,43 @@ impl<T: Trait> AssetTrait<T::Balance, T::AccountId> for Module<T> {
     }
 }
 
+impl<T: Trait> IdentityHoldings for Module<T> {
+    fn has_no_holdings(did: IdentityId) -> bool {
+        Self::assets_held(did)
+            .iter()
+            .all(|ticker| Self::balance_of(ticker, did) == 0.into())
+    }
+
+    fn ensure_can_move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult {
+        for ticker in Self::assets_held(from) {
+            let balance = Self::balance_of(&ticker, from);
+            if balance != 0.into() {
+                ensure!(!Self::frozen(&ticker), Error::<T>::InvalidTransfer);
+                ensure!(
+                    Self::check_granularity(&ticker, balance),
+                    Error::<T>::InvalidGranularity
+                );
+                Self::balance_of(&ticker, to)
+                    .checked_add(&balance)
+                    .ok_or(Error::<T>::BalanceOverflow)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult {
+        Self::ensure_can_move_holdings(from, to)?;
+        for ticker in Self::assets_held(from) {
+            let balance = Self::balance_of(&ticker, from);
+            if balance != 0.into() {
+                Self::_transfer(from, &ticker, from, to, balance)?;
+            }
+        }
+        <AssetsHeld>::remove(from);
+        Ok(())
+    }
+}
+
 impl<T: Trait> AcceptTransfer for Module<T> {
     fn accept_ticker_transfer(to_did: IdentityId, auth_id: u64) -> DispatchResult {
         Self::_accept_ticker_transfer(to_did, auth_id)
@@ -1606,6 +1729,25 @@ impl<T: Trait> Module<T> {
         token.owner_did == did
     }
 
+    /// Charges `fee` against the fee sponsorship of `ticker` on behalf of `investor`.
+    /// Returns the sponsor DID if neither the budget nor the investor cap is exceeded.
+    pub fn charge_sponsored_fee(
+        ticker: &Ticker,
+        investor: IdentityId,
+        fee: T::Balance,
+    ) -> Option<IdentityId> {
+        let mut sponsorship = Self::fee_sponsorship(ticker)?;
+        let sponsored = Self::sponsored_fees(ticker, &investor).checked_add(&fee)?;
+        if sponsored > sponsorship.investor_cap {
+            return None;
+        }
+        sponsorship.budget = sponsorship.budget.checked_sub(&fee)?;
+        let sponsor = sponsorship.sponsor;
+        <FeeSponsorships<T>>::insert(ticker, sponsorship);
+        <SponsoredFees<T>>::insert(ticker, &investor, sponsored);
+        Some(sponsor)
+    }
+
     pub fn is_ticker_available(ticker: &Ticker) -> bool {
         // Assumes uppercase ticker
         if <Tickers<T>>::contains_key(ticker) {
@@ -1860,6 +2002,7 @@ impl<T: Trait> Module<T> {
 
         // increase receiver's balance
         <BalanceOf<T>>::insert(ticker, &to_did, updated_to_balance);
+        Self::track_holder(ticker, to_did);
 
         // Update statistic info.
         <statistics::Module<T>>::update_transfer_stats(
@@ -1873,6 +2016,42 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Records `ticker` in the list of assets held by `did`.
+    /// Records the tickers of the balances received before the assets held by an identity were
+    /// tracked, which would otherwise be skipped when closing or merging that identity. It walks
+    /// the raw keys of `BalanceOf` since double maps cannot be iterated, and runs only once.
+    fn backfill_assets_held() {
+        if Self::assets_held_backfilled() {
+            return;
+        }
+        let prefix = [twox_128(b"Asset"), twox_128(b"BalanceOf")].concat();
+        let mut key = prefix.clone();
+        while let Some(next) =
+            sp_io::storage::next_key(&key).filter(|next| next.starts_with(&prefix))
+        {
+            // The key ends in `blake2_128(ticker) ++ ticker ++ blake2_128(did) ++ did`.
+            let mut ticker_key = next.get(prefix.len() + 16..).unwrap_or_default();
+            if let Ok(ticker) = Ticker::decode(&mut ticker_key) {
+                let mut did_key = ticker_key.get(16..).unwrap_or_default();
+                if let Ok(did) = IdentityId::decode(&mut did_key) {
+                    if Self::balance_of(&ticker, did) != 0.into() {
+                        Self::track_holder(&ticker, did);
+                    }
+                }
+            }
+            key = next;
+        }
+        <AssetsHeldBackfilled>::put(true);
+    }
+
+    fn track_holder(ticker: &Ticker, did: IdentityId) {
+        <AssetsHeld>::mutate(did, |tickers| {
+            if !tickers.contains(ticker) {
+                tickers.push(*ticker);
+            }
+        });
+    }
+
     pub fn _create_checkpoint(ticker: &Ticker) -> DispatchResult {
         if <TotalCheckpoints>::contains_key(ticker) {
             let mut checkpoint_count = Self::total_checkpoints_of(ticker);
@@ -1955,6 +2134,7 @@ impl<T: Trait> Module<T> {
         Self::_update_checkpoint(ticker, to_did, current_to_balance);
 
         <BalanceOf<T>>::insert(ticker, &to_did, updated_to_balance);
+        Self::track_holder(ticker, to_did);
         <Tokens<T>>::insert(ticker, token);
         let round = Self::funding_round(ticker);
         let ticker_round = (*ticker, round.clone());
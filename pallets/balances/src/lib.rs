@@ -174,7 +174,7 @@
 use polymesh_common_utilities::{
     traits::{
         balances::{AccountData, BalancesTrait, CheckCdd, Memo, RawEvent, Reasons},
-        identity::IdentityTrait,
+        identity::{IdentityHoldings, IdentityTrait},
         NegativeImbalance, PositiveImbalance,
     },
     Context, SystematicIssuers,
@@ -1081,6 +1081,33 @@ where
     }
 }
 
+// Polymesh specific change. The identity balance is moved when identities are merged.
+impl<T: Trait> IdentityHoldings for Module<T> {
+    fn has_no_holdings(did: IdentityId) -> bool {
+        Self::identity_balance(did).is_zero()
+    }
+
+    fn ensure_can_move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult {
+        Self::identity_balance(to)
+            .checked_add(&Self::identity_balance(from))
+            .ok_or(Error::<T>::Overflow)?;
+        Ok(())
+    }
+
+    fn move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult {
+        let value = Self::identity_balance(from);
+        if value.is_zero() {
+            return Ok(());
+        }
+        let new_to_balance = Self::identity_balance(to)
+            .checked_add(&value)
+            .ok_or(Error::<T>::Overflow)?;
+        <IdentityBalance<T>>::remove(from);
+        <IdentityBalance<T>>::insert(to, new_to_balance);
+        Ok(())
+    }
+}
+
 impl<T: Trait> ReservableCurrency<T::AccountId> for Module<T>
 where
     T::Balance: MaybeSerializeDeserialize + Debug,
@@ -76,6 +76,9 @@ decl_storage! {
     trait Store for Module<T: Trait> as CddOffchainWorker {
         /// Last block at which unsigned transaction get submitted with in the transaction pool
         pub LastExtSubmittedAt get(last_extrinsic_submitted_at): T::BlockNumber;
+        /// Last block at which the unsigned transaction suspending invalid validators got
+        /// submitted within the transaction pool
+        pub LastValidatorExtSubmittedAt get(last_validator_extrinsic_submitted_at): T::BlockNumber;
     }
 }
 
@@ -86,8 +89,12 @@ decl_event! {
         BlockNumber = <T as frame_system::Trait>::BlockNumber,
         AccountId = <T as frame_system::Trait>::AccountId,
     {
-        /// Event generated when nominators get removed from the `Staking` storage
+        /// Event generated when nominators get flagged or, after the grace period, removed from
+        /// the `Staking` storage
         InvalidateNominators(BlockNumber, Vec<AccountId>),
+        /// Event generated when validators get submitted for suspension because their cdd claim
+        /// or required claims lapsed
+        InvalidateValidators(BlockNumber, Vec<AccountId>),
     }
 }
 
@@ -128,6 +135,21 @@ decl_module! {
             Ok(())
         }
 
+        /// Submit the list of invalid permissioned validators via unsigned transaction. The
+        /// staking pallet suspends those of them that no longer meet the compliance requirements.
+        ///
+        /// Like `take_off_invalidate_nominators`, it can be called only once every
+        /// `T::CoolingInterval` blocks.
+        #[weight = SimpleDispatchInfo::FixedOperational(10_000_000)]
+        fn take_off_invalid_validators(origin, _block_number: T::BlockNumber, target: Vec<T::AccountId>, _signature: <T::SignerId as RuntimeAppPublic>::Signature) -> DispatchResult {
+            ensure_none(origin)?;
+            ensure!(target.len() > 0, Error::<T>::EmptyTargetList);
+            <pallet_staking::Module<T>>::unsafe_suspend_invalid_validators(target.clone())?;
+            let current_block = <system::Module<T>>::block_number();
+            <LastValidatorExtSubmittedAt<T>>::put(current_block);
+            Self::deposit_event(RawEvent::InvalidateValidators(current_block, target));
+            Ok(())
+        }
 
         fn offchain_worker(block: T::BlockNumber) {
             // Print the debug statement to know that offchain worker initiated
@@ -147,6 +169,18 @@ decl_module! {
                     }
                 }
             }
+            if Self::is_validator_transaction_allowed(block) {
+                // Fetch all the permissioned validators whose cdd claim expires within the
+                // `BufferInterval` or who miss any of the required claims.
+                let invalid_validators = <pallet_staking::Module<T>>::fetch_invalid_validators((T::BufferInterval::get()).saturated_into::<u64>());
+                debug::debug!("Invalid validators are fetched from the staking pallet: {:?}", invalid_validators);
+                if invalid_validators.len() > 0 {
+                    let res = Self::suspend_invalid_validators(block, invalid_validators);
+                    if let Err(e) = res {
+                        debug::error!("Error: {}", e);
+                    }
+                }
+            }
         }
     }
 }
@@ -191,6 +225,79 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn suspend_invalid_validators(
+        block: T::BlockNumber,
+        invalid_validators: Vec<T::AccountId>,
+    ) -> Result<(), &'static str> {
+        use frame_system::offchain::SubmitUnsignedTransaction;
+        ensure!(sp_io::offchain::is_validator(), "Not a validator");
+        if let Some(key) = T::SignerId::all().into_iter().next() {
+            let signature = key
+                .sign(&invalid_validators.encode())
+                .ok_or("Keys are not present or not able to sign")?;
+            let call = Call::take_off_invalid_validators(block, invalid_validators, signature);
+            let _ = T::SubmitUnsignedTransaction::submit_unsigned(call)
+                .map_err(|()| "Unable to submit unsigned transaction.")?;
+        }
+        Ok(())
+    }
+
+    fn is_validator_transaction_allowed(block_number: T::BlockNumber) -> bool {
+        Self::last_validator_extrinsic_submitted_at() + T::CoolingInterval::get() < block_number
+    }
+
+    /// Validates an unsigned transaction submitting `target` accounts at `block_number`, where
+    /// `last_unsigned_at` is the block of the last such transaction and `tag` identifies the kind
+    /// of the transaction in the pool.
+    fn validate_targets(
+        last_unsigned_at: T::BlockNumber,
+        block_number: &T::BlockNumber,
+        target: &[T::AccountId],
+        signature: &<T::SignerId as RuntimeAppPublic>::Signature,
+        tag: Vec<u8>,
+    ) -> TransactionValidity {
+        // Now let's check if the transaction has any chance to succeed.
+        ensure!(
+            last_unsigned_at + T::CoolingInterval::get() <= *block_number,
+            InvalidTransaction::Stale
+        );
+
+        // Let's make sure to reject transactions from the future.
+        ensure!(
+            &<system::Module<T>>::block_number() >= block_number,
+            InvalidTransaction::Future
+        );
+
+        // Get the key from the store to verify whether the signed signature is
+        // generated from the validator or not.
+        let signer_id = T::SignerId::all()
+            .into_iter()
+            .next()
+            .ok_or(InvalidTransaction::BadProof)?;
+
+        let signature_valid =
+            target.using_encoded(|encoded_targets| signer_id.verify(&encoded_targets, signature));
+        // validating signature
+        ensure!(signature_valid, InvalidTransaction::BadProof);
+
+        Ok(ValidTransaction {
+            // We set the priority to the max value - 10000. ~ near to high priority
+            // TODO: We can change after discussion
+            priority: TransactionPriority::max_value() - 10000_u64,
+            requires: vec![],
+            // We set the `provides` tag to be the same as `last_unsigned_at`. This makes
+            // sure only one transaction produced after `last_unsigned_at` will ever
+            // get to the transaction pool and will end up in the block.
+            // We can still have multiple transactions compete for the same "spot",
+            // and the one with higher priority will replace other one in the pool.
+            provides: vec![tag],
+            // The transaction is only valid for next n blocks. After that it's
+            // going to be revalidated by the pool. n = cooling interval
+            longevity: (T::CoolingInterval::get()).saturated_into::<u64>(),
+            propagate: true,
+        })
+    }
+
     fn is_unsigned_transaction_allowed(block_number: T::BlockNumber) -> bool {
         // check whether the last extrinsic submission blockNumber + cooling interval should be
         // greater than current block number or not.
@@ -211,51 +318,28 @@ impl<T: Trait> frame_support::unsigned::ValidateUnsigned for Module<T> {
     /// are being whitelisted and marked as valid.
     fn validate_unsigned(call: &Self::Call) -> TransactionValidity {
         // Firstly let's check that we call the right function.
-        if let Call::take_off_invalidate_nominators(block_number, target, signature) = call {
-            // Now let's check if the transaction has any chance to succeed.
-            let last_unsigned_at = <LastExtSubmittedAt<T>>::get();
-            ensure!(
-                last_unsigned_at + T::CoolingInterval::get() <= *block_number,
-                InvalidTransaction::Stale
-            );
-
-            // Let's make sure to reject transactions from the future.
-            ensure!(
-                &<system::Module<T>>::block_number() >= block_number,
-                InvalidTransaction::Future
-            );
-
-            // Get the key from the store to verify whether the signed signature is
-            // generated from the validator or not.
-            let signer_id = T::SignerId::all()
-                .into_iter()
-                .next()
-                .ok_or(InvalidTransaction::BadProof)?;
-
-            let signature_valid = target.using_encoded(|encoded_nominators| {
-                signer_id.verify(&encoded_nominators, &signature)
-            });
-            // validating signature
-            ensure!(signature_valid, InvalidTransaction::BadProof);
-
-            Ok(ValidTransaction {
-                // We set the priority to the max value - 10000. ~ near to high priority
-                // TODO: We can change after discussion
-                priority: TransactionPriority::max_value() - 10000_u64,
-                requires: vec![],
-                // We set the `provides` tag to be the same as `last_unsigned_at`. This makes
-                // sure only one transaction produced after `last_unsigned_at` will ever
-                // get to the transaction pool and will end up in the block.
-                // We can still have multiple transactions compete for the same "spot",
-                // and the one with higher priority will replace other one in the pool.
-                provides: vec![codec::Encode::encode(&(KEY_TYPE.0, last_unsigned_at))],
-                // The transaction is only valid for next n blocks. After that it's
-                // going to be revalidated by the pool. n = cooling interval
-                longevity: (T::CoolingInterval::get()).saturated_into::<u64>(),
-                propagate: true,
-            })
-        } else {
-            InvalidTransaction::Call.into()
+        match call {
+            Call::take_off_invalidate_nominators(block_number, target, signature) => {
+                let last_unsigned_at = <LastExtSubmittedAt<T>>::get();
+                Self::validate_targets(
+                    last_unsigned_at,
+                    block_number,
+                    target,
+                    signature,
+                    codec::Encode::encode(&(KEY_TYPE.0, last_unsigned_at)),
+                )
+            }
+            Call::take_off_invalid_validators(block_number, target, signature) => {
+                let last_unsigned_at = <LastValidatorExtSubmittedAt<T>>::get();
+                Self::validate_targets(
+                    last_unsigned_at,
+                    block_number,
+                    target,
+                    signature,
+                    codec::Encode::encode(&(KEY_TYPE.0, b"validators", last_unsigned_at)),
+                )
+            }
+            _ => InvalidTransaction::Call.into(),
         }
     }
 }
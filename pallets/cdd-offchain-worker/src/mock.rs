@@ -37,7 +37,7 @@ use polymesh_common_utilities::traits::{
     multisig::AddSignerMultiSig,
     CommonTrait,
 };
-use primitives::{AccountKey, IdentityId, Signatory};
+use primitives::{AccountKey, CddId, IdentityId, InvestorUid, Signatory};
 use sp_core::{
     crypto::{key_types, Pair as PairTrait},
     offchain::{testing, OffchainExt, TransactionPoolExt},
@@ -265,12 +265,18 @@ impl group::Trait<group::Instance2> for Test {
     type ResetOrigin = EnsureSignedBy<Four, AccountId>;
     type MembershipInitialized = ();
     type MembershipChanged = ();
+    type Currency = pallet_balances::Module<Test>;
+    type BallotDeposit = ();
+    type MaxVoters = ();
+    type MaxCandidates = ();
 }
 
 impl protocol_fee::Trait for Test {
     type Event = ();
     type Currency = Balances;
     type OnProtocolFeePayment = ();
+    type OnValidatorsFeeShare = ();
+    type PriceFeed = ();
 }
 
 impl IdentityTrait for Test {
@@ -284,10 +290,15 @@ impl IdentityTrait for Test {
     type Public = AccountId;
     type OffChainSignature = OffChainSignature;
     type ProtocolFee = protocol_fee::Module<Test>;
+    type Holdings = ();
 }
 
 impl pallet_transaction_payment::CddAndFeeDetails<Call> for Test {
-    fn get_valid_payer(_: &Call, _: &Signatory) -> Result<Option<Signatory>, InvalidTransaction> {
+    fn get_valid_payer(
+        _: &Call,
+        _: &Signatory,
+        _: Balance,
+    ) -> Result<Option<Signatory>, InvalidTransaction> {
         Ok(None)
     }
     fn clear_context() {}
@@ -628,42 +639,48 @@ impl ExtBuilder {
 
         let _ = identity::GenesisConfig::<Test> {
             identities: vec![
-                /// (master_account_id, service provider did, target did, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
+                /// (master_account_id, service provider did, target did, investor uid, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
                 /// Provide Identity
                 (
                     account_key_ring.get(&1005).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(1),
+                    InvestorUid::from(b"uid1"),
                     None,
                 ),
                 (
                     account_key_ring.get(&11).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(11),
+                    InvestorUid::from(b"uid11"),
                     None,
                 ),
                 (
                     account_key_ring.get(&21).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(21),
+                    InvestorUid::from(b"uid21"),
                     None,
                 ),
                 (
                     account_key_ring.get(&31).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(31),
+                    InvestorUid::from(b"uid31"),
                     None,
                 ),
                 (
                     account_key_ring.get(&41).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(41),
+                    InvestorUid::from(b"uid41"),
                     None,
                 ),
                 (
                     account_key_ring.get(&101).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(101),
+                    InvestorUid::from(b"uid101"),
                     None,
                 ),
             ],
@@ -764,11 +781,17 @@ pub fn account_from(id: u64) -> AccountId {
     Pair::from_seed(&enc_id).public()
 }
 
+/// It builds the CDD commitment of a test investor, using `seed` as its unique ID.
+pub fn make_cdd_id<S: AsRef<[u8]>>(seed: S) -> CddId {
+    CddId::new(InvestorUid::from(seed))
+}
+
 pub fn create_did_and_add_claim(stash: AccountId, expiry: u64) {
     Balances::make_free_balance_be(&account_from(1005), 1_000_000);
     assert_ok!(Identity::cdd_register_did(
         Origin::signed(account_from(1005)),
         stash,
+        make_cdd_id(stash),
         Some(expiry.saturated_into::<Moment>()),
         vec![]
     ));
@@ -22,6 +22,8 @@
 // - New instance of the group module is being added and assigned committee instance to
 // `MembershipInitialized` & `MembershipChanged` trait
 // - If MotionDuration > 0 then only the `close()` dispatchable will be used.
+// - Proposals are closed automatically at the end of their voting period, and abstentions follow
+// the vote of the prime member.
 
 //! # Committee Module
 //!
@@ -47,6 +49,15 @@
 //! - `close` - May be called by any signed account after the voting duration has ended in order to
 //! finish voting and close the proposal.
 //! - `set_release_coordinator` - Changes the release coordinator.
+//! - `set_voting_period` - Changes the voting period of the proposals of this committee.
+//! - `set_prime` - Changes the prime member, whose vote is used by default for abstentions.
+//!
+//! ### Automatic closing
+//! When the voting period is not zero, any proposal still open at the end of its voting period is
+//! closed automatically at the beginning of that block, on behalf of the `SystematicIssuer` of the
+//! committee. At most `MAX_EXPIRED_CLOSES_PER_BLOCK` proposals are closed per block, and the rest
+//! are closed in the following blocks. Members who did not vote are counted as
+//! voting like the prime member if it approved the proposal, or as rejecting it otherwise.
 //!
 //! ### Other Public Functions
 //! - `is_member` - Returns true if a given DID is contained in the set of committee members, and
@@ -73,7 +84,7 @@ use polymesh_common_utilities::{
 };
 use polymesh_primitives::{AccountKey, IdentityId};
 use sp_core::u32_trait::Value as U32;
-use sp_runtime::traits::{EnsureOrigin, Hash, Zero};
+use sp_runtime::traits::{EnsureOrigin, Hash, Saturating, Zero};
 use sp_std::{convert::TryFrom, prelude::*, vec};
 
 /// Simple index type for proposal counting.
@@ -82,6 +93,9 @@ pub type ProposalIndex = u32;
 /// The number of committee members
 pub type MemberCount = u32;
 
+/// The maximum number of expired proposals closed at the beginning of a block.
+pub const MAX_EXPIRED_CLOSES_PER_BLOCK: usize = 8;
+
 /// The committee trait.
 pub trait Trait<I>: frame_system::Trait + IdentityModuleTrait {
     /// The outer origin type.
@@ -95,13 +109,16 @@ pub trait Trait<I>: frame_system::Trait + IdentityModuleTrait {
 
     /// The outer event type.
     type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
-    /// The time-out for council motions.
+    /// The default time-out for council motions, used until a voting period is set.
     type MotionDuration: Get<Self::BlockNumber>;
 
     type EnactProposalMaker: EnactProposalMaker<
         <Self as frame_system::Trait>::Origin,
         <Self as Trait<I>>::Proposal,
     >;
+
+    /// The systematic issuer that acts on behalf of this committee when there is no caller.
+    type SystematicIssuer: Get<SystematicIssuers>;
 }
 
 /// Origin for the committee module.
@@ -146,6 +163,10 @@ decl_storage! {
         pub VoteThreshold get(fn vote_threshold) config(): (u32, u32);
         /// Release coordinator.
         pub ReleaseCoordinator get(fn release_coordinator): Option<IdentityId>;
+        /// Voting period of new proposals. If `None`, `MotionDuration` is used.
+        pub VotingPeriod get(fn voting_period): Option<T::BlockNumber>;
+        /// Prime member, whose vote is used by default for abstentions when a proposal is closed.
+        pub Prime get(fn prime): Option<IdentityId>;
     }
     add_extra_genesis {
         config(phantom): sp_std::marker::PhantomData<(T, I)>;
@@ -155,6 +176,7 @@ decl_storage! {
 decl_event!(
     pub enum Event<T, I> where
         <T as frame_system::Trait>::Hash,
+        <T as frame_system::Trait>::BlockNumber,
     {
         /// A motion (given hash) has been proposed (by given account) with a threshold (given `MemberCount`).
         /// Parameters: caller DID, proposal index, proposal hash.
@@ -195,6 +217,12 @@ decl_event!(
         /// Vote reject referendum.
         /// Parameters: caller DID, target Pip Id.
         VoteRejectReferendum(IdentityId, PipId),
+        /// Voting period has been updated.
+        /// Parameters: caller DID, new voting period.
+        VotingPeriodUpdated(IdentityId, BlockNumber),
+        /// Prime member has been updated.
+        /// Parameters: caller DID, DID of the prime member.
+        PrimeUpdated(IdentityId, Option<IdentityId>),
     }
 );
 
@@ -236,6 +264,12 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        fn on_initialize(n: T::BlockNumber) {
+            if !Self::motion_duration().is_zero() {
+                Self::close_expired_proposals(n);
+            }
+        }
+
         /// Change the vote threshold the determines the winning proposal. For e.g., for a simple
         /// majority use (1, 2) which represents the in-equation ">= 1/2"
         ///
@@ -280,7 +314,7 @@ decl_module! {
                 <ProposalCount<I>>::mutate(|i| *i += 1);
                 <Proposals<T, I>>::mutate(|proposals| proposals.push(proposal_hash));
                 <ProposalOf<T, I>>::insert(proposal_hash, *proposal);
-                let end = system::Module::<T>::block_number() + T::MotionDuration::get();
+                let end = system::Module::<T>::block_number() + Self::motion_duration();
                 let votes = PolymeshVotes { index, ayes: vec![did], nays: vec![], end: end };
                 <Voting<T, I>>::insert(proposal_hash, votes);
 
@@ -345,7 +379,9 @@ decl_module! {
         /// May be called by any signed account after the voting duration has ended in order to
         /// finish voting and close the proposal.
         ///
-        /// Abstentions are counted as rejections.
+        /// Abstentions are counted as approvals if the prime member approved the proposal, and as
+        /// rejections otherwise. The proposal is rejected unless the approvals satisfy the vote
+        /// threshold.
         ///
         /// # Arguments
         /// * `proposal` - A hash of the proposal to be closed.
@@ -366,31 +402,11 @@ decl_module! {
 
             let voting = Self::voting(&proposal).ok_or(Error::<T, I>::NoSuchProposal)?;
             // POLYMESH-NOTE- Change specific to Polymesh
-            ensure!(T::MotionDuration::get() > Zero::zero(), Error::<T, I>::NotAllowed);
+            ensure!(Self::motion_duration() > Zero::zero(), Error::<T, I>::NotAllowed);
             ensure!(voting.index == index, Error::<T, I>::MismatchedVotingIndex);
             ensure!(system::Module::<T>::block_number() >= voting.end, Error::<T, I>::TooEarly);
 
-            let mut no_votes = voting.nays.len() as MemberCount;
-            let yes_votes = voting.ayes.len() as MemberCount;
-            let seats = Self::members().len() as MemberCount;
-            let abstentions = seats - (yes_votes + no_votes);
-            no_votes += abstentions;
-
-            Self::deposit_event(RawEvent::Closed(did, proposal, yes_votes, no_votes));
-            let threshold = <VoteThreshold<I>>::get();
-
-            let approved = Self::is_threshold_satisfied(yes_votes, seats, threshold);
-            let rejected = Self::is_threshold_satisfied(no_votes, seats, threshold);
-            if approved || rejected {
-                Self::finalize_proposal(approved, seats, yes_votes, no_votes, proposal, did);
-                Self::deposit_event(RawEvent::FinalVotes(
-                    did,
-                    voting.index,
-                    proposal,
-                    voting.ayes,
-                    voting.nays,
-                ));
-            }
+            Self::unsafe_close(proposal, voting, did);
         }
 
         /// Changes the release coordinator.
@@ -410,6 +426,52 @@ decl_module! {
             Self::deposit_event(RawEvent::ReleaseCoordinatorUpdated(current_did, Some(id)));
         }
 
+        /// Changes the voting period of new proposals. Open proposals whose voting period has
+        /// already ended are given a full new voting period.
+        ///
+        /// # Arguments
+        /// * `period` - Number of blocks a proposal can be voted on. If `None`, the default
+        /// `MotionDuration` is used. A zero period disables the closing of proposals.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn set_voting_period(origin, period: Option<T::BlockNumber>) {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            <VotingPeriod<T, I>>::mutate(|old| *old = period);
+
+            let duration = Self::motion_duration();
+            if !duration.is_zero() {
+                let now = system::Module::<T>::block_number();
+                for hash in Self::proposals() {
+                    <Voting<T, I>>::mutate(&hash, |voting| {
+                        if let Some(voting) = voting.as_mut().filter(|v| v.end <= now) {
+                            voting.end = now.saturating_add(duration);
+                        }
+                    });
+                }
+            }
+            let current_did = Context::current_identity::<Identity<T>>()
+                .unwrap_or_else(Self::issuer_did);
+            Self::deposit_event(RawEvent::VotingPeriodUpdated(current_did, duration));
+        }
+
+        /// Changes the prime member.
+        ///
+        /// # Arguments
+        /// * `id` - The DID of the new prime member, or `None` to remove it.
+        ///
+        /// # Errors
+        /// * `MemberNotFound`, If the new prime member `id` is not part of the committee.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn set_prime(origin, id: Option<IdentityId>) {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            if let Some(id) = id {
+                ensure!(Self::members().contains(&id), Error::<T, I>::MemberNotFound);
+            }
+            <Prime<I>>::mutate(|prime| *prime = id);
+            let current_did = Context::current_identity::<Identity<T>>()
+                .unwrap_or(SystematicIssuers::Committee.as_id());
+            Self::deposit_event(RawEvent::PrimeUpdated(current_did, id));
+        }
+
         #[weight = SimpleDispatchInfo::FixedOperational(5_000_000)]
         pub fn vote_enact_referendum(origin, id: PipId) -> DispatchResult {
             Self::vote_referendum( origin, id,
@@ -438,6 +500,66 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Self::members().contains(who)
     }
 
+    /// Returns the voting period of new proposals.
+    pub fn motion_duration() -> T::BlockNumber {
+        Self::voting_period().unwrap_or_else(T::MotionDuration::get)
+    }
+
+    /// Returns the DID of the systematic issuer of this committee.
+    fn issuer_did() -> IdentityId {
+        T::SystematicIssuer::get().as_id()
+    }
+
+    /// Closes up to `MAX_EXPIRED_CLOSES_PER_BLOCK` open proposals whose voting period ended at or
+    /// before block `n`, oldest first.
+    fn close_expired_proposals(n: T::BlockNumber) {
+        let did = Self::issuer_did();
+        let expired = Self::proposals()
+            .into_iter()
+            .filter_map(|proposal| {
+                Self::voting(&proposal)
+                    .filter(|v| v.end <= n)
+                    .map(|voting| (proposal, voting))
+            })
+            .take(MAX_EXPIRED_CLOSES_PER_BLOCK)
+            .collect::<Vec<_>>();
+        for (proposal, voting) in expired {
+            Self::unsafe_close(proposal, voting, did);
+        }
+    }
+
+    /// Closes `proposal`, counting abstentions as the vote of the prime member if it approved
+    /// the proposal, or as rejections otherwise. The proposal is executed if the resulting
+    /// approvals satisfy the vote threshold, and rejected otherwise.
+    fn unsafe_close(
+        proposal: T::Hash,
+        voting: PolymeshVotes<IdentityId, T::BlockNumber>,
+        did: IdentityId,
+    ) {
+        let mut yes_votes = voting.ayes.len() as MemberCount;
+        let mut no_votes = voting.nays.len() as MemberCount;
+        let seats = Self::members().len() as MemberCount;
+        let abstentions = seats.saturating_sub(yes_votes + no_votes);
+        let prime_approves = Self::prime().map_or(false, |prime| voting.ayes.contains(&prime));
+        if prime_approves {
+            yes_votes += abstentions;
+        } else {
+            no_votes += abstentions;
+        }
+
+        Self::deposit_event(RawEvent::Closed(did, proposal, yes_votes, no_votes));
+        let threshold = <VoteThreshold<I>>::get();
+        let approved = Self::is_threshold_satisfied(yes_votes, seats, threshold);
+        Self::finalize_proposal(approved, seats, yes_votes, no_votes, proposal, did);
+        Self::deposit_event(RawEvent::FinalVotes(
+            did,
+            voting.index,
+            proposal,
+            voting.ayes,
+            voting.nays,
+        ));
+    }
+
     /// Given `votes` number of votes out of `total` votes, this function compares`votes`/`total`
     /// in relation to the threshold proporion `n`/`d`.
     fn is_threshold_satisfied(votes: u32, total: u32, (n, d): (u32, u32)) -> bool {
@@ -666,6 +788,17 @@ impl<T: Trait<I>, I: Instance> ChangeMembers<IdentityId> for Module<T, I> {
             }
         }
 
+        // Outgoing members cannot remain as prime member.
+        if let Some(prime) = Self::prime() {
+            if outgoing.contains(&prime) {
+                <Prime<I>>::kill();
+                Self::deposit_event(RawEvent::PrimeUpdated(
+                    Context::current_identity::<Identity<T>>().unwrap_or_default(),
+                    None,
+                ));
+            }
+        }
+
         // Add/remove Systematic CDD claims for new/removed members.
         let issuer = SystematicIssuers::Committee;
         <identity::Module<T>>::unsafe_add_systematic_cdd_claims(incoming, issuer);
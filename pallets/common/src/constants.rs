@@ -23,6 +23,8 @@ pub mod did {
     pub const CDD_PROVIDERS_DID: &[u8; 32] = b"system:customer_due_diligence\0\0\0";
     /// Treasury module DID. It is used in systematic CDD claim for the Treasury module.
     pub const TREASURY_MODULE_DID: &[u8; 32] = b"system:treasury_module_did\0\0\0\0\0\0";
+    /// Technical Committee DID. It is used in the events of the Technical Committee.
+    pub const TECHNICAL_COMMITTEE_DID: &[u8; 32] = b"system:technical_committee\0\0\0\0\0\0";
 }
 
 // ERC1400 transfer status codes
@@ -20,7 +20,7 @@ pub mod constants;
 pub mod traits;
 pub use traits::{
     asset, balances, compliance_manager, exemption, governance_group, group, identity, multisig,
-    pip, CommonTrait,
+    pip, price_feed, CommonTrait,
 };
 
 pub mod context;
@@ -48,22 +48,30 @@ use polymesh_primitives::IdentityId;
 /// their CDD claim's issuer has been revoked.
 /// * CDD Service Providers: Every CDD providers has a CDD claim generated by
 /// `SystematicIssuers::CDDProvider` group, in order to avoid self-generated claim issue.
+///
+/// `SystematicIssuers::TechnicalCommittee` issues no claim. It identifies the Technical Committee
+/// in the events it emits without a caller.
 #[derive(Debug, Clone, Copy)]
 pub enum SystematicIssuers {
     Committee,
     CDDProvider,
     TreasuryModule,
+    TechnicalCommittee,
 }
 
 impl SystematicIssuers {
     /// It returns the representation of this issuer as a raw public key.
     pub fn as_bytes(self) -> &'static [u8; 32] {
-        use constants::did::{CDD_PROVIDERS_DID, GOVERNANCE_COMMITTEE_DID, TREASURY_MODULE_DID};
+        use constants::did::{
+            CDD_PROVIDERS_DID, GOVERNANCE_COMMITTEE_DID, TECHNICAL_COMMITTEE_DID,
+            TREASURY_MODULE_DID,
+        };
 
         match self {
             SystematicIssuers::Committee => GOVERNANCE_COMMITTEE_DID,
             SystematicIssuers::CDDProvider => CDD_PROVIDERS_DID,
             SystematicIssuers::TreasuryModule => TREASURY_MODULE_DID,
+            SystematicIssuers::TechnicalCommittee => TECHNICAL_COMMITTEE_DID,
         }
     }
 
@@ -36,6 +36,11 @@ pub enum ProtocolOp {
     IdentityAddSigningItemsWithAuthorization,
     PipsPropose,
     VotingAddBallot,
+    AssetAddExtension,
+    StoCappedLaunchSto,
+    BridgeProposeBridgeTx,
+    MultiSigCreateMultiSig,
+    ComplianceManagerAddDefaultTrustedClaimIssuer,
 }
 
 /// Common interface to protocol fees for runtime modules.
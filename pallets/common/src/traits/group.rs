@@ -13,7 +13,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::identity::Trait as IdentityTrait;
+use crate::{identity::Trait as IdentityTrait, CommonTrait};
 
 use polymesh_primitives::IdentityId;
 
@@ -21,7 +21,7 @@ use codec::{Decode, Encode};
 use frame_support::{
     decl_event,
     dispatch::DispatchResult,
-    traits::{ChangeMembers, InitializeMembers},
+    traits::{ChangeMembers, Get, InitializeMembers, LockableCurrency, ReservableCurrency},
 };
 use sp_runtime::traits::EnsureOrigin;
 use sp_std::{
@@ -100,11 +100,25 @@ pub trait Trait<I>: frame_system::Trait + pallet_timestamp::Trait + IdentityTrai
 
     /// The receiver of the signal for when the membership has changed.
     type MembershipChanged: ChangeMembers<IdentityId>;
+
+    /// The currency in which election ballots are staked and their deposits are reserved.
+    type Currency: ReservableCurrency<Self::AccountId, Balance = <Self as CommonTrait>::Balance>
+        + LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+
+    /// The deposit reserved for each election ballot until the end of the term.
+    type BallotDeposit: Get<<Self as CommonTrait>::Balance>;
+
+    /// The maximum number of ballots cast in an election.
+    type MaxVoters: Get<u32>;
+
+    /// The maximum number of candidates in an election.
+    type MaxCandidates: Get<u32>;
 }
 
 decl_event!(
     pub enum Event<T, I> where
     <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::BlockNumber,
     <T as Trait<I>>::Event,
     {
         /// The given member was added; see the transaction for who.
@@ -122,6 +136,21 @@ decl_event!(
         /// The membership was reset; see the transaction for who the new set is.
         /// caller DID, List of new members.
         MembersReset(IdentityId, Vec<IdentityId>),
+        /// The election configuration was changed.
+        /// caller DID, term duration, desired number of members.
+        ElectionConfigChanged(IdentityId, BlockNumber, u32),
+        /// An identity registered as candidate for the next election.
+        /// candidate DID.
+        CandidacySubmitted(IdentityId),
+        /// A candidate withdrew from the next election.
+        /// candidate DID.
+        CandidacyWithdrawn(IdentityId),
+        /// A POLYX holder cast their approval votes.
+        /// voter DID, approved candidates.
+        ElectionVoteCast(IdentityId, Vec<IdentityId>),
+        /// The current term ended and the elected candidates became the new members.
+        /// block number, elected members.
+        TermEnded(BlockNumber, Vec<IdentityId>),
         /// Phantom member, never used.
         Dummy(sp_std::marker::PhantomData<(AccountId, Event)>),
     }
@@ -25,7 +25,7 @@ use polymesh_primitives::{
 };
 
 use codec::{Decode, Encode};
-use frame_support::{decl_event, weights::GetDispatchInfo, Parameter};
+use frame_support::{decl_event, dispatch::DispatchResult, weights::GetDispatchInfo, Parameter};
 use pallet_transaction_payment::{CddAndFeeDetails, ChargeTxFee};
 use sp_core::H512;
 use sp_runtime::traits::{Dispatchable, IdentifyAccount, Member, Verify};
@@ -106,6 +106,8 @@ pub trait Trait: CommonTrait + pallet_timestamp::Trait + balances::Trait {
     type Public: IdentifyAccount<AccountId = <Self as frame_system::Trait>::AccountId>;
     type OffChainSignature: Verify<Signer = Self::Public> + Member + Decode + Encode;
     type ProtocolFee: ChargeProtocolFee<<Self as frame_system::Trait>::AccountId>;
+    /// Balances and assets held by identities, used to close or merge them.
+    type Holdings: IdentityHoldings;
 }
 
 // rustfmt adds a comma after Option<Moment> in NewAuthorization and it breaks compilation
@@ -217,6 +219,12 @@ decl_event!(
 
         /// All Signing keys of the identity ID are unfrozen.
         SigningKeysUnfrozen(IdentityId),
+
+        /// Identity has been closed. Its signing keys are frozen and it has no valid CDD.
+        DidClosed(IdentityId),
+
+        /// Identity has been merged into another one. (merged DID, target DID)
+        DidsMerged(IdentityId, IdentityId),
     }
 );
 
@@ -248,3 +256,47 @@ pub trait IdentityTrait {
     // Provides the DID status for the given DID
     fn has_valid_cdd(target_did: IdentityId) -> bool;
 }
+
+/// This trait is used by the identity module to check and move the holdings of an identity,
+/// like its POLYX identity balance or its asset balances, which are managed by other modules.
+pub trait IdentityHoldings {
+    /// It returns true if `did` does not hold any balance.
+    fn has_no_holdings(did: IdentityId) -> bool;
+
+    /// It checks that all the holdings of `from` identity can be moved to `to` identity, without
+    /// writing anything.
+    fn ensure_can_move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult;
+
+    /// It moves all the holdings of `from` identity to `to` identity.
+    fn move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult;
+}
+
+impl IdentityHoldings for () {
+    fn has_no_holdings(_did: IdentityId) -> bool {
+        true
+    }
+
+    fn ensure_can_move_holdings(_from: IdentityId, _to: IdentityId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn move_holdings(_from: IdentityId, _to: IdentityId) -> DispatchResult {
+        Ok(())
+    }
+}
+
+impl<A: IdentityHoldings, B: IdentityHoldings> IdentityHoldings for (A, B) {
+    fn has_no_holdings(did: IdentityId) -> bool {
+        A::has_no_holdings(did) && B::has_no_holdings(did)
+    }
+
+    fn ensure_can_move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult {
+        A::ensure_can_move_holdings(from, to)?;
+        B::ensure_can_move_holdings(from, to)
+    }
+
+    fn move_holdings(from: IdentityId, to: IdentityId) -> DispatchResult {
+        A::move_holdings(from, to)?;
+        B::move_holdings(from, to)
+    }
+}
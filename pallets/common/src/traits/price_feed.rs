@@ -0,0 +1,33 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Trait Interface to the Price Feed Module
+//!
+//! The interface allows other modules to convert amounts from a reference unit into POLYX.
+
+use polymesh_primitives::Balance;
+
+/// This trait is used to read the POLYX price in the reference unit.
+pub trait PriceFeed {
+    /// Returns the last known price of one POLYX in the reference unit, expressed in base units
+    /// of the reference unit, and whether that price is stale.
+    fn polyx_price() -> Option<(Balance, bool)>;
+}
+
+impl PriceFeed for () {
+    fn polyx_price() -> Option<(Balance, bool)> {
+        None
+    }
+}
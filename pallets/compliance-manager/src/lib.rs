@@ -315,6 +315,9 @@ decl_module! {
         /// * `Unauthorized` if `origin` is not the owner of the ticker.
         /// * `DuplicateAssetRules` if `asset_rules` contains multiple entries with the same `rule_id`.
         ///
+        /// # Protocol fee
+        /// The `ComplianceManagerAddActiveRule` fee is charged once per rule in `asset_rules`.
+        ///
         /// # Weight
         /// `150_000 + 50_000 * asset_rules.len()`
         #[weight = FunctionOf(
@@ -334,6 +337,11 @@ decl_module! {
             let mut asset_rules_dedup = asset_rules.clone();
             asset_rules_dedup.dedup_by_key(|r| r.rule_id);
             ensure!(asset_rules.len() == asset_rules_dedup.len(), Error::<T>::DuplicateAssetRules);
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee_batch(
+                &Signatory::AccountKey(sender_key),
+                ProtocolOp::ComplianceManagerAddActiveRule,
+                asset_rules.len()
+            )?;
             <AssetRulesMap>::mutate(&ticker, |old_asset_rules| {
                 old_asset_rules.rules = asset_rules_dedup
             });
@@ -392,6 +400,9 @@ decl_module! {
         /// * origin - Signer of the dispatchable. It should be the owner of the ticker.
         /// * ticker - Symbol of the asset.
         /// * trusted_issuer - IdentityId of the trusted claim issuer.
+        ///
+        /// # Protocol fee
+        /// The `ComplianceManagerAddDefaultTrustedClaimIssuer` fee is charged.
         #[weight = SimpleDispatchInfo::FixedNormal(300_000)]
         pub fn add_default_trusted_claim_issuer(origin, ticker: Ticker, trusted_issuer: IdentityId) -> DispatchResult {
             Self::modify_default_trusted_claim_issuer(origin, ticker, trusted_issuer, true)
@@ -417,6 +428,10 @@ decl_module! {
         /// * ticker - Symbol of the asset.
         /// * trusted_issuers - Vector of IdentityId of the trusted claim issuers.
         ///
+        /// # Protocol fee
+        /// The `ComplianceManagerAddDefaultTrustedClaimIssuer` fee is charged once per issuer in
+        /// `trusted_issuers`.
+        ///
         /// # Weight
         /// `50_000 + 250_000 * trusted_issuers.len().max(values.len())`
         #[weight = FunctionOf(
@@ -463,6 +478,9 @@ decl_module! {
         /// * origin - Signer of the dispatchable. It should be the owner of the ticker.
         /// * ticker - Symbol of the asset.
         /// * asset_rule - Asset rule.
+        ///
+        /// # Protocol fee
+        /// The `ComplianceManagerAddActiveRule` fee is charged.
         #[weight = SimpleDispatchInfo::FixedNormal(150_000)]
         pub fn change_asset_rule(origin, ticker: Ticker, asset_rule: AssetTransferRule) -> DispatchResult {
             let sender_key = AccountKey::try_from(ensure_signed(origin)?.encode())?;
@@ -470,6 +488,10 @@ decl_module! {
 
             ensure!(Self::is_owner(&ticker, did), Error::<T>::Unauthorized);
             ensure!(Self::get_latest_rule_id(ticker) >= asset_rule.rule_id, Error::<T>::InvalidRuleId);
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee(
+                &Signatory::AccountKey(sender_key),
+                ProtocolOp::ComplianceManagerAddActiveRule
+            )?;
             Self::unsafe_change_asset_rule(did, ticker, asset_rule);
             Ok(())
         }
@@ -481,6 +503,9 @@ decl_module! {
         /// * ticker - Symbol of the asset.
         /// * asset_rules - Vector of asset rule.
         ///
+        /// # Protocol fee
+        /// The `ComplianceManagerAddActiveRule` fee is charged once per rule in `asset_rules`.
+        ///
         /// # Weight
         /// `100_000 + 100_000 * asset_rules.len().max(values.len())`
         #[weight = FunctionOf(
@@ -500,6 +525,11 @@ decl_module! {
             ensure!(Self::is_owner(&ticker, did), Error::<T>::Unauthorized);
             let latest_rule_id = Self::get_latest_rule_id(ticker);
             ensure!(asset_rules.iter().any(|rule| latest_rule_id >= rule.rule_id), Error::<T>::InvalidRuleId);
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee_batch(
+                &Signatory::AccountKey(sender_key),
+                ProtocolOp::ComplianceManagerAddActiveRule,
+                asset_rules.len()
+            )?;
 
             asset_rules.into_iter().for_each(|asset_rule| {
                 Self::unsafe_change_asset_rule(did, ticker, asset_rule);
@@ -549,6 +579,9 @@ impl<T: Trait> Module<T> {
 
     /// It fetches all claims of `target` identity with type and scope from `claim` and generated
     /// by any of `issuers`.
+    ///
+    /// CDD commitments are private to the investor and its CDD provider, so any CDD claim
+    /// matches a CDD claim from a rule, whatever its commitment is.
     fn fetch_claims(target: IdentityId, claim: &Claim, issuers: &[IdentityId]) -> Vec<Claim> {
         let claim_type = claim.claim_type();
         let scope = claim.as_scope().cloned();
@@ -556,8 +589,14 @@ impl<T: Trait> Module<T> {
         issuers
             .iter()
             .flat_map(|issuer| {
-                <identity::Module<T>>::fetch_claim(target, claim_type, *issuer, scope)
-                    .map(|id_claim| id_claim.claim)
+                <identity::Module<T>>::fetch_claim(target, claim_type, *issuer, scope).map(
+                    |id_claim| match id_claim.claim {
+                        Claim::CustomerDueDiligence(..) | Claim::LegacyCustomerDueDiligence => {
+                            claim.clone()
+                        }
+                        _ => id_claim.claim,
+                    },
+                )
             })
             .collect::<Vec<_>>()
     }
@@ -674,6 +713,12 @@ impl<T: Trait> Module<T> {
             Self::trusted_claim_issuer(&ticker).contains(&trusted_issuer) == !is_add_call,
             Error::<T>::IncorrectOperationOnTrustedIssuer
         );
+        if is_add_call {
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee(
+                &Signatory::AccountKey(sender_key),
+                ProtocolOp::ComplianceManagerAddDefaultTrustedClaimIssuer,
+            )?;
+        }
         Self::unsafe_modify_default_trusted_claim_issuer(did, ticker, trusted_issuer, is_add_call);
         Ok(())
     }
@@ -704,6 +749,13 @@ impl<T: Trait> Module<T> {
                 Error::<T>::DidNotExist
             );
         }
+        if is_add_call {
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee_batch(
+                &Signatory::AccountKey(sender_key),
+                ProtocolOp::ComplianceManagerAddDefaultTrustedClaimIssuer,
+                trusted_issuers.len(),
+            )?;
+        }
 
         // iterate all the trusted issuer and modify the data of those.
         trusted_issuers.into_iter().for_each(|default_issuer| {
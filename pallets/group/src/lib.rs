@@ -56,6 +56,17 @@
 //! disabling all claims generated by this member. It means that, claims generated before disabling
 //! any member are still valid and anyone generated after that moment will be invalid.
 //!
+//! ## Elections
+//!
+//! A group can optionally be renewed by term-based elections. Once `ResetOrigin` sets a term
+//! duration and a desired number of members, up to `MaxCandidates` identities with a valid CDD
+//! claim can register as candidates and up to `MaxVoters` POLYX holders with a valid CDD claim can
+//! approve any number of candidates up to the desired number of members. Each ballot reserves a
+//! `BallotDeposit` and locks the stake that weights its approvals until the end of the term. At
+//! the end of each term, the top candidates replace the active members, and every deposit and
+//! lock is released. Inactive members are not affected by elections and disabled members cannot
+//! be elected.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -65,16 +76,23 @@
 //! - `swap_member` - Replaces one identity with another.
 //! - `reset_members` - Re-initializes group members.
 //! - `abdicate_membership` - Unilateral abdication without being subject to a GC vote.
+//! - `set_election_config` - Sets the term duration and the number of elected members.
+//! - `submit_candidacy` - Registers the caller identity as candidate for the next election.
+//! - `withdraw_candidacy` - Removes the caller identity from the candidates.
+//! - `vote_candidates` - Approves a set of candidates for the next election.
 //!
 //! ### Other Public Methods
 //!
 //! - `get_valid_members` - Returns the current "active members" and any "valid member" whose
 //! revocation time-stamp is in the future.
+//! - `end_term` - Tallies the election votes and replaces the active members with the elected
+//! candidates.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use pallet_identity as identity;
+use polymesh_common_utilities::CommonTrait;
 pub use polymesh_common_utilities::{
     group::{GroupTrait, InactiveMember, RawEvent, Trait},
     Context, SystematicIssuers,
@@ -82,15 +100,40 @@ pub use polymesh_common_utilities::{
 use polymesh_primitives::{AccountKey, IdentityId};
 
 use frame_support::{
-    codec::Encode, decl_error, decl_module, decl_storage, dispatch::DispatchResult, ensure,
-    traits::ChangeMembers, weights::SimpleDispatchInfo, StorageValue,
+    codec::{Decode, Encode},
+    decl_error, decl_module, decl_storage,
+    dispatch::DispatchResult,
+    ensure,
+    traits::{
+        ChangeMembers, Currency, Get, LockIdentifier, LockableCurrency, ReservableCurrency,
+        WithdrawReasons,
+    },
+    weights::SimpleDispatchInfo,
+    StorageValue,
 };
 use frame_system::{self as system, ensure_signed};
-use sp_runtime::traits::EnsureOrigin;
+use sp_io::hashing::twox_64;
+use sp_runtime::traits::{EnsureOrigin, Saturating, Zero};
 use sp_std::{convert::TryFrom, prelude::*};
 
 pub type Event<T, I> = polymesh_common_utilities::group::Event<T, I>;
 type Identity<T> = identity::Module<T>;
+type BalanceOf<T> = <T as CommonTrait>::Balance;
+
+/// Approval ballot cast by a POLYX holder for the next election of a group.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ElectionVote<AccountId, Balance> {
+    /// Account whose stake weights this ballot.
+    pub voter: AccountId,
+    /// Identity of the voter. It must hold a valid CDD claim when votes are counted.
+    pub did: IdentityId,
+    /// Approved candidates.
+    pub candidates: Vec<IdentityId>,
+    /// Balance locked in the voter account until the end of the term.
+    pub stake: Balance,
+    /// Deposit reserved in the voter account until the end of the term.
+    pub deposit: Balance,
+}
 
 decl_storage! {
     trait Store for Module<T: Trait<I>, I: Instance=DefaultInstance> as Group {
@@ -98,6 +141,18 @@ decl_storage! {
         pub ActiveMembers get(fn active_members) config(): Vec<IdentityId>;
         /// The current "inactive" membership, stored as an ordered Vec.
         pub InactiveMembers get(fn inactive_members): Vec<InactiveMember<T::Moment>>;
+        /// Duration of a membership term in blocks. Elections are disabled while it is zero.
+        pub TermDuration get(fn term_duration): T::BlockNumber;
+        /// Number of candidates elected at the end of each term.
+        pub DesiredMembers get(fn desired_members): u32;
+        /// Block at which the current term ends.
+        pub TermEnd get(fn term_end): T::BlockNumber;
+        /// Registered candidates for the next election, stored as an ordered Vec.
+        pub Candidates get(fn candidates): Vec<IdentityId>;
+        /// Ballots cast for the next election, indexed by voter account.
+        pub Votes get(fn votes): map hasher(twox_64_concat) T::AccountId => Option<ElectionVote<T::AccountId, BalanceOf<T>>>;
+        /// Number of ballots cast for the next election.
+        pub VoterCount get(fn voter_count): u32;
     }
     add_extra_genesis {
         config(phantom): sp_std::marker::PhantomData<(T, I)>;
@@ -121,6 +176,21 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        /// The deposit reserved for each election ballot until the end of the term.
+        const BallotDeposit: BalanceOf<T> = T::BallotDeposit::get();
+
+        /// The maximum number of ballots cast in an election.
+        const MaxVoters: u32 = T::MaxVoters::get();
+
+        /// The maximum number of candidates in an election.
+        const MaxCandidates: u32 = T::MaxCandidates::get();
+
+        fn on_initialize(n: T::BlockNumber) {
+            if !Self::term_duration().is_zero() && n >= Self::term_end() {
+                Self::end_term(n);
+            }
+        }
+
         /// Disables a member at specific moment.
         ///
         /// Please note that if member is already revoked (a "valid member"), its revocation
@@ -222,15 +292,7 @@ decl_module! {
         #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
         pub fn reset_members(origin, members: Vec<IdentityId>) {
             T::ResetOrigin::try_origin(origin).map_err(|_| Error::<T, I>::BadOrigin)?;
-
-            let mut new_members = members.clone();
-            new_members.sort();
-            <ActiveMembers<I>>::mutate(|m| {
-                T::MembershipChanged::set_members_sorted(&new_members[..], m);
-                *m = new_members;
-            });
-            let current_did = Context::current_identity::<Identity<T>>().unwrap_or(SystematicIssuers::Committee.as_id());
-            Self::deposit_event(RawEvent::MembersReset(current_did, members));
+            Self::unsafe_reset_members(members);
         }
 
         /// Allows the calling member to *unilaterally quit* without this being subject to a GC
@@ -268,6 +330,136 @@ decl_module! {
 
             Ok(())
         }
+
+        /// Configures the term-based elections of this group. May only be called from
+        /// `ResetOrigin` or root.
+        ///
+        /// The current term restarts from this block. A zero `term_duration` disables elections,
+        /// keeping the current members until they are changed by other means.
+        ///
+        /// # Arguments
+        /// * `origin` - Origin representing `ResetOrigin` or root
+        /// * `term_duration` - Number of blocks of each membership term.
+        /// * `desired_members` - Number of candidates elected at the end of each term.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn set_election_config(origin, term_duration: T::BlockNumber, desired_members: u32) {
+            T::ResetOrigin::try_origin(origin).map_err(|_| Error::<T, I>::BadOrigin)?;
+            ensure!(term_duration.is_zero() || desired_members > 0,
+                Error::<T, I>::InvalidElectionConfig);
+
+            let term_end = if term_duration.is_zero() {
+                Zero::zero()
+            } else {
+                <system::Module<T>>::block_number().saturating_add(term_duration)
+            };
+            <TermDuration<T, I>>::put(term_duration);
+            <DesiredMembers<I>>::put(desired_members);
+            <TermEnd<T, I>>::put(term_end);
+
+            let current_did = Context::current_identity::<Identity<T>>().unwrap_or(SystematicIssuers::Committee.as_id());
+            Self::deposit_event(RawEvent::ElectionConfigChanged(current_did, term_duration, desired_members));
+        }
+
+        /// Registers the caller identity as candidate for the election at the end of the
+        /// current term.
+        ///
+        /// # Errors
+        /// * `ElectionsDisabled` if no term duration has been configured.
+        /// * `MissingCdd` if the caller identity has no valid CDD claim.
+        /// * `DisabledMember` if the caller identity is an inactive member of this group.
+        /// * `DuplicateCandidate` if the caller identity is already a candidate.
+        /// * `TooManyCandidates` if there are `MaxCandidates` candidates already.
+        #[weight = SimpleDispatchInfo::FixedNormal(200_000)]
+        pub fn submit_candidacy(origin) {
+            let sender_key = AccountKey::try_from(ensure_signed(origin)?.encode())?;
+            let did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+            ensure!(!Self::term_duration().is_zero(), Error::<T, I>::ElectionsDisabled);
+            ensure!(<Identity<T>>::has_valid_cdd(did), Error::<T, I>::MissingCdd);
+            ensure!(!Self::is_disabled(did), Error::<T, I>::DisabledMember);
+
+            let mut candidates = Self::candidates();
+            let location = candidates.binary_search(&did).err().ok_or(Error::<T, I>::DuplicateCandidate)?;
+            ensure!(candidates.len() < T::MaxCandidates::get() as usize, Error::<T, I>::TooManyCandidates);
+            candidates.insert(location, did);
+            <Candidates<I>>::put(candidates);
+
+            Self::deposit_event(RawEvent::CandidacySubmitted(did));
+        }
+
+        /// Removes the caller identity from the candidates of the next election. Any approval
+        /// already cast for it is ignored when votes are counted.
+        #[weight = SimpleDispatchInfo::FixedNormal(200_000)]
+        pub fn withdraw_candidacy(origin) {
+            let sender_key = AccountKey::try_from(ensure_signed(origin)?.encode())?;
+            let did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+
+            let mut candidates = Self::candidates();
+            let location = candidates.binary_search(&did).ok().ok_or(Error::<T, I>::NotACandidate)?;
+            candidates.remove(location);
+            <Candidates<I>>::put(candidates);
+
+            Self::deposit_event(RawEvent::CandidacyWithdrawn(did));
+        }
+
+        /// Approves `candidates` for the next election, replacing any previous ballot of the
+        /// caller account. The ballot is weighted by `stake`, which stays locked in the caller
+        /// account until the end of the term. The first ballot of an account also reserves
+        /// `BallotDeposit` until the end of the term.
+        ///
+        /// # Arguments
+        /// * `origin` - POLYX holder whose identity has a valid CDD claim.
+        /// * `candidates` - Approved candidates, at most `DesiredMembers` of them.
+        /// * `stake` - Balance locked to weight the approvals.
+        ///
+        /// # Errors
+        /// * `TooManyVoters` if `MaxVoters` ballots were cast already.
+        /// * `InsufficientBalance` if the caller cannot cover the stake and the deposit.
+        #[weight = SimpleDispatchInfo::FixedNormal(200_000)]
+        pub fn vote_candidates(origin, candidates: Vec<IdentityId>, stake: BalanceOf<T>) {
+            let sender = ensure_signed(origin)?;
+            let sender_key = AccountKey::try_from(sender.encode())?;
+            let did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+            ensure!(!Self::term_duration().is_zero(), Error::<T, I>::ElectionsDisabled);
+            ensure!(<Identity<T>>::has_valid_cdd(did), Error::<T, I>::MissingCdd);
+
+            let mut candidates = candidates;
+            candidates.sort();
+            candidates.dedup();
+            ensure!(!candidates.is_empty() && candidates.len() <= Self::desired_members() as usize,
+                Error::<T, I>::InvalidVote);
+            let registered = Self::candidates();
+            ensure!(candidates.iter().all(|c| registered.binary_search(c).is_ok()),
+                Error::<T, I>::NotACandidate);
+
+            // The deposit is only taken by the first ballot of the account.
+            let previous = Self::votes(&sender);
+            let deposit = match previous {
+                Some(ref vote) => vote.deposit,
+                None => {
+                    ensure!(Self::voter_count() < T::MaxVoters::get(), Error::<T, I>::TooManyVoters);
+                    T::BallotDeposit::get()
+                }
+            };
+            let new_reserve = if previous.is_some() { Zero::zero() } else { deposit };
+            ensure!(
+                T::Currency::free_balance(&sender) >= stake.saturating_add(new_reserve),
+                Error::<T, I>::InsufficientBalance
+            );
+
+            if previous.is_none() {
+                T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T, I>::InsufficientBalance)?;
+                <VoterCount<I>>::mutate(|count| *count += 1);
+            }
+            T::Currency::set_lock(Self::lock_id(), &sender, stake, WithdrawReasons::all());
+            <Votes<T, I>>::insert(&sender, ElectionVote {
+                voter: sender.clone(),
+                did,
+                candidates: candidates.clone(),
+                stake,
+                deposit,
+            });
+            Self::deposit_event(RawEvent::ElectionVoteCast(did, candidates));
+        }
     }
 }
 
@@ -284,7 +476,27 @@ decl_error! {
         /// Last member of the committee can not quit.
         LastMemberCannotQuit,
         /// Missing current DID
-        MissingCurrentIdentity
+        MissingCurrentIdentity,
+        /// Elections require a non-zero number of desired members.
+        InvalidElectionConfig,
+        /// Elections are not enabled for this group.
+        ElectionsDisabled,
+        /// The identity does not have a valid CDD claim.
+        MissingCdd,
+        /// Disabled members cannot run for election.
+        DisabledMember,
+        /// The identity is already a candidate.
+        DuplicateCandidate,
+        /// The identity is not a candidate.
+        NotACandidate,
+        /// A ballot must approve between one and `DesiredMembers` candidates.
+        InvalidVote,
+        /// The election already has `MaxCandidates` candidates.
+        TooManyCandidates,
+        /// The election already has `MaxVoters` ballots.
+        TooManyVoters,
+        /// The voter cannot cover the stake and the deposit of the ballot.
+        InsufficientBalance
     }
 }
 
@@ -296,6 +508,79 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Self::get_valid_members_at(now)
     }
 
+    /// Ends the current term: counts the ballots and replaces the active members with the
+    /// `DesiredMembers` candidates with the highest approval. Ballots are weighted by their
+    /// locked stake and ignored if the voter identity has lost its CDD claim. If no candidate
+    /// gets any approval, the current members are kept for another term.
+    ///
+    /// The work is bounded by `MaxCandidates` and by `MaxVoters` ballots of at most
+    /// `DesiredMembers` approvals each. Stakes and deposits are released, candidates and ballots
+    /// are cleared for the next election, and inactive members are left untouched.
+    pub fn end_term(now: T::BlockNumber) {
+        // Candidates are kept sorted, so each approval is found by binary search.
+        let mut tally = Self::candidates()
+            .into_iter()
+            .filter(|c| <Identity<T>>::has_valid_cdd(*c) && !Self::is_disabled(*c))
+            .map(|c| (c, BalanceOf::<T>::zero()))
+            .collect::<Vec<_>>();
+
+        let lock_id = Self::lock_id();
+        let votes = <Votes<T, I>>::iter().collect::<Vec<_>>();
+        for vote in votes {
+            if <Identity<T>>::has_valid_cdd(vote.did) {
+                for candidate in vote.candidates.iter() {
+                    if let Ok(i) = tally.binary_search_by(|(c, _)| c.cmp(candidate)) {
+                        tally[i].1 = tally[i].1.saturating_add(vote.stake);
+                    }
+                }
+            }
+            T::Currency::remove_lock(lock_id, &vote.voter);
+            let _ = T::Currency::unreserve(&vote.voter, vote.deposit);
+            <Votes<T, I>>::remove(&vote.voter);
+        }
+        <VoterCount<I>>::kill();
+        <Candidates<I>>::kill();
+
+        tally.retain(|(_, score)| !score.is_zero());
+        tally.sort_by(|(a_id, a_score), (b_id, b_score)| {
+            b_score.cmp(a_score).then_with(|| a_id.cmp(b_id))
+        });
+        let elected = tally
+            .into_iter()
+            .take(Self::desired_members() as usize)
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        if !elected.is_empty() {
+            Self::unsafe_reset_members(elected.clone());
+        }
+
+        <TermEnd<T, I>>::put(now.saturating_add(Self::term_duration()));
+        Self::deposit_event(RawEvent::TermEnded(now, elected));
+    }
+
+    /// The lock of election stakes, which is different for each instance of this module.
+    fn lock_id() -> LockIdentifier {
+        twox_64(I::PREFIX.as_bytes())
+    }
+
+    /// Returns true if `who` is an inactive member of this group.
+    fn is_disabled(who: IdentityId) -> bool {
+        Self::inactive_members().iter().any(|m| *m == who)
+    }
+
+    /// Replaces the active members with `members`, disregarding the existing membership.
+    fn unsafe_reset_members(members: Vec<IdentityId>) {
+        let mut new_members = members.clone();
+        new_members.sort();
+        <ActiveMembers<I>>::mutate(|m| {
+            T::MembershipChanged::set_members_sorted(&new_members[..], m);
+            *m = new_members;
+        });
+        let current_did = Context::current_identity::<Identity<T>>()
+            .unwrap_or(SystematicIssuers::Committee.as_id());
+        Self::deposit_event(RawEvent::MembersReset(current_did, members));
+    }
+
     /// Removes a member `who` as "active" or "inactive" member.
     ///
     /// # Arguments
@@ -3,7 +3,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Codec, Decode, Encode};
-pub use polymesh_primitives::{IdentityId, Link, Moment};
+pub use polymesh_primitives::{Authorization, IdentityId, Link, Moment};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_std::{prelude::*, vec::Vec};
@@ -34,6 +34,42 @@ pub enum LinkType {
     AssetOwnership,
     NoData,
 }
+/// The variant of `AuthorizationData` used to filter authorizations.
+#[derive(Eq, PartialEq, Encode, Decode, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum AuthorizationType {
+    AttestMasterKeyRotation,
+    RotateMasterKey,
+    TransferTicker,
+    AddMultiSigSigner,
+    TransferAssetOwnership,
+    JoinIdentity,
+    Custom,
+    NoData,
+}
+
+/// Criteria used to select the pending authorizations of a signatory.
+///
+/// Matching authorizations are returned in ascending order of their ids, starting after
+/// `start_after`, and at most `limit` of them are returned.
+#[derive(Eq, PartialEq, Encode, Decode, Clone, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AuthorizationFilter<Signatory, Moment> {
+    /// Only authorizations of this type.
+    pub auth_type: Option<AuthorizationType>,
+    /// Only authorizations added by this signatory.
+    pub authorized_by: Option<Signatory>,
+    /// Only authorizations that expire before this moment.
+    pub expires_before: Option<Moment>,
+    /// Include authorizations that have already expired.
+    pub allow_expired: bool,
+    /// Id of the last authorization of the previous page.
+    pub start_after: Option<u64>,
+    /// Maximum number of authorizations in the page.
+    pub limit: Option<u32>,
+}
+
 #[derive(Encode, Decode, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 pub enum DidStatus {
@@ -43,6 +79,8 @@ pub enum DidStatus {
 }
 
 sp_api::decl_runtime_apis! {
+    /// Version 2 adds `get_authorizations`.
+    #[api_version(2)]
     pub trait IdentityApi<IdentityId, Ticker, AccountKey, SigningItem, Signatory, Moment> where
         IdentityId: Codec,
         Ticker: Codec,
@@ -66,5 +104,8 @@ sp_api::decl_runtime_apis! {
         /// Retrieve the status of the DID
         fn get_did_status(dids: Vec<IdentityId>) -> Vec<DidStatus>;
 
+        /// Retrieve a page of the pending authorizations of a given signatory
+        fn get_authorizations(signatory: Signatory, filter: AuthorizationFilter<Signatory, Moment>) -> Vec<Authorization<Moment>>;
+
     }
 }
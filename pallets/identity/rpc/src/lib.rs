@@ -2,10 +2,10 @@ use codec::Codec;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
 use pallet_identity_rpc_runtime_api::{
-    AssetDidResult, CddStatus, DidRecords, DidStatus, IdentityApi as IdentityRuntimeApi, Link,
-    LinkType,
+    AssetDidResult, Authorization, AuthorizationFilter, CddStatus, DidRecords, DidStatus,
+    IdentityApi as IdentityRuntimeApi, Link, LinkType,
 };
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
     generic::BlockId,
@@ -57,6 +57,15 @@ pub trait IdentityApi<BlockHash, IdentityId, Ticker, AccountKey, SigningItem, Si
         dids: Vec<IdentityId>,
         at: Option<BlockHash>,
     ) -> Result<Vec<DidStatus>>;
+
+    /// Retrieve a page of the pending authorizations of a given signatory.
+    #[rpc(name = "identity_getAuthorizations")]
+    fn get_authorizations(
+        &self,
+        signatory: Signatory,
+        filter: AuthorizationFilter<Signatory, Moment>,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Authorization<Moment>>>;
 }
 
 /// A struct that implements the [`IdentityApi`].
@@ -205,4 +214,41 @@ where
             data: Some(format!("{:?}", e).into()),
         })
     }
+
+    fn get_authorizations(
+        &self,
+        signatory: Signatory,
+        filter: AuthorizationFilter<Signatory, Moment>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Authorization<Moment>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        // Runtimes before version 2 of the API do not provide the authorization inbox.
+        let has_inbox = api
+            .has_api_with::<dyn IdentityRuntimeApi<
+                Block,
+                IdentityId,
+                Ticker,
+                AccountKey,
+                SigningItem,
+                Signatory,
+                Moment,
+            >, _>(&at, |version| version >= 2)
+            .unwrap_or(false);
+        if !has_inbox {
+            return Err(RpcError {
+                code: ErrorCode::ServerError(Error::RuntimeError as i64),
+                message: "Authorizations are not supported by the runtime at this block".into(),
+                data: None,
+            });
+        }
+
+        api.get_authorizations(&at, signatory, filter)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(Error::RuntimeError as i64),
+                message: "Unable to fetch authorizations".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
 }
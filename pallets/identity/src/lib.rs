@@ -47,6 +47,26 @@
 //! see [freeze_signing_keys](./struct.Module.html#method.freeze_signing_keys)
 //! see [unfreeze_signing_keys](./struct.Module.html#method.unfreeze_signing_keys)
 //!
+//! ## Close and merge identities
+//!
+//! An identity without any balance, tickers or assets can be closed by its master key. Closing an identity revokes
+//! the authorizations it has issued, freezes its signing keys and its CDD is no longer valid.
+//! Governance can also merge an identity into another one (i.e. a corporate restructuring), which
+//! moves its balances, claims and signing items before closing it.
+//!
+//! see [close_identity](./struct.Module.html#method.close_identity)
+//! see [merge_identities](./struct.Module.html#method.merge_identities)
+//!
+//! ## CDD claims and investor unique IDs
+//!
+//! A `CustomerDueDiligence` claim carries a `CddId`, the commitment to the `InvestorUid` of the
+//! person behind the identity. Genesis identities need an explicit `InvestorUid`, which must be
+//! kept private by whoever builds the chain specification.
+//!
+//! CDD claims issued before investor unique IDs keep their former encoding as
+//! `Claim::LegacyCustomerDueDiligence`, so they still decode and count as valid CDD claims. They
+//! carry no commitment, and new ones cannot be added.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -77,6 +97,9 @@
 //! - `batch_accept_authorization` - Accepts an array of authorizations.
 //! - `add_signing_items_with_authorization` - Adds signing keys to target identity `id`.
 //! - `revoke_offchain_authorization` - Revokes the `auth` off-chain authorization of `signer`.
+//! - `close_identity` - Closes the caller's identity, which cannot hold any balance.
+//! - `merge_identities` - Moves balances, claims and signing items of an identity to another one,
+//! and closes the former.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
@@ -84,11 +107,16 @@
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 
-use pallet_identity_rpc_runtime_api::{DidRecords as RpcDidRecords, DidStatus, LinkType};
+use pallet_identity_rpc_runtime_api::{
+    AuthorizationFilter, AuthorizationType, DidRecords as RpcDidRecords, DidStatus, LinkType,
+};
 use pallet_transaction_payment::{CddAndFeeDetails, ChargeTxFee};
 use polymesh_common_utilities::{
     constants::{
-        did::{CDD_PROVIDERS_DID, GOVERNANCE_COMMITTEE_DID, SECURITY_TOKEN, USER},
+        did::{
+            CDD_PROVIDERS_DID, GOVERNANCE_COMMITTEE_DID, SECURITY_TOKEN, TECHNICAL_COMMITTEE_DID,
+            USER,
+        },
         TREASURY_MODULE_ID,
     },
     protocol_fee::{ChargeProtocolFee, ProtocolOp},
@@ -96,17 +124,17 @@ use polymesh_common_utilities::{
         asset::AcceptTransfer,
         group::{GroupTrait, InactiveMember},
         identity::{
-            AuthorizationNonce, IdentityTrait, LinkedKeyInfo, RawEvent, SigningItemWithAuth,
-            TargetIdAuthorization, Trait,
+            AuthorizationNonce, IdentityHoldings, IdentityTrait, LinkedKeyInfo, RawEvent,
+            SigningItemWithAuth, TargetIdAuthorization, Trait,
         },
         multisig::AddSignerMultiSig,
     },
     Context, SystematicIssuers,
 };
 use polymesh_primitives::{
-    AccountKey, AuthIdentifier, Authorization, AuthorizationData, AuthorizationError, Claim,
-    ClaimType, Identity as DidRecord, IdentityClaim, IdentityId, Link, LinkData, Permission, Scope,
-    Signatory, SignatoryType, SigningItem, Ticker,
+    AccountKey, AuthIdentifier, Authorization, AuthorizationData, AuthorizationError, CddId, Claim,
+    ClaimRequirement, ClaimType, Identity as DidRecord, IdentityClaim, IdentityId, InvestorUid,
+    Link, LinkData, Permission, Scope, Signatory, SignatoryType, SigningItem, Ticker,
 };
 
 use codec::{Decode, Encode};
@@ -136,6 +164,9 @@ use frame_system::{self as system, ensure_root, ensure_signed};
 
 pub type Event<T> = polymesh_common_utilities::traits::identity::Event<T>;
 
+/// Maximum number of authorizations returned by `get_authorizations` in a single page.
+pub const MAX_AUTHORIZATIONS_PAGE_SIZE: u32 = 100;
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, PartialOrd, Ord)]
 pub struct Claim1stKey {
     pub target: IdentityId,
@@ -202,9 +233,16 @@ decl_storage! {
 
         /// It defines if authorization from a CDD provider is needed to change master key of an identity
         pub CddAuthForMasterKeyRotation get(fn cdd_auth_for_master_key_rotation): bool;
+
+        /// DID -> moment when it was closed.
+        pub ClosedDids get(fn closed_at): map hasher(twox_64_concat) IdentityId => Option<T::Moment>;
+
+        /// CDD commitment -> Identity bound to it.
+        /// The binding is never removed, so a person cannot get a "unique" identity twice.
+        pub CddIdOwner get(fn cdd_id_owner): map hasher(blake2_128_concat) CddId => Option<IdentityId>;
     }
     add_extra_genesis {
-        config(identities): Vec<(T::AccountId, IdentityId, IdentityId, Option<u64>)>;
+        config(identities): Vec<(T::AccountId, IdentityId, IdentityId, InvestorUid, Option<u64>)>;
         config(signing_keys): Vec<(T::AccountId, IdentityId)>;
         build(|config: &GenesisConfig<T>| {
             // Add systematic CDD for the Treasury module
@@ -228,14 +266,14 @@ decl_storage! {
                 issuance_date: 0_u64,
                 last_update_date: 0_u64,
                 expiry: None,
-                claim: Claim::CustomerDueDiligence,
+                claim: Claim::CustomerDueDiligence(CddId::default()),
             };
 
             <Claims>::insert(&pk, &sk, id_claim.clone());
             <Module<T>>::deposit_event(RawEvent::ClaimAdded(treasury_did, id_claim));
 
-            // Add System DID: Governance committee && CDD providers
-            [GOVERNANCE_COMMITTEE_DID, CDD_PROVIDERS_DID, ].iter()
+            // Add System DID: Governance committee, CDD providers && Technical committee
+            [GOVERNANCE_COMMITTEE_DID, CDD_PROVIDERS_DID, TECHNICAL_COMMITTEE_DID].iter()
                 .for_each(|raw_id| {
                     let id = IdentityId::from(**raw_id);
                     let master_key = AccountKey::from(**raw_id);
@@ -248,7 +286,7 @@ decl_storage! {
                 });
 
             //  Other
-            for &(ref master_account_id, issuer, did, expiry) in &config.identities {
+            for &(ref master_account_id, issuer, did, investor_uid, expiry) in &config.identities {
                 // Direct storage change for registering the DID and providing the claim
                 let master_key = AccountKey::try_from(master_account_id.encode()).unwrap();
                 assert!(!<DidRecords>::contains_key(did), "Identity already exist");
@@ -260,7 +298,9 @@ decl_storage! {
                 };
                 <DidRecords>::insert(&did, record);
                 <Module<T>>::deposit_event(RawEvent::DidCreated(did.clone(), master_account_id.clone(), vec![]));
-                // Add the claim data for the CustomerDueDiligence type claim
+                // Add the claim data for the CustomerDueDiligence type claim.
+                let cdd_id = CddId::new(investor_uid);
+                assert!(!<CddIdOwner>::contains_key(cdd_id), "CDD Id already in use");
                 let claim_type = ClaimType::CustomerDueDiligence;
                 let pk = Claim1stKey{ target: did, claim_type };
                 let sk = Claim2ndKey{ issuer, scope: None };
@@ -269,9 +309,10 @@ decl_storage! {
                     issuance_date: 0_u64,
                     last_update_date: 0_u64,
                     expiry: expiry,
-                    claim: Claim::CustomerDueDiligence,
+                    claim: Claim::CustomerDueDiligence(cdd_id),
                 };
 
+                <CddIdOwner>::insert(cdd_id, did);
                 <Claims>::insert(&pk, &sk, id_claim.clone());
                 <Module<T>>::deposit_event(RawEvent::ClaimAdded(did, id_claim));
             }
@@ -322,17 +363,21 @@ decl_module! {
 
         /// Register `target_account` with a new Identity.
         ///
+        /// # Arguments
+        /// * `cdd_id` Commitment to the investor unique ID of the person behind `target_account`.
+        ///
         /// # Failure
         /// - `origin` has to be a active CDD provider. Inactive CDD providers cannot add new
         /// claims.
         /// - `target_account` (master key of the new Identity) can be linked to just one and only
         /// one identity.
         /// - External signing keys can be linked to just one identity.
+        /// - `cdd_id` cannot be empty nor bound to another identity.
         ///
         /// # Weight
         /// `400_000 + 60_000 * signing_items.len()`
         #[weight = FunctionOf(
-            |(_, _, items): (&T::AccountId, &Option<T::Moment>, &Vec<SigningItem>)| {
+            |(_, _, _, items): (&T::AccountId, &CddId, &Option<T::Moment>, &Vec<SigningItem>)| {
                 400_000 + 60_000 * u32::try_from(items.len()).unwrap_or_default()
             },
             DispatchClass::Normal,
@@ -341,23 +386,26 @@ decl_module! {
         pub fn cdd_register_did(
             origin,
             target_account: T::AccountId,
+            cdd_id: CddId,
             cdd_claim_expiry: Option<T::Moment>,
             signing_items: Vec<SigningItem>
         ) -> DispatchResult {
             // Sender has to be part of CDDProviders
             let cdd_sender = ensure_signed(origin)?;
             let cdd_key = AccountKey::try_from(cdd_sender.encode())?;
-            let cdd_id = Context::current_identity_or::<Self>(&cdd_key)?;
+            let cdd_did = Context::current_identity_or::<Self>(&cdd_key)?;
 
             let cdd_providers = T::CddServiceProviders::get_members();
-            ensure!(cdd_providers.contains(&cdd_id), Error::<T>::UnAuthorizedCddProvider);
+            ensure!(cdd_providers.contains(&cdd_did), Error::<T>::UnAuthorizedCddProvider);
+            Self::ensure_cdd_id_available(&cdd_id, None)?;
+
             // Register Identity and add claim.
             let new_id = Self::_register_did(
                 target_account,
                 signing_items,
                 Some((&Signatory::AccountKey(cdd_key), ProtocolOp::IdentityCddRegisterDid))
             )?;
-            Self::unsafe_add_claim(new_id, Claim::CustomerDueDiligence, cdd_id, cdd_claim_expiry);
+            Self::unsafe_add_claim(new_id, Claim::CustomerDueDiligence(cdd_id), cdd_did, cdd_claim_expiry);
             Ok(())
         }
 
@@ -544,7 +592,8 @@ decl_module! {
             ensure!(<DidRecords>::contains_key(target), Error::<T>::DidMustAlreadyExist);
 
             match claim {
-                Claim::CustomerDueDiligence => Self::unsafe_add_cdd_claim(target, claim, issuer, expiry)?,
+                Claim::CustomerDueDiligence(..) | Claim::LegacyCustomerDueDiligence =>
+                    Self::unsafe_add_cdd_claim(target, claim, issuer, expiry)?,
                 _ => {
                     T::ProtocolFee::charge_fee(
                     &Signatory::AccountKey(sender_key),
@@ -578,17 +627,28 @@ decl_module! {
             ensure!( claims.iter().all(
                 |batch_claim_item| <DidRecords>::contains_key(batch_claim_item.target)),
                 Error::<T>::DidMustAlreadyExist);
+            ensure!(
+                claims.iter().all(|batch_claim_item| batch_claim_item.claim != Claim::LegacyCustomerDueDiligence),
+                Error::<T>::InvalidCddId
+            );
 
-            let cdd_count: usize = claims
+            let cdd_claims = claims
                 .iter()
-                .filter(|batch_claim_item| match batch_claim_item.claim {
-                    Claim::CustomerDueDiligence => true,
-                    _ => false,
-                })
-                .count();
+                .filter_map(|batch_claim_item| batch_claim_item.claim.as_cdd_id()
+                    .map(|cdd_id| (*cdd_id, batch_claim_item.target)))
+                .collect::<Vec<_>>();
+            let cdd_count = cdd_claims.len();
             if cdd_count > 0 {
                 let cdd_providers = T::CddServiceProviders::get_members();
                 ensure!(cdd_providers.contains(&issuer), Error::<T>::UnAuthorizedCddProvider);
+                // The same commitment cannot be bound to different targets in the same batch.
+                for (idx, (cdd_id, target)) in cdd_claims.iter().enumerate() {
+                    Self::ensure_cdd_id_available(cdd_id, Some(*target))?;
+                    ensure!(
+                        cdd_claims[..idx].iter().all(|(prev_id, prev_target)| prev_id != cdd_id || prev_target == target),
+                        Error::<T>::CddIdAlreadyInUse
+                    );
+                }
             }
 
             T::ProtocolFee::charge_fee_batch(
@@ -1140,6 +1200,62 @@ decl_module! {
             <RevokeOffChainAuthorization<T>>::insert((signer,auth), true);
             Ok(())
         }
+
+        /// It closes the caller's identity. It revokes all the authorizations issued by the
+        /// identity, it freezes its signing keys and its CDD claims are not valid anymore.
+        ///
+        /// # Failure
+        /// - Only the master key can close an identity.
+        /// - The identity cannot hold any balance (POLYX or assets), so they have to be moved
+        /// before.
+        /// - The identity cannot own any ticker or asset, so their ownership has to be
+        /// transferred before.
+        #[weight = SimpleDispatchInfo::FixedNormal(1_000_000)]
+        pub fn close_identity(origin) -> DispatchResult {
+            let sender_key = AccountKey::try_from(ensure_signed(origin)?.encode())?;
+            let did = Context::current_identity_or::<Self>(&sender_key)?;
+            let _grants_checked = Self::grant_check_only_master_key(&sender_key, did)?;
+            ensure!(!Self::is_did_closed(did), Error::<T>::DidIsClosed);
+            ensure!(T::Holdings::has_no_holdings(did), Error::<T>::DidHasHoldings);
+            ensure!(!Self::owns_tickers_or_assets(did), Error::<T>::DidOwnsAssets);
+
+            Self::unsafe_close_identity(did);
+            Ok(())
+        }
+
+        /// It merges `from` identity into `to` identity. Balances, claims and signing items of
+        /// `from` are moved to `to`, and then `from` is closed.
+        /// Callable via root (governance).
+        ///
+        /// # Failure
+        /// - Both identities have to exist, be different and not closed.
+        /// - `from` cannot own any ticker or asset, as their ownership is not moved.
+        /// - Every balance of `from` has to be movable to `to`, so no asset can be frozen, have
+        /// a granularity that the balance does not meet, or overflow the balance of `to`.
+        #[weight = SimpleDispatchInfo::FixedOperational(2_000_000)]
+        pub fn merge_identities(origin, from: IdentityId, to: IdentityId) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(from != to, Error::<T>::CannotMergeIntoItself);
+            ensure!(
+                <DidRecords>::contains_key(from) && <DidRecords>::contains_key(to),
+                Error::<T>::DidDoesNotExist
+            );
+            ensure!(
+                !Self::is_did_closed(from) && !Self::is_did_closed(to),
+                Error::<T>::DidIsClosed
+            );
+            ensure!(!Self::owns_tickers_or_assets(from), Error::<T>::DidOwnsAssets);
+            // Every holding is checked first, so a failing move cannot leave `from` half merged.
+            T::Holdings::ensure_can_move_holdings(from, to)?;
+
+            T::Holdings::move_holdings(from, to)?;
+            Self::unsafe_move_claims(from, to);
+            Self::unsafe_move_signing_items(from, to);
+            Self::unsafe_close_identity(from);
+
+            Self::deposit_event(RawEvent::DidsMerged(from, to));
+            Ok(())
+        }
     }
 }
 
@@ -1205,6 +1321,18 @@ decl_error! {
         FailedToChargeFee,
         /// Signer is not a signing key of the provided identity
         NotASigner,
+        /// The identity has been closed.
+        DidIsClosed,
+        /// The identity still holds some balance.
+        DidHasHoldings,
+        /// The identity still owns some tickers or assets.
+        DidOwnsAssets,
+        /// An identity cannot be merged into itself.
+        CannotMergeIntoItself,
+        /// CDD claims have to carry a non-empty commitment. Legacy CDD claims cannot be added.
+        InvalidCddId,
+        /// The CDD commitment is already bound to another identity.
+        CddIdAlreadyInUse,
     }
 }
 
@@ -1640,6 +1768,32 @@ impl<T: Trait> Module<T> {
             .next()
     }
 
+    /// Returns `true` if `id` holds a non-expired claim meeting `requirement`.
+    pub fn holds(id: IdentityId, requirement: &ClaimRequirement) -> bool {
+        Self::fetch_claim(
+            id,
+            requirement.claim_type,
+            requirement.issuer,
+            requirement.scope,
+        )
+        .is_some()
+    }
+
+    /// It fetches the `claim_type` claims for target identity `id` which were issued by `issuer`,
+    /// in any scope.
+    /// It only returns non-expired claims.
+    pub fn fetch_claims_with_issuer(
+        id: IdentityId,
+        claim_type: ClaimType,
+        issuer: IdentityId,
+    ) -> Vec<IdentityClaim> {
+        let now = <pallet_timestamp::Module<T>>::get();
+
+        Self::fetch_base_claims(id, claim_type)
+            .filter(|c| c.claim_issuer == issuer && Self::is_identity_claim_not_expired_at(c, now))
+            .collect()
+    }
+
     /// See `Self::fetch_cdd`.
     #[inline]
     pub fn has_valid_cdd(claim_for: IdentityId) -> bool {
@@ -1663,6 +1817,10 @@ impl<T: Trait> Module<T> {
     /// No state change is allowed in this function because this function is used within the RPC
     /// calls.
     pub fn fetch_cdd(claim_for: IdentityId, leeway: T::Moment) -> Option<IdentityId> {
+        if Self::is_did_closed(claim_for) {
+            return None;
+        }
+
         let exp_with_leeway = <pallet_timestamp::Module<T>>::get()
             .checked_add(&leeway)
             .unwrap_or_default();
@@ -1676,6 +1834,7 @@ impl<T: Trait> Module<T> {
         Self::fetch_base_claims(claim_for, ClaimType::CustomerDueDiligence)
             .filter(|id_claim| {
                 Self::is_identity_cdd_claim_valid(
+                    claim_for,
                     id_claim,
                     exp_with_leeway,
                     &active_cdds,
@@ -1688,11 +1847,13 @@ impl<T: Trait> Module<T> {
 
     /// A CDD claims is considered valid if:
     /// * Claim is not expired at `exp_with_leeway` moment.
+    /// * Its commitment is bound to `target`. Only systematic issuers can use an empty one.
     /// * Its issuer is valid, that means:
     ///   * Issuer is an active CDD provider, or
     ///   * Issuer is an inactive CDD provider but claim was updated/created before that it was
     ///   deactivated.
     fn is_identity_cdd_claim_valid(
+        target: IdentityId,
         id_claim: &IdentityClaim,
         exp_with_leeway: T::Moment,
         active_cdds: &[IdentityId],
@@ -1703,9 +1864,19 @@ impl<T: Trait> Module<T> {
             SystematicIssuers::Committee.as_id(),
             SystematicIssuers::CDDProvider.as_id(),
         ];
-        Self::is_identity_claim_not_expired_at(id_claim, exp_with_leeway)
+        let is_systematic = systematic_cdds.contains(&id_claim.claim_issuer);
+        let is_cdd_id_valid = match &id_claim.claim {
+            Claim::CustomerDueDiligence(cdd_id) if cdd_id.is_default() => is_systematic,
+            Claim::CustomerDueDiligence(cdd_id) => Self::cdd_id_owner(cdd_id) == Some(target),
+            // Claims issued before investor unique IDs carry no commitment.
+            Claim::LegacyCustomerDueDiligence => true,
+            _ => false,
+        };
+
+        is_cdd_id_valid
+            && Self::is_identity_claim_not_expired_at(id_claim, exp_with_leeway)
             && (active_cdds.contains(&id_claim.claim_issuer)
-                || systematic_cdds.contains(&id_claim.claim_issuer)
+                || is_systematic
                 || inactive_not_expired_cdds
                     .iter()
                     .filter(|cdd| cdd.id == id_claim.claim_issuer)
@@ -1786,6 +1957,7 @@ impl<T: Trait> Module<T> {
         let sender_key = AccountKey::try_from(ensure_signed(origin)?.encode())?;
         let did = Context::current_identity_or::<Self>(&sender_key)?;
         let _grants_checked = Self::grant_check_only_master_key(&sender_key, did)?;
+        ensure!(!Self::is_did_closed(did), Error::<T>::DidIsClosed);
 
         if freeze {
             <IsDidFrozen>::insert(&did, true);
@@ -1974,6 +2146,9 @@ impl<T: Trait> Module<T> {
             .map_or(last_update_date, |id_claim| id_claim.issuance_date);
 
         let expiry = expiry.into_iter().map(|m| m.saturated_into::<u64>()).next();
+        if let Some(cdd_id) = claim.as_cdd_id().filter(|cdd_id| !cdd_id.is_default()) {
+            <CddIdOwner>::insert(cdd_id, target);
+        }
         let pk = Claim1stKey { target, claim_type };
         let sk = Claim2ndKey { issuer, scope };
         let id_claim = IdentityClaim {
@@ -1992,6 +2167,7 @@ impl<T: Trait> Module<T> {
     ///
     /// # Errors
     /// - 'UnAuthorizedCddProvider' is returned if `issuer` is not a CDD provider.
+    /// - 'InvalidCddId' or 'CddIdAlreadyInUse' if its commitment cannot be bound to `target`.
     fn unsafe_add_cdd_claim(
         target: IdentityId,
         claim: Claim,
@@ -2003,15 +2179,165 @@ impl<T: Trait> Module<T> {
             cdd_providers.contains(&issuer),
             Error::<T>::UnAuthorizedCddProvider
         );
+        let cdd_id = claim.as_cdd_id().ok_or(Error::<T>::InvalidCddId)?;
+        Self::ensure_cdd_id_available(cdd_id, Some(target))?;
 
         Self::unsafe_add_claim(target, claim, issuer, expiry);
         Ok(())
     }
 
+    /// It checks that `cdd_id` is not empty and that it is not bound to any identity other than
+    /// `target`. A `None` target means a new identity, so `cdd_id` must be unbound.
+    fn ensure_cdd_id_available(cdd_id: &CddId, target: Option<IdentityId>) -> DispatchResult {
+        ensure!(!cdd_id.is_default(), Error::<T>::InvalidCddId);
+        if let Some(owner) = Self::cdd_id_owner(cdd_id) {
+            ensure!(Some(owner) == target, Error::<T>::CddIdAlreadyInUse);
+        }
+        Ok(())
+    }
+
+    /// It checks if `investor_uid` opens the commitment of any CDD claim of `target`.
+    /// It allows to prove the link between an identity and a person.
+    pub fn is_cdd_id_opening_valid(target: IdentityId, investor_uid: InvestorUid) -> bool {
+        let cdd_id = CddId::new(investor_uid);
+        Self::cdd_id_owner(cdd_id) == Some(target)
+            && Self::fetch_base_claims(target, ClaimType::CustomerDueDiligence)
+                .any(|id_claim| id_claim.claim.as_cdd_id() == Some(&cdd_id))
+    }
+
     pub fn is_identity_exists(did: &IdentityId) -> bool {
         <DidRecords>::contains_key(did)
     }
 
+    /// It checks if `did` has been closed.
+    #[inline]
+    pub fn is_did_closed(did: IdentityId) -> bool {
+        <ClosedDids<T>>::contains_key(did)
+    }
+
+    /// It closes `did` identity without any previous security check.
+    /// All authorizations issued by `did` are revoked and its signing keys are frozen.
+    fn unsafe_close_identity(did: IdentityId) {
+        let did_sig = Signatory::from(did);
+        let mut targets: Vec<Signatory> = vec![];
+        <AuthorizationsGiven>::iter_prefix(&did_sig).for_each(|target| {
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        });
+        let given_auths = targets
+            .into_iter()
+            .flat_map(|target| {
+                <Authorizations<T>>::iter_prefix(target)
+                    .filter(|auth| auth.authorized_by == did_sig)
+                    .map(|auth| (target, auth.auth_id))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        given_auths.into_iter().for_each(|(target, auth_id)| {
+            Self::unsafe_remove_auth(&target, auth_id, &did_sig, true)
+        });
+
+        <IsDidFrozen>::insert(&did, true);
+        <ClosedDids<T>>::insert(&did, <pallet_timestamp::Module<T>>::get());
+        Self::deposit_event(RawEvent::DidClosed(did));
+    }
+
+    /// It moves all claims of `from` to `to`, except systematic ones.
+    /// Claims that `to` already has from the same issuer and scope are kept, but CDD commitments
+    /// of `from` are always bound to `to`.
+    fn unsafe_move_claims(from: IdentityId, to: IdentityId) {
+        let systematic_issuers = [
+            SystematicIssuers::TreasuryModule.as_id(),
+            SystematicIssuers::Committee.as_id(),
+            SystematicIssuers::CDDProvider.as_id(),
+        ];
+        let claim_types = [
+            ClaimType::Accredited,
+            ClaimType::Affiliate,
+            ClaimType::BuyLockup,
+            ClaimType::SellLockup,
+            ClaimType::CustomerDueDiligence,
+            ClaimType::KnowYourCustomer,
+            ClaimType::Jurisdiction,
+            ClaimType::Whitelisted,
+            ClaimType::BlackListed,
+            ClaimType::NoType,
+        ];
+
+        for claim_type in claim_types.iter().cloned() {
+            let from_pk = Claim1stKey {
+                target: from,
+                claim_type,
+            };
+            let to_pk = Claim1stKey {
+                target: to,
+                claim_type,
+            };
+            let id_claims = <Claims>::iter_prefix(&from_pk)
+                .filter(|id_claim| !systematic_issuers.contains(&id_claim.claim_issuer))
+                .collect::<Vec<_>>();
+
+            for id_claim in id_claims {
+                let scope = id_claim.claim.as_scope().cloned();
+                let issuer = id_claim.claim_issuer;
+                Self::unsafe_revoke_claim(from, claim_type, issuer, scope);
+
+                if let Some(cdd_id) = id_claim.claim.as_cdd_id() {
+                    <CddIdOwner>::insert(cdd_id, to);
+                }
+                let sk = Claim2ndKey { issuer, scope };
+                if !<Claims>::contains_key(&to_pk, &sk) {
+                    <Claims>::insert(&to_pk, &sk, id_claim.clone());
+                    Self::deposit_event(RawEvent::ClaimAdded(to, id_claim));
+                }
+            }
+        }
+    }
+
+    /// It moves all signing items of `from` to `to`.
+    /// Master key of `from` keeps linked to it.
+    fn unsafe_move_signing_items(from: IdentityId, to: IdentityId) {
+        let to_record = <DidRecords>::get(to);
+        let to_signer = Signatory::from(to);
+        let to_master = Signatory::from(to_record.master_key);
+        let signing_items = <DidRecords>::get(from).signing_items;
+        if signing_items.is_empty() {
+            return;
+        }
+
+        let signers = signing_items.iter().map(|si| si.signer).collect::<Vec<_>>();
+        signers.iter().for_each(|signer| {
+            if let Signatory::AccountKey(ref key) = signer {
+                Self::unlink_key_from_did(key, from);
+            }
+        });
+        <DidRecords>::mutate(from, |record| {
+            (*record).remove_signing_items(&signers);
+        });
+        Self::deposit_event(RawEvent::SigningItemsRemoved(from, signers));
+
+        let new_items = signing_items
+            .into_iter()
+            .filter(|si| si.signer != to_signer && si.signer != to_master)
+            .filter(|si| {
+                !to_record
+                    .signing_items
+                    .iter()
+                    .any(|to_si| to_si.signer == si.signer)
+            })
+            .collect::<Vec<_>>();
+        for si in &new_items {
+            if let Signatory::AccountKey(ref key) = si.signer {
+                Self::link_key_to_did(key, si.signer_type, to);
+            }
+        }
+        <DidRecords>::mutate(to, |record| {
+            (*record).add_signing_items(&new_items);
+        });
+        Self::deposit_event(RawEvent::SigningItemsAdded(to, new_items));
+    }
+
     /// It removes a claim from `target` which was issued by `issuer` without any security check.
     fn unsafe_revoke_claim(
         target: IdentityId,
@@ -2096,6 +2422,13 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// It returns true if `did` owns a registered ticker or an asset.
+    fn owns_tickers_or_assets(did: IdentityId) -> bool {
+        let signer = Signatory::from(did);
+        !Self::get_filtered_links(signer, false, Some(LinkType::TickerOwnership)).is_empty()
+            || !Self::get_filtered_links(signer, false, Some(LinkType::AssetOwnership)).is_empty()
+    }
+
     /// Use to get the filtered link data for a given signatory
     /// - if link_type is None then return links data on the basis of the `allow_expired` boolean
     /// - if link_type is Some(value) then return filtered links on the value basis type in conjunction
@@ -2141,6 +2474,57 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Use to get a page of the pending authorizations of a given signatory.
+    /// - Authorizations are filtered by type, issuer and expiry as described by `filter`.
+    /// - Expired authorizations are only included when `filter.allow_expired` is set.
+    /// - The page is ordered by authorization id and holds at most `filter.limit` items, capped
+    ///   at `MAX_AUTHORIZATIONS_PAGE_SIZE`.
+    pub fn get_authorizations(
+        signatory: Signatory,
+        filter: AuthorizationFilter<Signatory, T::Moment>,
+    ) -> Vec<Authorization<T::Moment>> {
+        let now = <pallet_timestamp::Module<T>>::get();
+        let limit = filter.limit.map_or(MAX_AUTHORIZATIONS_PAGE_SIZE, |limit| {
+            limit.min(MAX_AUTHORIZATIONS_PAGE_SIZE)
+        }) as usize;
+
+        let mut auths = <Authorizations<T>>::iter_prefix(signatory)
+            .filter(|auth| {
+                filter.start_after.map_or(true, |id| auth.auth_id > id)
+                    && filter.auth_type.map_or(true, |auth_type| {
+                        auth_type == Self::authorization_type(&auth.authorization_data)
+                    })
+                    && filter
+                        .authorized_by
+                        .map_or(true, |issuer| issuer == auth.authorized_by)
+                    && filter
+                        .expires_before
+                        .map_or(true, |before| auth.expiry.map_or(false, |e| e < before))
+                    && (filter.allow_expired || auth.expiry.map_or(true, |e| e >= now))
+            })
+            .collect::<Vec<Authorization<T::Moment>>>();
+        auths.sort_by_key(|auth| auth.auth_id);
+        auths.truncate(limit);
+        auths
+    }
+
+    fn authorization_type(data: &AuthorizationData) -> AuthorizationType {
+        match data {
+            AuthorizationData::AttestMasterKeyRotation(..) => {
+                AuthorizationType::AttestMasterKeyRotation
+            }
+            AuthorizationData::RotateMasterKey(..) => AuthorizationType::RotateMasterKey,
+            AuthorizationData::TransferTicker(..) => AuthorizationType::TransferTicker,
+            AuthorizationData::AddMultiSigSigner => AuthorizationType::AddMultiSigSigner,
+            AuthorizationData::TransferAssetOwnership(..) => {
+                AuthorizationType::TransferAssetOwnership
+            }
+            AuthorizationData::JoinIdentity(..) => AuthorizationType::JoinIdentity,
+            AuthorizationData::Custom(..) => AuthorizationType::Custom,
+            AuthorizationData::NoData => AuthorizationType::NoData,
+        }
+    }
+
     pub fn get_did_status(dids: Vec<IdentityId>) -> Vec<DidStatus> {
         let mut result = Vec::with_capacity(dids.len());
         dids.into_iter().for_each(|did| {
@@ -2260,7 +2644,7 @@ impl<T: Trait> IdentityTrait for Module<T> {
         targets.iter().for_each(|new_member| {
             Self::unsafe_add_claim(
                 *new_member,
-                Claim::CustomerDueDiligence,
+                Claim::CustomerDueDiligence(CddId::default()),
                 issuer.as_id(),
                 None,
             )
@@ -16,6 +16,7 @@
 
 // Modified by Polymath Inc - 23rd Febuary 2020
 // - Added ability to update slashing parameters by introducing `set_slashing_params()` dispatchable
+// - Added selectable slashing curves and per-validator offline reports.
 
 //! # I'm online Module
 //!
@@ -40,10 +41,12 @@
 //!
 //! ### Dispatchable functions
 //! * [set_slashing_params](Module::set_slashing_params) - Set slashing params.
+//! * [set_slashing_curve](Module::set_slashing_curve) - Set the slashing curve.
 //!
 //! ### Public Functions
 //!
 //! - `is_online` - True if the validator sent a heartbeat in the current session.
+//! - `offline_reports` - The liveness of a validator in each of the recent sessions.
 //!
 //! ## Usage
 //!
@@ -243,6 +246,38 @@ pub struct OfflineSlashingParams {
     pub max_slash_percent: u32,
 }
 
+/// The curve used to compute the fraction slashed from offline validators.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum OfflineSlashingCurve {
+    /// The curve defined by `SlashingParams`.
+    Params,
+    /// Slashes in proportion to the fraction of offline validators, up to the given fraction when
+    /// all validators are offline.
+    Linear(Perbill),
+    /// Pairs of a fraction of offline validators and the fraction slashed once it is reached, in
+    /// ascending order of the former.
+    Stepped(Vec<(Perbill, Perbill)>),
+    /// Offline validators are not slashed but are still chilled.
+    NoneWithChill,
+}
+
+impl Default for OfflineSlashingCurve {
+    fn default() -> Self {
+        OfflineSlashingCurve::Params
+    }
+}
+
+/// The liveness of a validator during a session.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OfflineReport {
+    /// Whether a heartbeat was received from the validator.
+    pub heartbeat_received: bool,
+    /// The number of blocks authored by the validator.
+    pub authored_blocks: u32,
+}
+
 pub trait Trait: frame_system::Trait + pallet_session::historical::Trait {
     /// The identifier type for an authority.
     type AuthorityId: Member + Parameter + RuntimeAppPublic + Default + Ord;
@@ -289,6 +324,8 @@ decl_event!(
         /// Newly updated slashing params.
         /// OfflineSlashingParams
         SlashingParamsUpdated(OfflineSlashingParams),
+        /// Newly selected slashing curve.
+        SlashingCurveUpdated(OfflineSlashingCurve),
     }
 );
 
@@ -317,6 +354,17 @@ decl_storage! {
 
         /// Config parameters for slash fraction
         SlashingParams get(fn slashing_params) config(): OfflineSlashingParams;
+
+        /// The curve used in calculating slash fraction.
+        SlashingCurve get(fn slashing_curve) config(): OfflineSlashingCurve;
+
+        /// Number of sessions for which offline reports are kept.
+        ReportHistoryDepth get(fn report_history_depth) config(): SessionIndex = 84;
+
+        /// For each session index, the liveness of each validator of the session.
+        OfflineReports get(fn offline_report):
+            double_map hasher(twox_64_concat) SessionIndex, hasher(twox_64_concat) T::ValidatorId
+            => Option<OfflineReport>;
     }
     add_extra_genesis {
         config(keys): Vec<T::AuthorityId>;
@@ -391,6 +439,23 @@ decl_module! {
             Self::deposit_event(RawEvent::SlashingParamsUpdated(params));
         }
 
+        /// Set the curve used in calculating `slash_fraction`.
+        /// Only Governance committee is allowed to set the curve.
+        #[weight = SimpleDispatchInfo::FixedOperational(100_000)]
+        fn set_slashing_curve(origin, curve: OfflineSlashingCurve) {
+            if let OfflineSlashingCurve::Stepped(steps) = &curve {
+                ensure!(
+                    steps.windows(2).all(|pair| pair[0].0 < pair[1].0),
+                    Error::<T>::InvalidSlashingParam
+                );
+            }
+
+            T::CommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::NotAuthorised)?;
+
+            SlashingCurve::put(&curve);
+            Self::deposit_event(RawEvent::SlashingCurveUpdated(curve));
+        }
+
         // Runs after every block.
         fn offchain_worker(now: T::BlockNumber) {
             // Only send messages if we are a potential validator.
@@ -463,6 +528,18 @@ impl<T: Trait> Module<T> {
         <ReceivedHeartbeats>::contains_key(&current_session, &authority_index)
     }
 
+    /// Returns the liveness of `validator` in each of the last `ReportHistoryDepth` sessions in
+    /// which it was a validator, in ascending order of session index.
+    pub fn offline_reports(validator: T::ValidatorId) -> Vec<(SessionIndex, OfflineReport)> {
+        let current_session = <pallet_session::Module<T>>::current_index();
+        let first_session = current_session.saturating_sub(Self::report_history_depth());
+        (first_session..current_session)
+            .filter_map(|session| {
+                <OfflineReports<T>>::get(session, &validator).map(|report| (session, report))
+            })
+            .collect()
+    }
+
     /// Note that the given authority has authored a block in the current session.
     fn note_authorship(author: T::ValidatorId) {
         let current_session = <pallet_session::Module<T>>::current_index();
@@ -636,6 +713,22 @@ impl<T: Trait> pallet_session::OneSessionHandler<T::AccountId> for Module<T> {
         let keys = Keys::<T>::get();
         let current_validators = <pallet_session::Module<T>>::validators();
 
+        // Keep the liveness of each validator for the offline reports and forget the reports
+        // that fell out of the history.
+        for (index, id) in current_validators.iter().enumerate() {
+            let report = OfflineReport {
+                heartbeat_received: <ReceivedHeartbeats>::contains_key(
+                    &session_index,
+                    &(index as u32),
+                ),
+                authored_blocks: <AuthoredBlocks<T>>::get(&session_index, id),
+            };
+            <OfflineReports<T>>::insert(&session_index, id, report);
+        }
+        if let Some(expired) = session_index.checked_sub(Self::report_history_depth()) {
+            <OfflineReports<T>>::remove_prefix(&expired);
+        }
+
         let offenders = current_validators
             .into_iter()
             .enumerate()
@@ -757,11 +850,26 @@ impl<T: Trait, Offender: Clone> Offence<Offender> for UnresponsivenessOffence<T,
         self.session_index
     }
 
+    /// Follows the selected `OfflineSlashingCurve`. Under `OfflineSlashingCurve::Params`:
     /// min((constant * (k - (n / max_offline_percent + 1))) / n, 1) * (max_slash_percent / 100)
     /// basically, `max_offline_percent`% can be offline with no slash, but after that, it linearly
     /// climbs up to `max_slash_percent`%
     fn slash_fraction(offenders: u32, validator_set_count: u32) -> Perbill {
-        let params: OfflineSlashingParams = <Module<T>>::slashing_params();
+        let offline = || Perbill::from_rational_approximation(offenders, validator_set_count);
+        let params = match <Module<T>>::slashing_curve() {
+            OfflineSlashingCurve::Params => <Module<T>>::slashing_params(),
+            OfflineSlashingCurve::Linear(max_slash) => return offline().saturating_mul(max_slash),
+            OfflineSlashingCurve::Stepped(steps) => {
+                let offline = offline();
+                return steps
+                    .into_iter()
+                    .rev()
+                    .find(|(threshold, _)| offline >= *threshold)
+                    .map(|(_, slash)| slash)
+                    .unwrap_or_default();
+            }
+            OfflineSlashingCurve::NoneWithChill => return Perbill::zero(),
+        };
         if let Some(threshold) =
             offenders.checked_sub(validator_set_count / params.max_offline_percent + 1)
         {
@@ -461,3 +461,104 @@ fn should_not_send_a_report_if_already_online() {
         );
     });
 }
+
+#[test]
+fn should_select_slashing_curve() {
+    new_test_ext().execute_with(|| {
+        let root = Origin::system(frame_system::RawOrigin::Root);
+        let slash_fraction =
+            |offenders| UnresponsivenessOffence::<Runtime, u64>::slash_fraction(offenders, 50);
+
+        assert_err!(
+            ImOnline::set_slashing_curve(
+                Origin::signed(1),
+                OfflineSlashingCurve::Linear(Perbill::from_percent(10))
+            ),
+            Error::<Runtime>::NotAuthorised
+        );
+        assert_ok!(ImOnline::set_slashing_curve(
+            root.clone(),
+            OfflineSlashingCurve::Linear(Perbill::from_percent(10))
+        ));
+        assert_eq!(slash_fraction(25), Perbill::from_percent(5));
+
+        // Steps must be in ascending order.
+        assert_err!(
+            ImOnline::set_slashing_curve(
+                root.clone(),
+                OfflineSlashingCurve::Stepped(vec![
+                    (Perbill::from_percent(40), Perbill::from_percent(5)),
+                    (Perbill::from_percent(10), Perbill::from_percent(1)),
+                ])
+            ),
+            Error::<Runtime>::InvalidSlashingParam
+        );
+        assert_ok!(ImOnline::set_slashing_curve(
+            root.clone(),
+            OfflineSlashingCurve::Stepped(vec![
+                (Perbill::from_percent(10), Perbill::from_percent(1)),
+                (Perbill::from_percent(40), Perbill::from_percent(5)),
+            ])
+        ));
+        assert_eq!(slash_fraction(4), Perbill::zero());
+        assert_eq!(slash_fraction(5), Perbill::from_percent(1));
+        assert_eq!(slash_fraction(20), Perbill::from_percent(5));
+
+        assert_ok!(ImOnline::set_slashing_curve(
+            root,
+            OfflineSlashingCurve::NoneWithChill
+        ));
+        assert_eq!(slash_fraction(50), Perbill::zero());
+    });
+}
+
+#[test]
+fn should_keep_offline_reports() {
+    use pallet_authorship::EventHandler;
+
+    new_test_ext().execute_with(|| {
+        let block = 1;
+        advance_session();
+        VALIDATORS.with(|l| *l.borrow_mut() = Some(vec![1, 2, 3, 4, 5, 6]));
+        advance_session();
+
+        // Validator 2 authors a block in session 2.
+        ImOnline::note_author(2);
+        advance_session();
+
+        // Validator 1 sends a heartbeat in session 3.
+        assert_ok!(heartbeat(block, 3, 0, 1.into()));
+        advance_session();
+
+        assert_eq!(
+            ImOnline::offline_reports(1),
+            vec![
+                (2, OfflineReport::default()),
+                (
+                    3,
+                    OfflineReport {
+                        heartbeat_received: true,
+                        authored_blocks: 0,
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            ImOnline::offline_reports(2),
+            vec![
+                (
+                    2,
+                    OfflineReport {
+                        heartbeat_received: false,
+                        authored_blocks: 1,
+                    }
+                ),
+                (3, OfflineReport::default()),
+            ]
+        );
+        assert_eq!(
+            ImOnline::offline_reports(5),
+            vec![(3, OfflineReport::default())]
+        );
+    });
+}
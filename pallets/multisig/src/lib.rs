@@ -31,7 +31,17 @@
 //!
 //! - **multisig**: a special type of account that can do tranaction only if at least `n` of its `m`
 //! signers approve.
-//! - **proposal**: a general transaction that the multisig can vote on and accept.
+//! - **signer weight**: the number of approvals a signer's vote counts for. Signers have a weight
+//! of 1 unless a different weight was given when they were authorized, so the number of required
+//! signatures of a multisig with unweighted signers is simply the number of approving signers.
+//! - **proposal**: a general transaction that the multisig can vote on and accept. A proposal
+//! can optionally expire, after which it can no longer be approved. It is closed as rejected once
+//! enough signers vote against it for approval to become impossible, and its creator can cancel it
+//! while it is open.
+//! - **proposal details**: an optional human-readable description of a proposal and a linked
+//! document giving signers the context of the call they vote on.
+//! - **execution result**: whether the call of an approved proposal succeeded. The error of a
+//! failed call is recorded so that it can be inspected after the proposal is closed.
 //!
 //! ## Interface
 //!
@@ -46,6 +56,10 @@
 //! - `create_proposal_as_key` - Creates a multisig proposal given the signer's account key.
 //! - `approve_as_identity` - Approves a multisig proposal given the signer's identity.
 //! - `approve_as_key` - Approves a multisig proposal given the signer's account key.
+//! - `reject_as_identity` - Rejects a multisig proposal given the signer's identity.
+//! - `reject_as_key` - Rejects a multisig proposal given the signer's account key.
+//! - `cancel_proposal_as_identity` - Cancels a multisig proposal created by the signer's identity.
+//! - `cancel_proposal_as_key` - Cancels a multisig proposal created by the signer's account key.
 //! - `accept_multisig_signer_as_identity` - Accepts a multisig signer authorization given the
 //! signer's identity.
 //! - `accept_multisig_signer_as_key` - Accepts a multisig signer authorization given the signer's
@@ -71,6 +85,8 @@
 //! - `create_or_approve_proposal` - Creates or approves a multisig proposal.
 //! - `approve_for` - Approves a multisig proposal and executes it if enough signatures have been
 //! received.
+//! - `reject_for` - Rejects a multisig proposal and closes it if it can no longer be approved.
+//! - `cancel_for` - Cancels a multisig proposal on behalf of its creator.
 //! - `_accept_multisig_signer` - Accepts and processes an addition of a signer to a multisig.
 //! - `get_next_multisig_address` - Gets the next available multisig account ID.
 //! - `get_multisig_address` - Constructs a multisig account given a nonce.
@@ -78,6 +94,8 @@
 //! not.
 //! - `is_changing_signers_allowed` - Checks whether changing the list of signers is allowed in a
 //! multisig.
+//! - `get_open_proposals` - Lists the open proposals of a multisig along with their current
+//! approvers.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -96,11 +114,14 @@ use pallet_transaction_payment::{CddAndFeeDetails, ChargeTxFee};
 use polymesh_common_utilities::{
     identity::{LinkedKeyInfo, Trait as IdentityTrait},
     multisig::AddSignerMultiSig,
+    protocol_fee::{ChargeProtocolFee, ProtocolOp},
     Context,
 };
 use polymesh_primitives::{
-    AccountKey, AuthorizationData, AuthorizationError, IdentityId, Signatory,
+    AccountKey, AuthorizationData, AuthorizationError, Document, IdentityId, Signatory,
 };
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::traits::{Dispatchable, Hash};
 use sp_std::{convert::TryFrom, prelude::*};
 type Identity<T> = identity::Module<T>;
@@ -111,6 +132,70 @@ pub type CreateMultisigAccountResult<T> =
 /// Either the ID of a successfully created proposal or an error.
 pub type CreateProposalResult = sp_std::result::Result<u64, DispatchError>;
 
+/// The status of a multisig proposal.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    /// The proposal is open for voting. It is closed as `Expired` by the first approval after
+    /// its expiry.
+    Active,
+    /// The proposal was approved and its call executed successfully.
+    ExecutionSuccessful,
+    /// The proposal was approved but its call failed. The error is kept in
+    /// `ProposalExecutionErrors`.
+    ExecutionFailed,
+    /// The proposal was closed because it could no longer be approved.
+    Rejected,
+    /// The proposal was cancelled by its creator.
+    Cancelled,
+    /// The proposal was closed because it was not approved before its expiry.
+    Expired,
+}
+
+impl Default for ProposalStatus {
+    fn default() -> Self {
+        ProposalStatus::Active
+    }
+}
+
+/// The maximum length in bytes of the description of a proposal.
+pub const MAX_DESCRIPTION_LENGTH: usize = 1_024;
+
+/// Human-readable context of a multisig proposal.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ProposalDetails {
+    /// Description of what the proposal does. It is at most `MAX_DESCRIPTION_LENGTH` bytes long.
+    pub description: Vec<u8>,
+    /// Optional document supporting the proposal.
+    pub document: Option<Document>,
+}
+
+/// The maximum number of open proposals returned by `get_open_proposals`.
+pub const MAX_OPEN_PROPOSALS_PAGE_SIZE: u32 = 100;
+
+/// An open multisig proposal as returned by the RPC.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OpenProposal<Proposal, Moment> {
+    /// The proposal ID.
+    pub id: u64,
+    /// The call of the proposal. The RPC returns it SCALE encoded.
+    pub proposal: Proposal,
+    /// The description and document of the proposal, if any were given.
+    pub details: Option<ProposalDetails>,
+    /// The signer that created the proposal.
+    pub creator: Option<Signatory>,
+    /// Current signers that approved the proposal.
+    pub approvers: Vec<Signatory>,
+    /// Weight of the votes in favor of the proposal.
+    pub approvals: u64,
+    /// Weight of the votes against the proposal.
+    pub rejections: u64,
+    /// The moment after which the proposal can no longer be approved.
+    pub expiry: Option<Moment>,
+}
+
 /// The multisig trait.
 pub trait Trait: frame_system::Trait + IdentityTrait {
     /// The overarching event type.
@@ -125,8 +210,11 @@ decl_storage! {
         pub MultiSigSigners: double_map hasher(twox_64_concat) T::AccountId, hasher(blake2_128_concat) Signatory => Signatory;
         /// Number of approved/accepted signers of a multisig.
         pub NumberOfSigners get(fn number_of_signers): map hasher(twox_64_concat) T::AccountId => u64;
-        /// Confirmations required before processing a multisig tx.
+        /// Confirmations required before processing a multisig tx. This is the total weight of the
+        /// approving signers.
         pub MultiSigSignsRequired get(fn ms_signs_required): map hasher(twox_64_concat) T::AccountId => u64;
+        /// Weights of multisig signers different from 1. (multisig, signer) => weight.
+        pub SignerWeights get(fn signer_weights): double_map hasher(twox_64_concat) T::AccountId, hasher(blake2_128_concat) Signatory => Option<u64>;
         /// Number of transactions proposed in a multisig. Used as tx id; starts from 0.
         pub MultiSigTxDone get(fn ms_tx_done): map hasher(twox_64_concat) T::AccountId => u64;
         /// Proposals presented for voting to a multisig (multisig, proposal id) => Option<T::Proposal>.
@@ -134,7 +222,7 @@ decl_storage! {
         /// A mapping of proposals to their IDs.
         pub ProposalIds get(fn proposal_ids):
             double_map hasher(twox_64_concat) T::AccountId, hasher(blake2_256) T::Proposal => Option<u64>;
-        /// Number of votes in favor of a tx. Mapping from (multisig, tx id) => no. of approvals.
+        /// Weight of the votes in favor of a tx. Mapping from (multisig, tx id) => approvals.
         pub TxApprovals get(fn tx_approvals): map hasher(twox_64_concat) (T::AccountId, u64) => u64;
         /// Individual multisig signer votes. (multi sig, signer, proposal) => vote.
         pub Votes get(fn votes): map hasher(blake2_128_concat) (T::AccountId, Signatory, u64) => bool;
@@ -144,6 +232,20 @@ decl_storage! {
         pub KeyToMultiSig get(fn key_to_ms): map hasher(blake2_128_concat) AccountKey => T::AccountId;
         /// Know whether the proposal is closed or not
         pub ProposalClosed get(fn is_proposal_closed): map hasher(twox_64_concat) (T::AccountId, u64) => bool;
+        /// Weight of the votes against a tx. Mapping from (multisig, tx id) => rejections.
+        pub TxRejections get(fn tx_rejections): map hasher(twox_64_concat) (T::AccountId, u64) => u64;
+        /// Individual multisig signer rejections. (multisig, signer, proposal) => rejected.
+        pub RejectionVotes get(fn rejection_votes): map hasher(blake2_128_concat) (T::AccountId, Signatory, u64) => bool;
+        /// The moment after which a proposal can no longer be approved. (multisig, tx id) => expiry.
+        pub ProposalExpiry get(fn proposal_expiry): map hasher(twox_64_concat) (T::AccountId, u64) => Option<T::Moment>;
+        /// The signer that created a proposal. (multisig, tx id) => creator.
+        pub ProposalCreator get(fn proposal_creator): map hasher(twox_64_concat) (T::AccountId, u64) => Option<Signatory>;
+        /// The status of a proposal. (multisig, tx id) => status.
+        pub ProposalStatuses get(fn proposal_status): map hasher(twox_64_concat) (T::AccountId, u64) => ProposalStatus;
+        /// The description and document of a proposal. (multisig, tx id) => details.
+        pub ProposalDetailsOf get(fn proposal_details): map hasher(twox_64_concat) (T::AccountId, u64) => Option<ProposalDetails>;
+        /// The error returned by the call of a proposal that failed. (multisig, tx id) => error.
+        pub ProposalExecutionErrors get(fn proposal_execution_error): map hasher(twox_64_concat) (T::AccountId, u64) => Option<DispatchError>;
     }
 }
 
@@ -158,19 +260,45 @@ decl_module! {
         ///
         /// # Arguments
         /// * `signers` - Signers of the multisig (They need to accept authorization before they are actually added).
-        /// * `sigs_required` - Number of sigs required to process a multi-sig tx.
+        /// * `sigs_required` - Number of sigs required to process a multi-sig tx. If signers are
+        /// weighted, this is the total weight of the approving signers.
+        /// * `signer_weights` - Optional weights of `signers`, in the same order. All signers have
+        /// a weight of 1 if it is `None`.
         #[weight = SimpleDispatchInfo::FixedNormal(250_000)]
-        pub fn create_multisig(origin, signers: Vec<Signatory>, sigs_required: u64) -> DispatchResult {
+        pub fn create_multisig(
+            origin,
+            signers: Vec<Signatory>,
+            sigs_required: u64,
+            signer_weights: Option<Vec<u64>>
+        ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             ensure!(!signers.is_empty(), Error::<T>::NoSigners);
-            ensure!(u64::try_from(signers.len()).unwrap_or_default() >= sigs_required && sigs_required > 0,
+            let total_weight = match &signer_weights {
+                Some(weights) => {
+                    ensure!(weights.len() == signers.len(), Error::<T>::SignerWeightsMismatch);
+                    ensure!(weights.iter().all(|w| *w > 0), Error::<T>::InvalidSignerWeight);
+                    weights.iter().fold(0u64, |total, w| total.saturating_add(*w))
+                }
+                None => u64::try_from(signers.len()).unwrap_or_default(),
+            };
+            ensure!(total_weight >= sigs_required && sigs_required > 0,
                 Error::<T>::RequiredSignaturesOutOfBounds
             );
+            let sender_key = AccountKey::try_from(sender.encode())?;
             let account_id = Self::create_multisig_account(
                 sender.clone(),
                 signers.as_slice(),
                 sigs_required
             )?;
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee(
+                &Signatory::AccountKey(sender_key),
+                ProtocolOp::MultiSigCreateMultiSig
+            )?;
+            if let Some(weights) = signer_weights {
+                for (signer, weight) in signers.iter().zip(weights.into_iter()) {
+                    Self::unsafe_set_signer_weight(&account_id, signer, weight);
+                }
+            }
             let caller_did = Context::current_identity_or::<Identity<T>>(&(AccountKey::try_from(sender.encode())?))?;
             Self::deposit_event(RawEvent::MultiSigCreated(caller_did, account_id, sender, signers, sigs_required));
             Ok(())
@@ -181,18 +309,24 @@ decl_module! {
         /// # Arguments
         /// * `multisig` - MultiSig address.
         /// * `proposal` - Proposal to be voted on.
+        /// * `expiry` - Optional moment after which the proposal can no longer be approved. It is
+        /// ignored if the proposal already exists.
+        /// * `details` - Optional description and document of the proposal. It is ignored if the
+        /// proposal already exists.
         /// If this is 1 out of `m` multisig, the proposal will be immediately executed.
         #[weight = SimpleDispatchInfo::FixedNormal(750_000)]
         pub fn create_or_approve_proposal_as_identity(
             origin,
             multisig: T::AccountId,
-            proposal: Box<T::Proposal>
+            proposal: Box<T::Proposal>,
+            expiry: Option<T::Moment>,
+            details: Option<ProposalDetails>
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let sender_key = AccountKey::try_from(sender.encode())?;
             let sender_did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
             let sender_signer = Signatory::from(sender_did);
-            Self::create_or_approve_proposal(multisig, sender_signer, proposal)
+            Self::create_or_approve_proposal(multisig, sender_signer, proposal, expiry, details)
         }
 
         /// Creates a multisig proposal if it hasn't been created or approves it if it has.
@@ -200,16 +334,22 @@ decl_module! {
         /// # Arguments
         /// * `multisig` - MultiSig address.
         /// * `proposal` - Proposal to be voted on.
+        /// * `expiry` - Optional moment after which the proposal can no longer be approved. It is
+        /// ignored if the proposal already exists.
+        /// * `details` - Optional description and document of the proposal. It is ignored if the
+        /// proposal already exists.
         /// If this is 1 out of `m` multisig, the proposal will be immediately executed.
         #[weight = SimpleDispatchInfo::FixedNormal(750_000)]
         pub fn create_or_approve_proposal_as_key(
             origin,
             multisig: T::AccountId,
-            proposal: Box<T::Proposal>
+            proposal: Box<T::Proposal>,
+            expiry: Option<T::Moment>,
+            details: Option<ProposalDetails>
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let sender_signer = Signatory::from(AccountKey::try_from(sender.encode())?);
-            Self::create_or_approve_proposal(multisig, sender_signer, proposal)
+            Self::create_or_approve_proposal(multisig, sender_signer, proposal, expiry, details)
         }
 
         /// Creates a multisig proposal
@@ -217,15 +357,23 @@ decl_module! {
         /// # Arguments
         /// * `multisig` - MultiSig address.
         /// * `proposal` - Proposal to be voted on.
+        /// * `expiry` - Optional moment after which the proposal can no longer be approved.
+        /// * `details` - Optional description and document of the proposal.
         /// If this is 1 out of `m` multisig, the proposal will be immediately executed.
         #[weight = SimpleDispatchInfo::FixedNormal(250_000)]
-        pub fn create_proposal_as_identity(origin, multisig: T::AccountId, proposal: Box<T::Proposal>) -> DispatchResult {
+        pub fn create_proposal_as_identity(
+            origin,
+            multisig: T::AccountId,
+            proposal: Box<T::Proposal>,
+            expiry: Option<T::Moment>,
+            details: Option<ProposalDetails>
+        ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let sender_key = AccountKey::try_from(sender.encode())?;
             let sender_did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
 
             let sender_signer = Signatory::from(sender_did);
-            Self::create_proposal(multisig, sender_signer, proposal)?;
+            Self::create_proposal(multisig, sender_signer, proposal, expiry, details)?;
             Ok(())
         }
 
@@ -234,12 +382,20 @@ decl_module! {
         /// # Arguments
         /// * `multisig` - MultiSig address.
         /// * `proposal` - Proposal to be voted on.
+        /// * `expiry` - Optional moment after which the proposal can no longer be approved.
+        /// * `details` - Optional description and document of the proposal.
         /// If this is 1 out of `m` multisig, the proposal will be immediately executed.
         #[weight = SimpleDispatchInfo::FixedNormal(250_000)]
-        pub fn create_proposal_as_key(origin, multisig: T::AccountId, proposal: Box<T::Proposal>) -> DispatchResult {
+        pub fn create_proposal_as_key(
+            origin,
+            multisig: T::AccountId,
+            proposal: Box<T::Proposal>,
+            expiry: Option<T::Moment>,
+            details: Option<ProposalDetails>
+        ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let sender_signer = Signatory::from(AccountKey::try_from(sender.encode())?);
-            Self::create_proposal(multisig, sender_signer, proposal)?;
+            Self::create_proposal(multisig, sender_signer, proposal, expiry, details)?;
             Ok(())
         }
 
@@ -271,6 +427,61 @@ decl_module! {
             Self::approve_for(multisig, signer, proposal_id)
         }
 
+        /// Rejects a multisig proposal using the caller's identity.
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Proposal id to reject.
+        /// If approval becomes impossible, the proposal will be closed as rejected.
+        #[weight = SimpleDispatchInfo::FixedNormal(400_000)]
+        pub fn reject_as_identity(origin, multisig: T::AccountId, proposal_id: u64) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let sender_key = AccountKey::try_from(sender.encode())?;
+            let sender_did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+            let signer = Signatory::from(sender_did);
+            Self::reject_for(multisig, signer, proposal_id)
+        }
+
+        /// Rejects a multisig proposal using the caller's signing key (`AccountId`).
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Proposal id to reject.
+        /// If approval becomes impossible, the proposal will be closed as rejected.
+        #[weight = SimpleDispatchInfo::FixedNormal(400_000)]
+        pub fn reject_as_key(origin, multisig: T::AccountId, proposal_id: u64) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let signer = Signatory::from(AccountKey::try_from(sender.encode())?);
+            Self::reject_for(multisig, signer, proposal_id)
+        }
+
+        /// Cancels a multisig proposal that was created using the caller's identity.
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Proposal id to cancel.
+        #[weight = SimpleDispatchInfo::FixedNormal(250_000)]
+        pub fn cancel_proposal_as_identity(origin, multisig: T::AccountId, proposal_id: u64) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let sender_key = AccountKey::try_from(sender.encode())?;
+            let sender_did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+            let signer = Signatory::from(sender_did);
+            Self::cancel_for(multisig, signer, proposal_id)
+        }
+
+        /// Cancels a multisig proposal that was created using the caller's signing key
+        /// (`AccountId`).
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Proposal id to cancel.
+        #[weight = SimpleDispatchInfo::FixedNormal(250_000)]
+        pub fn cancel_proposal_as_key(origin, multisig: T::AccountId, proposal_id: u64) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let signer = Signatory::from(AccountKey::try_from(sender.encode())?);
+            Self::cancel_for(multisig, signer, proposal_id)
+        }
+
         /// Accepts a multisig signer authorization given to signer's identity.
         ///
         /// # Arguments
@@ -300,11 +511,15 @@ decl_module! {
         ///
         /// # Arguments
         /// * `signer` - Signatory to add.
+        /// * `weight` - Optional weight of the signer. It defaults to 1.
         #[weight = SimpleDispatchInfo::FixedNormal(400_000)]
-        pub fn add_multisig_signer(origin, signer: Signatory) -> DispatchResult {
+        pub fn add_multisig_signer(origin, signer: Signatory, weight: Option<u64>) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             ensure!(<MultiSigSignsRequired<T>>::contains_key(&sender), Error::<T>::NoSuchMultisig);
+            ensure!(weight != Some(0), Error::<T>::InvalidSignerWeight);
+            ensure!(!<MultiSigSigners<T>>::contains_key(&sender, &signer), Error::<T>::AlreadyASigner);
             let sender_signer = Signatory::from(AccountKey::try_from(sender.encode())?);
+            Self::unsafe_set_signer_weight(&sender, &signer, weight.unwrap_or(1));
             Self::unsafe_add_auth_for_signers(sender_signer, signer, sender);
             Ok(())
         }
@@ -319,7 +534,8 @@ decl_module! {
             ensure!(<MultiSigSignsRequired<T>>::contains_key(&sender), Error::<T>::NoSuchMultisig);
             ensure!(<MultiSigSigners<T>>::contains_key(&sender, &signer), Error::<T>::NotASigner);
             ensure!(
-                <NumberOfSigners<T>>::get(&sender) > <MultiSigSignsRequired<T>>::get(&sender),
+                Self::total_signer_weight(&sender).saturating_sub(Self::signer_weight(&sender, &signer))
+                    >= <MultiSigSignsRequired<T>>::get(&sender),
                 Error::<T>::NotEnoughSigners
             );
             ensure!(Self::is_changing_signers_allowed(&sender), Error::<T>::ChangeNotAllowed);
@@ -359,6 +575,9 @@ decl_module! {
             ensure!(<Identity<T>>::is_master_key(sender_did, &sender_key), Error::<T>::NotMasterKey);
             let multisig_signer = Signatory::from(AccountKey::try_from(multisig.encode())?);
             for signer in signers {
+                if !<MultiSigSigners<T>>::contains_key(&multisig, &signer) {
+                    Self::unsafe_set_signer_weight(&multisig, &signer, 1);
+                }
                 Self::unsafe_add_auth_for_signers(multisig_signer, signer, multisig.clone());
             }
             Ok(())
@@ -396,15 +615,18 @@ decl_module! {
             ensure!(Self::is_changing_signers_allowed(&multisig), Error::<T>::ChangeNotAllowed);
             let signers_len:u64 = u64::try_from(signers.len()).unwrap_or_default();
 
-            // NB: the below check can be underflowed but that doesnt matter
-            // because the checks in the next loop will fail in that case.
+            let mut removed_weight = 0u64;
+            for signer in &signers {
+                ensure!(<MultiSigSigners<T>>::contains_key(&multisig, signer), Error::<T>::NotASigner);
+                removed_weight = removed_weight.saturating_add(Self::signer_weight(&multisig, signer));
+            }
             ensure!(
-                <NumberOfSigners<T>>::get(&multisig) - signers_len >= <MultiSigSignsRequired<T>>::get(&multisig),
+                Self::total_signer_weight(&multisig).saturating_sub(removed_weight)
+                    >= <MultiSigSignsRequired<T>>::get(&multisig),
                 Error::<T>::NotEnoughSigners
             );
 
             for signer in signers {
-                ensure!(<MultiSigSigners<T>>::contains_key(&multisig, &signer), Error::<T>::NotASigner);
                 Self::unsafe_signer_removal(multisig.clone(), &signer);
             }
 
@@ -417,13 +639,14 @@ decl_module! {
         /// multisig itself.
         ///
         /// # Arguments
-        /// * `sigs_required` - New number of required signatures.
+        /// * `sigs_required` - New number of required signatures. If signers are weighted, this is
+        /// the total weight of the approving signers.
         #[weight = SimpleDispatchInfo::FixedNormal(150_000)]
         pub fn change_sigs_required(origin, sigs_required: u64) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             ensure!(<MultiSigSignsRequired<T>>::contains_key(&sender), Error::<T>::NoSuchMultisig);
             ensure!(
-                <NumberOfSigners<T>>::get(&sender) >= sigs_required,
+                Self::total_signer_weight(&sender) >= sigs_required,
                 Error::<T>::NotEnoughSigners
             );
             ensure!(Self::is_changing_signers_allowed(&sender), Error::<T>::ChangeNotAllowed);
@@ -481,6 +704,7 @@ decl_module! {
             // Add the new signers for the given multi-sig
             new_signers.into_iter()
                 .for_each(|signer| {
+                    Self::unsafe_set_signer_weight(&sender, &signer, 1);
                     Self::unsafe_add_auth_for_signers(sender_signer, signer, sender.clone())
                 });
             // Change the no. of signers for a multisig
@@ -567,6 +791,24 @@ decl_event!(
         /// Event emitted when the proposal get approved.
         /// Arguments: caller DID, multisig, authorized signer, proposal id.
         ProposalApproved(IdentityId, AccountId, Signatory, u64),
+        /// Event emitted when the weight of a signatory is set.
+        /// Arguments: caller DID, multisig, signer, weight.
+        MultiSigSignerWeightSet(IdentityId, AccountId, Signatory, u64),
+        /// Event emitted when a vote is cast in favor of rejecting a proposal.
+        /// Arguments: caller DID, multisig, rejecting signer, proposal id.
+        ProposalRejectionVote(IdentityId, AccountId, Signatory, u64),
+        /// Event emitted when a proposal is rejected because it can no longer be approved.
+        /// Arguments: caller DID, multisig, proposal ID.
+        ProposalRejected(IdentityId, AccountId, u64),
+        /// Event emitted when a proposal is cancelled by its creator.
+        /// Arguments: caller DID, multisig, proposal ID.
+        ProposalCancelled(IdentityId, AccountId, u64),
+        /// Event emitted when a proposal is closed because it was not approved before its expiry.
+        /// Arguments: caller DID, multisig, proposal ID.
+        ProposalExpired(IdentityId, AccountId, u64),
+        /// Event emitted when the call of an approved proposal fails.
+        /// Arguments: caller DID, multisig, proposal ID, error.
+        ProposalExecutionFailed(IdentityId, AccountId, u64, DispatchError),
     }
 );
 
@@ -608,7 +850,23 @@ decl_error! {
         /// Current DID is missing
         MissingCurrentIdentity,
         /// The function can only be called by the master key of the did
-        NotMasterKey
+        NotMasterKey,
+        /// Already rejected.
+        AlreadyRejected,
+        /// The proposal has been rejected, cancelled or has expired.
+        ProposalAlreadyClosed,
+        /// The proposal has expired.
+        ProposalExpired,
+        /// The expiry of the proposal is not in the future.
+        InvalidExpiry,
+        /// Only the creator of the proposal can cancel it.
+        NotProposalCreator,
+        /// The number of signer weights differs from the number of signers.
+        SignerWeightsMismatch,
+        /// Signer weights must be greater than zero.
+        InvalidSignerWeight,
+        /// The description of the proposal is longer than `MAX_DESCRIPTION_LENGTH`.
+        ProposalDescriptionTooLong
     }
 }
 
@@ -630,6 +888,7 @@ impl<T: Trait> Module<T> {
             <identity::KeyToIdentityIds>::remove(key);
         }
         <MultiSigSigners<T>>::remove(&multisig, signer);
+        <SignerWeights<T>>::remove(&multisig, signer);
         Self::deposit_event(RawEvent::MultiSigSignerRemoved(
             Context::current_identity::<Identity<T>>().unwrap_or_default(),
             multisig,
@@ -637,6 +896,38 @@ impl<T: Trait> Module<T> {
         ));
     }
 
+    /// Sets the weight of a current or pending signer of a multisig. Only weights different from
+    /// 1 are stored.
+    fn unsafe_set_signer_weight(multisig: &T::AccountId, signer: &Signatory, weight: u64) {
+        if weight == 1 {
+            if <SignerWeights<T>>::contains_key(multisig, signer) {
+                <SignerWeights<T>>::remove(multisig, signer);
+            } else {
+                return;
+            }
+        } else {
+            <SignerWeights<T>>::insert(multisig, signer, weight);
+        }
+        Self::deposit_event(RawEvent::MultiSigSignerWeightSet(
+            Context::current_identity::<Identity<T>>().unwrap_or_default(),
+            multisig.clone(),
+            *signer,
+            weight,
+        ));
+    }
+
+    /// Returns the weight of a signer of a multisig.
+    pub fn signer_weight(multisig: &T::AccountId, signer: &Signatory) -> u64 {
+        Self::signer_weights(multisig, signer).unwrap_or(1)
+    }
+
+    /// Returns the total weight of the accepted signers of a multisig.
+    pub fn total_signer_weight(multisig: &T::AccountId) -> u64 {
+        <MultiSigSigners<T>>::iter_prefix(multisig).fold(0u64, |total, signer| {
+            total.saturating_add(Self::signer_weight(multisig, &signer))
+        })
+    }
+
     /// Changes the required signature count for a given multisig.
     fn unsafe_change_sigs_required(multisig: T::AccountId, sigs_required: u64) {
         <MultiSigSignsRequired<T>>::insert(&multisig, &sigs_required);
@@ -683,14 +974,36 @@ impl<T: Trait> Module<T> {
         multisig: T::AccountId,
         sender_signer: Signatory,
         proposal: Box<T::Proposal>,
+        expiry: Option<T::Moment>,
+        details: Option<ProposalDetails>,
     ) -> CreateProposalResult {
         ensure!(
             <MultiSigSigners<T>>::contains_key(&multisig, &sender_signer),
             Error::<T>::NotASigner
         );
+        if let Some(expiry) = expiry {
+            ensure!(
+                expiry > <pallet_timestamp::Module<T>>::get(),
+                Error::<T>::InvalidExpiry
+            );
+        }
+        if let Some(details) = &details {
+            ensure!(
+                details.description.len() <= MAX_DESCRIPTION_LENGTH,
+                Error::<T>::ProposalDescriptionTooLong
+            );
+        }
         let proposal_id = Self::ms_tx_done(multisig.clone());
-        <Proposals<T>>::insert((multisig.clone(), proposal_id), proposal.clone());
+        let multisig_proposal = (multisig.clone(), proposal_id);
+        <Proposals<T>>::insert(&multisig_proposal, proposal.clone());
         <ProposalIds<T>>::insert(multisig.clone(), *proposal, proposal_id);
+        <ProposalCreator<T>>::insert(&multisig_proposal, sender_signer);
+        if let Some(expiry) = expiry {
+            <ProposalExpiry<T>>::insert(&multisig_proposal, expiry);
+        }
+        if let Some(details) = details {
+            <ProposalDetailsOf<T>>::insert(&multisig_proposal, details);
+        }
         // Since proposal_ids are always only incremented by 1, they can not overflow.
         let next_proposal_id: u64 = proposal_id + 1u64;
         <MultiSigTxDone<T>>::insert(multisig.clone(), next_proposal_id);
@@ -710,13 +1023,24 @@ impl<T: Trait> Module<T> {
         multisig: T::AccountId,
         sender_signer: Signatory,
         proposal: Box<T::Proposal>,
+        expiry: Option<T::Moment>,
+        details: Option<ProposalDetails>,
     ) -> DispatchResult {
-        if let Some(proposal_id) = Self::proposal_ids(&multisig, &*proposal) {
-            // This is an existing proposal.
-            Self::approve_for(multisig, sender_signer, proposal_id)?;
-        } else {
-            // The proposal is new.
-            Self::create_proposal(multisig, sender_signer, proposal)?;
+        match Self::proposal_ids(&multisig, &*proposal) {
+            Some(proposal_id) if Self::is_active_and_expired(&multisig, proposal_id) => {
+                // The existing proposal has expired, so the call is proposed anew. The new
+                // proposal takes over `ProposalIds` before the expired one is closed.
+                Self::create_proposal(multisig.clone(), sender_signer, proposal, expiry, details)?;
+                Self::close_expired_proposal(&multisig, proposal_id);
+            }
+            Some(proposal_id) => {
+                // This is an existing proposal.
+                Self::approve_for(multisig, sender_signer, proposal_id)?;
+            }
+            None => {
+                // The proposal is new.
+                Self::create_proposal(multisig, sender_signer, proposal, expiry, details)?;
+            }
         }
         Ok(())
     }
@@ -737,10 +1061,27 @@ impl<T: Trait> Module<T> {
             !Self::votes(&multisig_signer_proposal),
             Error::<T>::AlreadyApproved
         );
+        ensure!(
+            !Self::rejection_votes(&multisig_signer_proposal),
+            Error::<T>::AlreadyRejected
+        );
+        match Self::proposal_status(&multisig_proposal) {
+            ProposalStatus::Rejected | ProposalStatus::Cancelled | ProposalStatus::Expired => {
+                return Err(Error::<T>::ProposalAlreadyClosed.into());
+            }
+            ProposalStatus::Active => {
+                if Self::is_proposal_expired(&multisig_proposal) {
+                    // The approval arrives too late. The proposal is closed instead.
+                    Self::close_expired_proposal(&multisig, proposal_id);
+                    return Ok(());
+                }
+            }
+            ProposalStatus::ExecutionSuccessful | ProposalStatus::ExecutionFailed => {}
+        }
         if let Some(proposal) = Self::proposals(&multisig_proposal) {
             <Votes<T>>::insert(&multisig_signer_proposal, true);
-            // Since approvals are always only incremented by 1, they can not overflow.
-            let approvals: u64 = Self::tx_approvals(&multisig_proposal) + 1u64;
+            let approvals = Self::tx_approvals(&multisig_proposal)
+                .saturating_add(Self::signer_weight(&multisig, &signer));
             <TxApprovals<T>>::insert(&multisig_proposal, approvals);
             let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
             // Emit ProposalApproved event
@@ -778,25 +1119,40 @@ impl<T: Trait> Module<T> {
                     Error::<T>::FailedToChargeFee
                 );
 
-                <ProposalClosed<T>>::insert(multisig_proposal, true);
+                <ProposalClosed<T>>::insert(&multisig_proposal, true);
                 let res = match proposal
                     .dispatch(frame_system::RawOrigin::Signed(multisig.clone()).into())
                 {
-                    Ok(_) => true,
+                    Ok(_) => Ok(()),
                     Err(e) => {
                         let e: DispatchError = e;
                         sp_runtime::print(e);
-                        false
+                        Err(e)
                     }
                 };
+                let status = if res.is_ok() {
+                    ProposalStatus::ExecutionSuccessful
+                } else {
+                    ProposalStatus::ExecutionFailed
+                };
+                <ProposalStatuses<T>>::insert(&multisig_proposal, status);
                 let current_did = Context::current_identity::<Identity<T>>()
                     .ok_or_else(|| Error::<T>::MissingCurrentIdentity)?;
                 Self::deposit_event(RawEvent::ProposalExecuted(
                     current_did,
-                    multisig,
+                    multisig.clone(),
                     proposal_id,
-                    res,
+                    res.is_ok(),
                 ));
+                if let Err(e) = res {
+                    <ProposalExecutionErrors<T>>::insert(&multisig_proposal, e);
+                    Self::deposit_event(RawEvent::ProposalExecutionFailed(
+                        current_did,
+                        multisig,
+                        proposal_id,
+                        e,
+                    ));
+                }
                 Ok(())
             } else {
                 Ok(())
@@ -806,6 +1162,162 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Rejects a multisig proposal and closes it if enough signers have voted against it that it
+    /// can no longer be approved.
+    pub fn reject_for(
+        multisig: T::AccountId,
+        signer: Signatory,
+        proposal_id: u64,
+    ) -> DispatchResult {
+        ensure!(
+            <MultiSigSigners<T>>::contains_key(&multisig, &signer),
+            Error::<T>::NotASigner
+        );
+        let multisig_signer_proposal = (multisig.clone(), signer, proposal_id);
+        let multisig_proposal = (multisig.clone(), proposal_id);
+        ensure!(
+            <Proposals<T>>::contains_key(&multisig_proposal),
+            Error::<T>::ProposalMissing
+        );
+        ensure!(
+            !Self::is_proposal_closed(&multisig_proposal),
+            Error::<T>::ProposalAlreadyClosed
+        );
+        ensure!(
+            !Self::votes(&multisig_signer_proposal),
+            Error::<T>::AlreadyApproved
+        );
+        ensure!(
+            !Self::rejection_votes(&multisig_signer_proposal),
+            Error::<T>::AlreadyRejected
+        );
+        <RejectionVotes<T>>::insert(&multisig_signer_proposal, true);
+        let rejections = Self::tx_rejections(&multisig_proposal)
+            .saturating_add(Self::signer_weight(&multisig, &signer));
+        <TxRejections<T>>::insert(&multisig_proposal, rejections);
+        let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+        Self::deposit_event(RawEvent::ProposalRejectionVote(
+            current_did,
+            multisig.clone(),
+            signer,
+            proposal_id,
+        ));
+        // The proposal is rejected once the signers who have not rejected it cannot approve it.
+        let possible_approvals = Self::total_signer_weight(&multisig).saturating_sub(rejections);
+        if possible_approvals < Self::ms_signs_required(&multisig) {
+            Self::unsafe_close_proposal(&multisig, proposal_id, ProposalStatus::Rejected);
+            Self::deposit_event(RawEvent::ProposalRejected(
+                current_did,
+                multisig,
+                proposal_id,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Cancels an open multisig proposal on behalf of the signer that created it.
+    pub fn cancel_for(
+        multisig: T::AccountId,
+        signer: Signatory,
+        proposal_id: u64,
+    ) -> DispatchResult {
+        let multisig_proposal = (multisig.clone(), proposal_id);
+        ensure!(
+            <Proposals<T>>::contains_key(&multisig_proposal),
+            Error::<T>::ProposalMissing
+        );
+        ensure!(
+            Self::proposal_creator(&multisig_proposal) == Some(signer),
+            Error::<T>::NotProposalCreator
+        );
+        ensure!(
+            !Self::is_proposal_closed(&multisig_proposal),
+            Error::<T>::ProposalAlreadyClosed
+        );
+        Self::unsafe_close_proposal(&multisig, proposal_id, ProposalStatus::Cancelled);
+        Self::deposit_event(RawEvent::ProposalCancelled(
+            Context::current_identity::<Identity<T>>().unwrap_or_default(),
+            multisig,
+            proposal_id,
+        ));
+        Ok(())
+    }
+
+    /// Closes a proposal without executing it. The same call can be proposed again afterwards.
+    fn unsafe_close_proposal(multisig: &T::AccountId, proposal_id: u64, status: ProposalStatus) {
+        let multisig_proposal = (multisig.clone(), proposal_id);
+        if let Some(proposal) = Self::proposals(&multisig_proposal) {
+            if Self::proposal_ids(multisig, &proposal) == Some(proposal_id) {
+                <ProposalIds<T>>::remove(multisig, &proposal);
+            }
+        }
+        <ProposalClosed<T>>::insert(&multisig_proposal, true);
+        <ProposalStatuses<T>>::insert(&multisig_proposal, status);
+    }
+
+    /// Closes a proposal that was not approved before its expiry.
+    fn close_expired_proposal(multisig: &T::AccountId, proposal_id: u64) {
+        Self::unsafe_close_proposal(multisig, proposal_id, ProposalStatus::Expired);
+        Self::deposit_event(RawEvent::ProposalExpired(
+            Context::current_identity::<Identity<T>>().unwrap_or_default(),
+            multisig.clone(),
+            proposal_id,
+        ));
+    }
+
+    /// Checks whether a proposal is still open for voting although it has passed its expiry.
+    fn is_active_and_expired(multisig: &T::AccountId, proposal_id: u64) -> bool {
+        let multisig_proposal = (multisig.clone(), proposal_id);
+        Self::proposal_status(&multisig_proposal) == ProposalStatus::Active
+            && Self::is_proposal_expired(&multisig_proposal)
+    }
+
+    /// Checks whether a proposal has passed its expiry.
+    pub fn is_proposal_expired(multisig_proposal: &(T::AccountId, u64)) -> bool {
+        Self::proposal_expiry(multisig_proposal).map_or(false, |expiry| {
+            expiry <= <pallet_timestamp::Module<T>>::get()
+        })
+    }
+
+    /// Lists up to `count` open proposals of a multisig, starting from the proposal ID `start`.
+    /// The page size is capped at `MAX_OPEN_PROPOSALS_PAGE_SIZE`. Proposals past their expiry are
+    /// left out, as they can no longer be approved. The approvers of a proposal are its current
+    /// signers that voted in favor of it.
+    pub fn get_open_proposals(
+        multisig: T::AccountId,
+        start: u64,
+        count: u32,
+    ) -> Vec<OpenProposal<T::Proposal, T::Moment>> {
+        let signers = <MultiSigSigners<T>>::iter_prefix(&multisig).collect::<Vec<Signatory>>();
+        (start..Self::ms_tx_done(&multisig))
+            .filter_map(|id| {
+                let multisig_proposal = (multisig.clone(), id);
+                if Self::is_proposal_closed(&multisig_proposal)
+                    || Self::is_proposal_expired(&multisig_proposal)
+                {
+                    return None;
+                }
+                let proposal = Self::proposals(&multisig_proposal)?;
+                let approvers = signers
+                    .iter()
+                    .filter(|signer| Self::votes((multisig.clone(), **signer, id)))
+                    .cloned()
+                    .collect();
+                Some(OpenProposal {
+                    id,
+                    proposal,
+                    details: Self::proposal_details(&multisig_proposal),
+                    creator: Self::proposal_creator(&multisig_proposal),
+                    approvers,
+                    approvals: Self::tx_approvals(&multisig_proposal),
+                    rejections: Self::tx_rejections(&multisig_proposal),
+                    expiry: Self::proposal_expiry(&multisig_proposal),
+                })
+            })
+            .take(count.min(MAX_OPEN_PROPOSALS_PAGE_SIZE) as usize)
+            .collect()
+    }
+
     /// Accepts and processed an addition of a signer to a multisig.
     pub fn _accept_multisig_signer(signer: Signatory, auth_id: u64) -> DispatchResult {
         ensure!(
@@ -26,6 +26,45 @@
 //! - a simple majority is needed to pass the ballot so that it heads for the
 //! next stage (governing committee)
 //!
+//! ### Lifecycle
+//!
+//! 1. Discussion: during its cool-off period, a proposal can be amended or cancelled by its
+//! proposer, and nobody can vote on it.
+//! 2. Community vote: the proposal is `Pending` and token holders vote on it until its end.
+//! 3. Snapshot: at the end of the vote, the sentiment of the community is recorded in a
+//! `PipSnapshot`. The proposal is rejected unless it has a majority and it meets the quorum of
+//! its category.
+//! 4. Technical review: proposals of categories that require it go to the `TechnicalReview`
+//! state, where the technical committee approves or rejects them. A proposal that is not reviewed
+//! within the `TechnicalReviewPeriod` is rejected.
+//! 5. Referendum: the governance committee ratifies or rejects the proposal.
+//!
+//! Each proposal has a `PipCategory`, which the runtime derives from its call through the
+//! `PipCategorizer` of the module. The governance committee can set the quorum of a category,
+//! and whether its proposals need a technical review, with `set_category_config`. The quorum of a
+//! proposal is fixed when it is created and recorded in its `PipsMetadata`.
+//!
+//! ### Voting modes
+//!
+//! The majority is computed over the voting weight of the ballot, which depends on its voting
+//! mode. The mode of a new ballot is the default mode set by the governance committee, and the
+//! committee can change it for a ballot during its cool-off period.
+//! - `Linear`: the weight of a vote is its stake.
+//! - `Conviction`: voters can lock their stake beyond the end of the ballot with a `Conviction`
+//! that multiplies the weight of their vote. The longer the lock, the larger the multiplier.
+//! - `Quadratic`: the weight of an identity is the square root of the stake of all its keys on
+//! the same side of the ballot, so that splitting a stake among keys adds no weight.
+//!
+//! The quorum is always computed over the stake in favour of the ballot.
+//!
+//! ### Delegation
+//!
+//! Token holders can delegate the voting power of a locked amount of their balance to a
+//! representative identity. When an account of the delegate votes on a ballot, the delegated
+//! amounts are counted as part of that vote. A delegator can override the vote of its delegate on
+//! a ballot by voting on it, in which case the delegated amount is retracted from the vote of the
+//! delegate.
+//!
 //! ## Overview
 //!
 //! The Pips module provides functions for:
@@ -44,12 +83,23 @@
 //! - `set_proposal_duration` change duration in blocks for which proposal stays active
 //! - `set_proposal_cool_off_period` change duration in blocks for which a proposal can be amended
 //! - `set_default_enact_period` change the period after enactment after which the proposal is executed
+//! - `set_conviction_lock_period` change the lock period that conviction multipliers apply to
+//! - `set_default_voting_mode` change the voting mode of new proposals
+//! - `set_proposal_voting_mode` change the voting mode of a proposal during its cool-off period
+//! - `set_category_config` change the quorum and the review requirement of a category
+//! - `set_technical_review_period` change the time the technical committee has to review a proposal
 //! - `propose` - token holders can propose a new ballot.
 //! - `amend_proposal` - allows the creator of a proposal to amend the proposal details
 //! - `cancel_proposal` - allows the creator of a proposal to cancel the proposal
 //! - `bond_additional_deposit` - allows the creator of a proposal to bond additional POLYX to it
 //! - `unbond_deposit` - allows the creator of a proposal to unbond POLYX from it
 //! - `vote` - Token holders can vote on a ballot.
+//! - `vote_with_conviction` - Token holders can lock their vote on a conviction voting ballot.
+//! - `unlock` - Removes the expired conviction locks of the caller.
+//! - `delegate` - Token holders can delegate their voting power to an identity.
+//! - `undelegate` - Token holders can withdraw their delegated voting power.
+//! - `approve_technical_review` - technical committee moves a reviewed proposal to a referendum
+//! - `reject_technical_review` - technical committee rejects a reviewed proposal
 //! - `kill_proposal` - close a proposal and refund all deposits
 //! - `fast_track_proposal` - move a proposal to a referendum stage
 //! - `emergency_referendum` - create an emergency referndum, bypassing the token holder vote
@@ -67,7 +117,7 @@ use frame_support::{
     debug, decl_error, decl_event, decl_module, decl_storage,
     dispatch::DispatchResult,
     ensure,
-    traits::{Currency, LockableCurrency, ReservableCurrency},
+    traits::{Currency, LockIdentifier, LockableCurrency, ReservableCurrency, WithdrawReasons},
     weights::SimpleDispatchInfo,
     Parameter,
 };
@@ -85,7 +135,8 @@ use polymesh_primitives::{AccountKey, Beneficiary, IdentityId, Signatory};
 use polymesh_primitives_derive::VecU8StrongTyped;
 use sp_core::H256;
 use sp_runtime::traits::{
-    BlakeTwo256, CheckedAdd, CheckedSub, Dispatchable, EnsureOrigin, Hash, Saturating, Zero,
+    BlakeTwo256, CheckedAdd, CheckedSub, Dispatchable, EnsureOrigin, Hash, IntegerSquareRoot,
+    Saturating, Zero,
 };
 use sp_std::{
     convert::{From, TryFrom},
@@ -94,6 +145,11 @@ use sp_std::{
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+/// The identifier of the balance locks of conviction votes.
+const PIPS_LOCK_ID: LockIdentifier = *b"pips    ";
+/// The identifier of the balance locks of delegated voting power.
+const PIPS_DELEGATION_LOCK_ID: LockIdentifier = *b"pipsdelg";
+
 /// Balance
 type BalanceOf<T> =
     <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
@@ -134,6 +190,10 @@ pub enum VoteCount<Balance> {
         ayes: Balance,
         /// Stake against
         nays: Balance,
+        /// Voting weight for
+        ayes_weight: Balance,
+        /// Voting weight against
+        nays_weight: Balance,
     },
     /// Proposal was not for given index.
     ProposalNotFound,
@@ -165,6 +225,64 @@ pub struct PipsMetadata<T: Trait> {
     /// This proposal allows any changes
     /// During Cool-off period, proposal owner can amend any PIP detail or cancel the entire
     pub cool_off_until: T::BlockNumber,
+    /// The category of the proposal.
+    pub category: PipCategory,
+    /// Minimum stake the proposal must gather, taken from its category when it was created.
+    pub quorum: BalanceOf<T>,
+}
+
+/// The category of a proposal. Categories can have their own quorum and review requirements.
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PipCategory {
+    /// An upgrade of the runtime.
+    RuntimeUpgrade,
+    /// A change of a chain parameter.
+    ParameterChange,
+    /// A disbursement of treasury funds.
+    Treasury,
+    /// Any other proposal.
+    Other,
+}
+
+impl Default for PipCategory {
+    fn default() -> Self {
+        PipCategory::Other
+    }
+}
+
+/// Tells the category of a proposal from its call, which is only known to the runtime.
+pub trait PipCategorizer<Proposal> {
+    /// Returns the category of `proposal`.
+    fn category(proposal: &Proposal) -> PipCategory;
+}
+
+impl<Proposal> PipCategorizer<Proposal> for () {
+    fn category(_proposal: &Proposal) -> PipCategory {
+        PipCategory::default()
+    }
+}
+
+/// The requirements of the proposals of a category.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct CategoryConfig<Balance> {
+    /// Minimum stake a proposal of the category must gather.
+    pub quorum: Balance,
+    /// Whether proposals of the category are reviewed by the technical committee.
+    pub technical_review: bool,
+}
+
+/// The sentiment of the community on a proposal, recorded at the end of its vote.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PipSnapshot<Balance: Parameter, BlockNumber> {
+    /// The block the snapshot was taken at.
+    pub taken_at: BlockNumber,
+    /// The votes on the proposal.
+    pub result: VotingResult<Balance>,
+    /// Whether the proposal passed the community vote.
+    pub passed: bool,
 }
 
 /// For keeping track of proposal being voted on.
@@ -177,6 +295,102 @@ pub struct VotingResult<Balance: Parameter> {
     /// The current set of voters that rejected with their stake.
     pub nays_count: u32,
     pub nays_stake: Balance,
+    /// The voting weight in favour of the proposal, according to its voting mode.
+    pub ayes_weight: Balance,
+    /// The voting weight against the proposal, according to its voting mode.
+    pub nays_weight: Balance,
+}
+
+/// How the stake of a vote is turned into voting weight.
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum VotingMode {
+    /// The weight of a vote is its stake.
+    Linear,
+    /// The weight of a vote is its stake multiplied by the conviction of the voter.
+    Conviction,
+    /// The weight of an identity is the square root of the stake of its votes.
+    Quadratic,
+}
+
+impl Default for VotingMode {
+    fn default() -> Self {
+        VotingMode::Linear
+    }
+}
+
+/// The commitment of a conviction vote. A vote with a conviction other than `None` is not
+/// reserved but locked until `lock_periods` conviction lock periods after the end of the
+/// proposal.
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum Conviction {
+    /// The stake is reserved until the end of the proposal and counts once.
+    None,
+    /// Locked for 1 period, the stake counts twice.
+    Locked1x,
+    /// Locked for 2 periods, the stake counts three times.
+    Locked2x,
+    /// Locked for 4 periods, the stake counts four times.
+    Locked4x,
+    /// Locked for 8 periods, the stake counts five times.
+    Locked8x,
+    /// Locked for 16 periods, the stake counts six times.
+    Locked16x,
+}
+
+impl Default for Conviction {
+    fn default() -> Self {
+        Conviction::None
+    }
+}
+
+impl Conviction {
+    /// The number of times the stake of the vote counts.
+    pub fn multiplier(self) -> u32 {
+        match self {
+            Conviction::None => 1,
+            Conviction::Locked1x => 2,
+            Conviction::Locked2x => 3,
+            Conviction::Locked4x => 4,
+            Conviction::Locked8x => 5,
+            Conviction::Locked16x => 6,
+        }
+    }
+
+    /// The number of conviction lock periods the stake is locked after the end of the proposal.
+    pub fn lock_periods(self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked1x => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked4x => 4,
+            Conviction::Locked8x => 8,
+            Conviction::Locked16x => 16,
+        }
+    }
+}
+
+/// A balance locked by a conviction vote.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ConvictionLock<Balance, BlockNumber> {
+    /// The proposal the vote was cast on.
+    pub pip: PipId,
+    /// The locked amount.
+    pub amount: Balance,
+    /// The block from which the amount can be unlocked.
+    pub until: BlockNumber,
+}
+
+/// The voting power an account delegated to a representative identity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct Delegation<Balance> {
+    /// The identity that votes on behalf of the delegator.
+    pub delegate: IdentityId,
+    /// The locked amount the delegate votes with.
+    pub amount: Balance,
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode)]
@@ -215,6 +429,8 @@ pub enum ProposalState {
     Rejected,
     /// Proposal has moved to referendum stage
     Referendum,
+    /// Proposal passed the community vote and it is reviewed by the technical committee
+    TechnicalReview,
 }
 
 impl Default for ProposalState {
@@ -293,11 +509,17 @@ pub trait Trait:
     /// Origin for enacting a referundum.
     type VotingMajorityOrigin: EnsureOrigin<Self::Origin>;
 
+    /// Origin for the technical review of proposals.
+    type TechnicalCommitteeOrigin: EnsureOrigin<Self::Origin>;
+
     /// Committee
     type GovernanceCommittee: GovernanceGroupTrait<<Self as pallet_timestamp::Trait>::Moment>;
 
     type Treasury: TreasuryTrait<<Self as CommonTrait>::Balance>;
 
+    /// Derives the category of a proposal from its call.
+    type Categorizer: PipCategorizer<Self::Proposal>;
+
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 }
@@ -355,6 +577,54 @@ decl_storage! {
 
         /// Default enactment period that will be use after a proposal is accepted by GC.
         pub DefaultEnactmentPeriod get(fn default_enactment_period) config(): T::BlockNumber;
+
+        /// The number of blocks per lock period of a conviction vote.
+        pub ConvictionLockPeriod get(fn conviction_lock_period) config(): T::BlockNumber;
+
+        /// The number of blocks the technical committee has to review a proposal before it is
+        /// rejected. Zero means that reviews do not expire.
+        pub TechnicalReviewPeriod get(fn technical_review_period) config(): T::BlockNumber;
+
+        /// The proposals whose technical review ends at a block.
+        /// block number -> Pip id
+        pub TechnicalReviewsEndingAt get(fn technical_reviews_ending_at): map hasher(twox_64_concat) T::BlockNumber => Vec<PipId>;
+
+        /// The voting mode of new proposals.
+        pub DefaultVotingMode get(fn default_voting_mode): VotingMode;
+
+        /// The voting mode of a proposal.
+        /// proposal id -> voting mode
+        pub ProposalVotingMode get(fn proposal_voting_mode): map hasher(twox_64_concat) PipId => VotingMode;
+
+        /// The conviction of a vote. Only conviction votes are stored.
+        /// (proposal id, account) -> conviction
+        pub VoteConvictions get(fn vote_conviction): double_map hasher(twox_64_concat) PipId, hasher(twox_64_concat) T::AccountId => Conviction;
+
+        /// The balances locked by the conviction votes of an account.
+        pub ConvictionLocks get(fn conviction_locks): map hasher(twox_64_concat) T::AccountId => Vec<ConvictionLock<BalanceOf<T>, T::BlockNumber>>;
+
+        /// The voting power delegated by an account.
+        pub Delegations get(fn delegation): map hasher(twox_64_concat) T::AccountId => Option<Delegation<BalanceOf<T>>>;
+
+        /// The accounts that delegated their voting power to an identity.
+        pub Delegators get(fn delegators): map hasher(twox_64_concat) IdentityId => Vec<T::AccountId>;
+
+        /// Votes cast by delegates with the voting power of their delegators.
+        /// (proposal id, delegator) -> Vote
+        pub DelegatedVotes get(fn delegated_vote): double_map hasher(twox_64_concat) PipId, hasher(twox_64_concat) T::AccountId => Vote<BalanceOf<T>>;
+
+        /// The stake of the votes of an identity on one side of a proposal, which quadratic
+        /// voting takes the square root of.
+        /// (proposal id, (identity, aye or nay)) -> stake
+        pub IdentityStakes get(fn identity_stake): double_map hasher(twox_64_concat) PipId, hasher(twox_64_concat) (IdentityId, bool) => BalanceOf<T>;
+
+        /// The requirements of the proposals of a category. Categories without a configuration
+        /// use the `QuorumThreshold` and skip the technical review.
+        pub CategoryConfigs get(fn category_config): map hasher(twox_64_concat) PipCategory => Option<CategoryConfig<BalanceOf<T>>>;
+
+        /// The snapshot of the community vote of a proposal.
+        /// proposal id -> snapshot
+        pub ProposalSnapshots get(fn proposal_snapshot): map hasher(twox_64_concat) PipId => Option<PipSnapshot<BalanceOf<T>, T::BlockNumber>>;
     }
 }
 
@@ -414,6 +684,42 @@ decl_event!(
         /// Refund proposal
         /// (id, total amount)
         ProposalRefund(IdentityId, PipId, Balance),
+        /// Conviction lock period changed
+        /// (caller DID, old period, new period)
+        ConvictionLockPeriodChanged(IdentityId, BlockNumber, BlockNumber),
+        /// Voting mode of new proposals changed
+        /// (caller DID, old mode, new mode)
+        DefaultVotingModeChanged(IdentityId, VotingMode, VotingMode),
+        /// Voting mode of a proposal changed
+        /// (caller DID, id, old mode, new mode)
+        ProposalVotingModeChanged(IdentityId, PipId, VotingMode, VotingMode),
+        /// A conviction vote locked a balance
+        /// (caller DID, voter, id, conviction, locked amount, lock end)
+        VoteLocked(IdentityId, AccountId, PipId, Conviction, Balance, BlockNumber),
+        /// Expired conviction locks were removed and the remaining amount is locked
+        /// (caller DID, voter, locked amount)
+        VoteUnlocked(IdentityId, AccountId, Balance),
+        /// Voting power was delegated to an identity
+        /// (caller DID, delegator, delegate, amount)
+        Delegated(IdentityId, AccountId, IdentityId, Balance),
+        /// Delegated voting power was withdrawn
+        /// (caller DID, delegator, delegate)
+        Undelegated(IdentityId, AccountId, IdentityId),
+        /// The requirements of a category changed
+        /// (caller DID, category, new configuration)
+        CategoryConfigChanged(IdentityId, PipCategory, Option<CategoryConfig<Balance>>),
+        /// The community vote of a proposal was snapshotted
+        /// (caller DID, id, ayes stake, nays stake, passed)
+        ProposalSnapshotTaken(IdentityId, PipId, Balance, Balance, bool),
+        /// The technical committee reviewed a proposal
+        /// (caller DID, id, approved)
+        TechnicalReviewCompleted(IdentityId, PipId, bool),
+        /// Technical review period changed
+        /// (caller DID, old period, new period)
+        TechnicalReviewPeriodChanged(IdentityId, BlockNumber, BlockNumber),
+        /// A proposal was rejected because the technical committee did not review it in time
+        /// (caller DID, id)
+        TechnicalReviewExpired(IdentityId, PipId),
     }
 );
 
@@ -459,6 +765,14 @@ decl_error! {
         IncorrectProposalState,
         /// Insufficient treasury funds to pay beneficiaries
         InsufficientTreasuryFunds,
+        /// Conviction votes are only allowed on conviction voting proposals
+        ConvictionNotAllowed,
+        /// The account already delegates its voting power
+        AlreadyDelegating,
+        /// The account does not delegate its voting power
+        NotDelegating,
+        /// The delegate identity does not exist
+        NoSuchDelegate,
     }
 }
 
@@ -541,6 +855,68 @@ decl_module! {
             Self::deposit_event(RawEvent::DefaultEnactmentPeriodChanged(SystematicIssuers::Committee.as_id(), duration, previous_duration));
         }
 
+        /// Change the number of blocks per lock period of a conviction vote. It only applies to
+        /// votes cast afterwards.
+        ///
+        /// # Arguments
+        /// * `period` conviction lock period in blocks
+        #[weight = SimpleDispatchInfo::FixedOperational(100_000)]
+        pub fn set_conviction_lock_period(origin, period: T::BlockNumber) {
+            T::CommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+            Self::deposit_event(RawEvent::ConvictionLockPeriodChanged(SystematicIssuers::Committee.as_id(), Self::conviction_lock_period(), period));
+            <ConvictionLockPeriod<T>>::put(period);
+        }
+
+        /// Change the number of blocks the technical committee has to review a proposal. It only
+        /// applies to reviews started afterwards.
+        ///
+        /// # Arguments
+        /// * `period` technical review period in blocks, or zero for reviews that do not expire
+        #[weight = SimpleDispatchInfo::FixedOperational(100_000)]
+        pub fn set_technical_review_period(origin, period: T::BlockNumber) {
+            T::CommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+            Self::deposit_event(RawEvent::TechnicalReviewPeriodChanged(SystematicIssuers::Committee.as_id(), Self::technical_review_period(), period));
+            <TechnicalReviewPeriod<T>>::put(period);
+        }
+
+        /// Change the voting mode of new proposals. Existing proposals keep their mode.
+        ///
+        /// # Arguments
+        /// * `mode` the voting mode of new proposals
+        #[weight = SimpleDispatchInfo::FixedOperational(100_000)]
+        pub fn set_default_voting_mode(origin, mode: VotingMode) {
+            T::CommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+            Self::deposit_event(RawEvent::DefaultVotingModeChanged(SystematicIssuers::Committee.as_id(), Self::default_voting_mode(), mode));
+            <DefaultVotingMode>::put(mode);
+        }
+
+        /// Change the voting mode of the proposal with id `id`. Only the deposit of the proposer
+        /// has been voted during the cool-off period, so its weight is recomputed.
+        ///
+        /// # Errors
+        /// * `ProposalIsImmutable`: The voting mode can only change during the cool-off period.
+        #[weight = SimpleDispatchInfo::FixedOperational(200_000)]
+        pub fn set_proposal_voting_mode(origin, id: PipId, mode: VotingMode) {
+            T::CommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+            let meta = Self::proposal_metadata(id)
+                .ok_or_else(|| Error::<T>::MismatchedProposalId)?;
+            Self::is_proposal_state(id, ProposalState::Pending)?;
+            let curr_block_number = <system::Module<T>>::block_number();
+            ensure!( meta.cool_off_until > curr_block_number, Error::<T>::ProposalIsImmutable);
+
+            let old_mode = Self::proposal_voting_mode(id);
+            let deposit = Self::deposits(id, &meta.proposer).amount;
+            let identity_stake = Self::voter_identity(&meta.proposer)
+                .map_or(deposit, |did| Self::identity_stake(id, (did, true)));
+            <ProposalResult<T>>::mutate(id, |stats| {
+                stats.ayes_weight = stats.ayes_weight
+                    .saturating_sub(Self::identity_vote_weight(old_mode, deposit, identity_stake, Conviction::None))
+                    .saturating_add(Self::identity_vote_weight(mode, deposit, identity_stake, Conviction::None));
+            });
+            <ProposalVotingMode>::insert(id, mode);
+            Self::deposit_event(RawEvent::ProposalVotingModeChanged(SystematicIssuers::Committee.as_id(), id, old_mode, mode));
+        }
+
         /// A network member creates a Mesh Improvement Proposal by submitting a dispatchable which
         /// changes the network in someway. A minimum deposit is required to open a new proposal.
         ///
@@ -548,6 +924,9 @@ decl_module! {
         /// * `proposal` a dispatchable call
         /// * `deposit` minimum deposit value
         /// * `url` a link to a website for proposal discussion
+        ///
+        /// The category of the proposal, which sets its quorum and review stage, is derived from
+        /// its call.
         #[weight = SimpleDispatchInfo::FixedNormal(5_000_000)]
         pub fn propose(
             origin,
@@ -578,6 +957,7 @@ decl_module! {
             let curr_block_number = <system::Module<T>>::block_number();
             let cool_off_until = curr_block_number + Self::proposal_cool_off_period();
             let end = cool_off_until + Self::proposal_duration();
+            let category = T::Categorizer::category(&proposal);
             let proposal_metadata = PipsMetadata {
                 proposer: proposer.clone(),
                 id,
@@ -585,6 +965,8 @@ decl_module! {
                 url: url.clone(),
                 description: description.clone(),
                 cool_off_until: cool_off_until.clone(),
+                category,
+                quorum: Self::category_quorum(category),
             };
             let _ = <ProposalsMaturingAt<T>>::append(end, [id].iter())?;
             <ProposalMetadata<T>>::insert(id, proposal_metadata);
@@ -602,10 +984,11 @@ decl_module! {
                 beneficiaries,
             };
             <Proposals<T>>::insert(id, pip);
+            <ProposalVotingMode>::insert(id, Self::default_voting_mode());
 
             // Add vote and update voting counter.
             // INTERNAL: It is impossible to overflow counters in the first vote.
-            Self::unsafe_vote( id, proposer.clone(), Vote::Yes(deposit))
+            Self::unsafe_vote( id, proposer.clone(), Vote::Yes(deposit), Conviction::None)
                 .map_err(|vote_error| {
                     debug::error!("The counters of voting (id={}) have an overflow during the 1st vote", id);
                     vote_error
@@ -733,9 +1116,21 @@ decl_module! {
                 |depo_info| depo_info.amount += max_additional_deposit);
 
             // 4. Update vote details to record additional vote
+            let (old_weight, new_weight) = Self::unsafe_update_identity_stake(
+                id,
+                &proposer,
+                true,
+                curr_deposit,
+                curr_deposit + max_additional_deposit,
+                Conviction::None);
             <ProposalResult<T>>::mutate(
                 id,
-                |stats| stats.ayes_stake += max_additional_deposit
+                |stats| {
+                    stats.ayes_stake += max_additional_deposit;
+                    stats.ayes_weight = stats.ayes_weight
+                        .saturating_sub(old_weight)
+                        .saturating_add(new_weight);
+                }
             );
             <ProposalVotes<T>>::insert(id, &proposer, Vote::Yes(curr_deposit + max_additional_deposit));
             let current_did = Context::current_identity::<Identity<T>>().ok_or_else(|| Error::<T>::MissingCurrentIdentity)?;
@@ -784,9 +1179,21 @@ decl_module! {
             <Deposits<T>>::insert(id, &proposer, depo_info);
 
             // 4. Update vote details to record reduced vote
+            let (old_weight, new_weight) = Self::unsafe_update_identity_stake(
+                id,
+                &proposer,
+                true,
+                diff_amount + new_deposit,
+                new_deposit,
+                Conviction::None);
             <ProposalResult<T>>::mutate(
                 id,
-                |stats| stats.ayes_stake = new_deposit
+                |stats| {
+                    stats.ayes_stake = new_deposit;
+                    stats.ayes_weight = stats.ayes_weight
+                        .saturating_sub(old_weight)
+                        .saturating_add(new_weight);
+                }
             );
             <ProposalVotes<T>>::insert(id, &proposer, Vote::Yes(new_deposit));
 
@@ -821,6 +1228,8 @@ decl_module! {
 
             // Double-check vote duplication.
             ensure!( Self::proposal_vote(id, &proposer) == Vote::None, Error::<T>::DuplicateVote);
+            let proposer_key = AccountKey::try_from(proposer.encode())?;
+            let proposer_did = Context::current_identity_or::<Identity<T>>(&proposer_key)?;
 
             // Reserve the deposit
             <T as Trait>::Currency::reserve(&proposer, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
@@ -831,7 +1240,7 @@ decl_module! {
             } else {
                 Vote::No(deposit)
             };
-            Self::unsafe_vote( id, proposer.clone(), vote)
+            Self::unsafe_vote( id, proposer.clone(), vote, Conviction::None)
                 .map_err( |vote_error| {
                     debug::warn!("The counters of voting (id={}) have an overflow, transaction is roll-back", id);
                     let _ = <T as Trait>::Currency::unreserve(&proposer, deposit);
@@ -844,9 +1253,186 @@ decl_module! {
             };
             <Deposits<T>>::insert(id, &proposer, depo_info);
             let current_did = Context::current_identity::<Identity<T>>().ok_or_else(|| Error::<T>::MissingCurrentIdentity)?;
+            Self::unsafe_retract_delegated_vote(id, &proposer);
+            Self::unsafe_cast_delegated_votes(id, proposer_did, aye_or_nay);
             Self::deposit_event(RawEvent::Voted(current_did, proposer, id, aye_or_nay, deposit));
         }
 
+        /// A network member can vote on a conviction voting proposal by locking some balance
+        /// beyond the end of the proposal. The longer the lock, the more the vote counts.
+        ///
+        /// # Arguments
+        /// * `id` proposal id
+        /// * `aye_or_nay` a bool representing for or against vote
+        /// * `deposit` the amount to vote with
+        /// * `conviction` how long `deposit` stays locked after the proposal ends
+        ///
+        /// # Errors
+        /// * `ConvictionNotAllowed`: The proposal is not in the conviction voting mode.
+        #[weight = SimpleDispatchInfo::FixedNormal(300_000)]
+        pub fn vote_with_conviction(
+            origin,
+            id: PipId,
+            aye_or_nay: bool,
+            deposit: BalanceOf<T>,
+            conviction: Conviction
+        ) {
+            let voter = ensure_signed(origin.clone())?;
+            ensure!(
+                Self::proposal_voting_mode(id) == VotingMode::Conviction,
+                Error::<T>::ConvictionNotAllowed
+            );
+            if conviction == Conviction::None {
+                // Without conviction, the vote is a regular reserved vote.
+                return Self::vote(origin, id, aye_or_nay, deposit);
+            }
+            let meta = Self::proposal_metadata(id)
+                .ok_or_else(|| Error::<T>::MismatchedProposalId)?;
+            let curr_block_number = <system::Module<T>>::block_number();
+            ensure!( meta.cool_off_until <= curr_block_number, Error::<T>::ProposalOnCoolOffPeriod);
+            Self::is_proposal_state(id, ProposalState::Pending)?;
+            ensure!(<ProposalResult<T>>::contains_key(id), Error::<T>::NoSuchProposal);
+            ensure!( Self::proposal_vote(id, &voter) == Vote::None, Error::<T>::DuplicateVote);
+            let voter_key = AccountKey::try_from(voter.encode())?;
+            let voter_did = Context::current_identity_or::<Identity<T>>(&voter_key)?;
+            ensure!(
+                <T as Trait>::Currency::free_balance(&voter) >= deposit,
+                Error::<T>::InsufficientDeposit
+            );
+
+            let vote = if aye_or_nay {
+                Vote::Yes(deposit)
+            } else {
+                Vote::No(deposit)
+            };
+            Self::unsafe_vote(id, voter.clone(), vote, conviction)?;
+
+            // Lock the stake until the end of its conviction lock periods.
+            let until = meta.end.saturating_add(
+                Self::conviction_lock_period().saturating_mul(conviction.lock_periods().into())
+            );
+            let mut locks = Self::conviction_locks(&voter);
+            locks.push(ConvictionLock { pip: id, amount: deposit, until });
+            Self::unsafe_update_conviction_locks(&voter, locks);
+
+            let current_did = Context::current_identity::<Identity<T>>().ok_or_else(|| Error::<T>::MissingCurrentIdentity)?;
+            Self::unsafe_retract_delegated_vote(id, &voter);
+            Self::unsafe_cast_delegated_votes(id, voter_did, aye_or_nay);
+            Self::deposit_event(RawEvent::Voted(current_did, voter.clone(), id, aye_or_nay, deposit));
+            Self::deposit_event(RawEvent::VoteLocked(current_did, voter, id, conviction, deposit, until));
+        }
+
+        /// Removes the expired conviction locks of the caller. The balance stays locked up to the
+        /// largest amount of the remaining locks.
+        #[weight = SimpleDispatchInfo::FixedNormal(100_000)]
+        pub fn unlock(origin) {
+            let voter = ensure_signed(origin)?;
+            let curr_block_number = <system::Module<T>>::block_number();
+            let mut locks = Self::conviction_locks(&voter);
+            locks.retain(|lock| lock.until > curr_block_number);
+            let locked = Self::unsafe_update_conviction_locks(&voter, locks);
+            let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+            Self::deposit_event(RawEvent::VoteUnlocked(current_did, voter, locked));
+        }
+
+        /// Delegates the voting power of `amount` of the caller's balance to the identity
+        /// `delegate`. The amount stays locked until the caller undelegates. When an account of
+        /// `delegate` votes on a proposal, the amount is counted as part of that vote unless the
+        /// caller votes on the proposal too.
+        ///
+        /// # Arguments
+        /// * `delegate` the identity that votes on behalf of the caller
+        /// * `amount` the amount to delegate
+        ///
+        /// # Errors
+        /// * `AlreadyDelegating`: The caller already delegates its voting power.
+        /// * `NoSuchDelegate`: `delegate` is not an existing identity.
+        #[weight = SimpleDispatchInfo::FixedNormal(200_000)]
+        pub fn delegate(origin, delegate: IdentityId, amount: BalanceOf<T>) {
+            let delegator = ensure_signed(origin)?;
+            ensure!(Self::delegation(&delegator).is_none(), Error::<T>::AlreadyDelegating);
+            ensure!(<Identity<T>>::is_identity_exists(&delegate), Error::<T>::NoSuchDelegate);
+            ensure!(
+                !amount.is_zero() && <T as Trait>::Currency::free_balance(&delegator) >= amount,
+                Error::<T>::InsufficientDeposit
+            );
+
+            <T as Trait>::Currency::set_lock(PIPS_DELEGATION_LOCK_ID, &delegator, amount, WithdrawReasons::all());
+            <Delegations<T>>::insert(&delegator, Delegation { delegate, amount });
+            <Delegators<T>>::mutate(delegate, |delegators| delegators.push(delegator.clone()));
+
+            let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+            Self::deposit_event(RawEvent::Delegated(current_did, delegator, delegate, amount));
+        }
+
+        /// Withdraws the voting power delegated by the caller. The delegated amount is retracted
+        /// from the votes of the delegate on pending proposals and it is unlocked.
+        ///
+        /// # Errors
+        /// * `NotDelegating`: The caller does not delegate its voting power.
+        #[weight = SimpleDispatchInfo::FixedNormal(300_000)]
+        pub fn undelegate(origin) {
+            let delegator = ensure_signed(origin)?;
+            let delegation = Self::delegation(&delegator).ok_or_else(|| Error::<T>::NotDelegating)?;
+
+            <ProposalMetadata<T>>::iter()
+                .map(|meta| meta.id)
+                .filter(|id| Self::is_proposal_state(*id, ProposalState::Pending).is_ok())
+                .for_each(|id| Self::unsafe_retract_delegated_vote(id, &delegator));
+            <T as Trait>::Currency::remove_lock(PIPS_DELEGATION_LOCK_ID, &delegator);
+            <Delegations<T>>::remove(&delegator);
+            <Delegators<T>>::mutate(delegation.delegate, |delegators| delegators.retain(|d| *d != delegator));
+
+            let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+            Self::deposit_event(RawEvent::Undelegated(current_did, delegator, delegation.delegate));
+        }
+
+        /// Change the quorum of a category and whether its proposals are reviewed by the technical
+        /// committee. Without a configuration, the category uses the `QuorumThreshold` and skips
+        /// the technical review. It only applies to proposals created afterwards.
+        ///
+        /// # Arguments
+        /// * `category` the category to configure
+        /// * `config` the new configuration, or `None` to use the defaults
+        #[weight = SimpleDispatchInfo::FixedOperational(100_000)]
+        pub fn set_category_config(
+            origin,
+            category: PipCategory,
+            config: Option<CategoryConfig<BalanceOf<T>>>
+        ) {
+            T::CommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+            <CategoryConfigs<T>>::mutate(category, |old| *old = config.clone());
+            Self::deposit_event(RawEvent::CategoryConfigChanged(SystematicIssuers::Committee.as_id(), category, config));
+        }
+
+        /// The technical committee approves a proposal under review, which becomes a referendum
+        /// for the governance committee.
+        ///
+        /// # Errors
+        /// * `IncorrectProposalState`: The proposal is not under technical review.
+        #[weight = SimpleDispatchInfo::FixedOperational(200_000)]
+        pub fn approve_technical_review(origin, id: PipId) {
+            T::TechnicalCommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+            Self::is_proposal_state(id, ProposalState::TechnicalReview)?;
+            Self::create_referendum(id, ReferendumState::Pending, ReferendumType::Community);
+            let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+            Self::deposit_event(RawEvent::TechnicalReviewCompleted(current_did, id, true));
+        }
+
+        /// The technical committee rejects a proposal under review.
+        ///
+        /// # Errors
+        /// * `IncorrectProposalState`: The proposal is not under technical review.
+        #[weight = SimpleDispatchInfo::FixedOperational(200_000)]
+        pub fn reject_technical_review(origin, id: PipId) {
+            T::TechnicalCommitteeOrigin::try_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+            Self::is_proposal_state(id, ProposalState::TechnicalReview)?;
+            Self::update_proposal_state(id, ProposalState::Rejected);
+            let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+            Self::deposit_event(RawEvent::TechnicalReviewCompleted(current_did, id, false));
+            Self::prune_data(id, Self::prune_historical_pips());
+        }
+
         /// An emergency stop measure to kill a proposal. Governance committee can kill
         /// a proposal at any time.
         #[weight = SimpleDispatchInfo::FixedOperational(100_000)]
@@ -920,6 +1506,8 @@ decl_module! {
                 url: url.clone(),
                 description: description.clone(),
                 cool_off_until: Zero::zero(),
+                category: PipCategory::default(),
+                quorum: Zero::zero(),
             };
             <ProposalMetadata<T>>::insert(id, proposal_metadata);
             Self::deposit_event(RawEvent::ProposalCreated(
@@ -1025,9 +1613,11 @@ decl_module! {
 impl<T: Trait> Module<T> {
     /// Runs the following procedure:
     /// 1. Find all proposals that need to end as of this block and close voting
-    /// 2. Tally votes
-    /// 3. Submit any proposals that meet the quorum threshold, to the governance committee
-    /// 4. Automatically execute any referendum
+    /// 2. Tally votes and snapshot them
+    /// 3. Submit any proposals that meet the quorum of their category to the technical committee,
+    /// if their category requires it, or to the governance committee
+    /// 4. Reject any proposals whose technical review has expired
+    /// 5. Automatically execute any referendum
     pub fn end_block(block_number: T::BlockNumber) -> DispatchResult {
         // Find all matured proposals...
         <ProposalsMaturingAt<T>>::take(block_number)
@@ -1038,18 +1628,28 @@ impl<T: Trait> Module<T> {
                     if proposal.state == ProposalState::Pending {
                         // Tally votes and create referendums
                         let voting = Self::proposal_result(id);
-
-                        // 1. Ayes staked must be more than nays staked (simple majority)
-                        // 2. Ayes staked are more than the minimum quorum threshold
-                        if voting.ayes_stake > voting.nays_stake
-                            && voting.ayes_stake >= Self::quorum_threshold()
-                        {
+                        let (category, quorum) = Self::proposal_metadata(id)
+                            .map(|meta| (meta.category, meta.quorum))
+                            .unwrap_or_else(|| (PipCategory::default(), Self::quorum_threshold()));
+
+                        // 1. Ayes weight must be more than nays weight (simple majority)
+                        // 2. Ayes staked are more than the quorum of the proposal
+                        let passed =
+                            voting.ayes_weight > voting.nays_weight && voting.ayes_stake >= quorum;
+                        Self::take_snapshot(id, voting, passed);
+                        if passed {
                             Self::refund_proposal(id);
-                            Self::create_referendum(
-                                id,
-                                ReferendumState::Pending,
-                                ReferendumType::Community,
-                            );
+                            if Self::category_config(category)
+                                .map_or(false, |config| config.technical_review)
+                            {
+                                Self::start_technical_review(id, block_number);
+                            } else {
+                                Self::create_referendum(
+                                    id,
+                                    ReferendumState::Pending,
+                                    ReferendumType::Community,
+                                );
+                            }
                         } else {
                             Self::update_proposal_state(id, ProposalState::Rejected);
                             Self::refund_proposal(id);
@@ -1059,6 +1659,16 @@ impl<T: Trait> Module<T> {
                 }
             });
         <ProposalsMaturingAt<T>>::remove(block_number);
+        // Reject the proposals that were not reviewed in time.
+        <TechnicalReviewsEndingAt<T>>::take(block_number)
+            .into_iter()
+            .filter(|id| Self::is_proposal_state(*id, ProposalState::TechnicalReview).is_ok())
+            .for_each(|id| {
+                Self::update_proposal_state(id, ProposalState::Rejected);
+                let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+                Self::deposit_event(RawEvent::TechnicalReviewExpired(current_did, id));
+                Self::prune_data(id, Self::prune_historical_pips());
+            });
         // Execute automatically referendums after its enactment period.
         let referendum_ids = <ScheduledReferendumsAt<T>>::take(block_number);
         referendum_ids
@@ -1089,6 +1699,42 @@ impl<T: Trait> Module<T> {
         ));
     }
 
+    /// Submits proposal `id` to the technical committee, which has `TechnicalReviewPeriod` blocks
+    /// from `block_number` to review it.
+    fn start_technical_review(id: PipId, block_number: T::BlockNumber) {
+        Self::update_proposal_state(id, ProposalState::TechnicalReview);
+        let period = Self::technical_review_period();
+        if !period.is_zero() {
+            <TechnicalReviewsEndingAt<T>>::mutate(block_number + period, |ids| ids.push(id));
+        }
+    }
+
+    /// Returns the quorum of new proposals of `category`.
+    pub fn category_quorum(category: PipCategory) -> BalanceOf<T> {
+        Self::category_config(category)
+            .map(|config| config.quorum)
+            .unwrap_or_else(Self::quorum_threshold)
+    }
+
+    /// Records the sentiment of the community on proposal `id` at the end of its vote.
+    fn take_snapshot(id: PipId, result: VotingResult<BalanceOf<T>>, passed: bool) {
+        let (ayes, nays) = (result.ayes_stake, result.nays_stake);
+        let snapshot = PipSnapshot {
+            taken_at: <system::Module<T>>::block_number(),
+            result,
+            passed,
+        };
+        <ProposalSnapshots<T>>::insert(id, snapshot);
+        let current_did = Context::current_identity::<Identity<T>>().unwrap_or_default();
+        Self::deposit_event(RawEvent::ProposalSnapshotTaken(
+            current_did,
+            id,
+            ayes,
+            nays,
+            passed,
+        ));
+    }
+
     /// Refunds any tokens used to vote or bond a proposal
     fn refund_proposal(id: PipId) {
         let total_refund = <Deposits<T>>::iter_prefix(id).fold(0.into(), |acc, depo_info| {
@@ -1115,6 +1761,11 @@ impl<T: Trait> Module<T> {
         if prune {
             <ProposalResult<T>>::remove(id);
             <ProposalVotes<T>>::remove_prefix(id);
+            <VoteConvictions<T>>::remove_prefix(id);
+            <DelegatedVotes<T>>::remove_prefix(id);
+            <IdentityStakes<T>>::remove_prefix(id);
+            <ProposalVotingMode>::remove(id);
+            <ProposalSnapshots<T>>::remove(id);
             <ProposalMetadata<T>>::remove(id);
             <Proposals<T>>::remove(id);
             <Referendums<T>>::remove(id);
@@ -1194,10 +1845,16 @@ impl<T: Trait> Module<T> {
     fn pay_to_beneficiaries(id: PipId) {
         if let Some(proposal) = Self::proposals(id) {
             if let Some(beneficiaries) = proposal.beneficiaries {
-                let _ = beneficiaries.into_iter().fold(0.into(), |acc, b| {
-                    T::Treasury::disbursement(b.id, b.amount);
-                    b.amount.saturating_add(acc)
-                });
+                for b in beneficiaries {
+                    if let Err(e) = T::Treasury::disbursement(b.id, b.amount) {
+                        debug::error!(
+                            "Referendum {}, its payment to {:?} fails: {:?}",
+                            id,
+                            b.id,
+                            e
+                        );
+                    }
+                }
             }
         }
     }
@@ -1253,6 +1910,8 @@ impl<T: Trait> Module<T> {
         VoteCount::ProposalFound {
             ayes: voting.ayes_stake,
             nays: voting.nays_stake,
+            ayes_weight: voting.ayes_weight,
+            nays_weight: voting.nays_weight,
         }
     }
 
@@ -1302,6 +1961,17 @@ impl<T: Trait> Module<T> {
             .collect::<HistoricalVotingById<_>>()
     }
 
+    /// Retrieve the accounts that delegated their voting power to `delegate` along with the
+    /// delegated amounts.
+    pub fn delegators_of(delegate: IdentityId) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        Self::delegators(delegate)
+            .into_iter()
+            .filter_map(|delegator| {
+                Self::delegation(&delegator).map(|delegation| (delegator, delegation.amount))
+            })
+            .collect()
+    }
+
     /// It generates the next id for proposals and referendums.
     fn next_pip_id() -> u32 {
         let id = <PipIdSequence>::get();
@@ -1310,8 +1980,102 @@ impl<T: Trait> Module<T> {
         id
     }
 
+    /// Returns the voting weight of `stake` in voting mode `mode`.
+    pub fn vote_weight(
+        mode: VotingMode,
+        stake: BalanceOf<T>,
+        conviction: Conviction,
+    ) -> BalanceOf<T> {
+        match mode {
+            VotingMode::Linear => stake,
+            VotingMode::Conviction => stake.saturating_mul(conviction.multiplier().into()),
+            VotingMode::Quadratic => stake.integer_sqrt(),
+        }
+    }
+
+    /// Returns the voting weight of a vote of `stake` in voting mode `mode`, cast by an identity
+    /// whose votes on the same side total `identity_stake`. The quadratic weight is that of the
+    /// whole identity.
+    fn identity_vote_weight(
+        mode: VotingMode,
+        stake: BalanceOf<T>,
+        identity_stake: BalanceOf<T>,
+        conviction: Conviction,
+    ) -> BalanceOf<T> {
+        match mode {
+            VotingMode::Quadratic => identity_stake.integer_sqrt(),
+            _ => Self::vote_weight(mode, stake, conviction),
+        }
+    }
+
+    /// Returns the identity of `voter`, if any.
+    fn voter_identity(voter: &T::AccountId) -> Option<IdentityId> {
+        AccountKey::try_from(voter.encode())
+            .ok()
+            .and_then(|key| <Identity<T>>::get_identity(&key))
+    }
+
+    /// Replaces `old` by `new` in the stake of the identity of `voter` on one side of proposal
+    /// `id`. It returns the voting weight that the stake of `voter` adds to that side before and
+    /// after the change. In quadratic mode that is the weight of the whole identity, so that
+    /// splitting a stake among the keys of an identity adds no weight.
+    fn unsafe_update_identity_stake(
+        id: PipId,
+        voter: &T::AccountId,
+        aye_or_nay: bool,
+        old: BalanceOf<T>,
+        new: BalanceOf<T>,
+        conviction: Conviction,
+    ) -> (BalanceOf<T>, BalanceOf<T>) {
+        let mode = Self::proposal_voting_mode(id);
+        let did = match Self::voter_identity(voter) {
+            Some(did) => did,
+            None => {
+                return (
+                    Self::vote_weight(mode, old, conviction),
+                    Self::vote_weight(mode, new, conviction),
+                )
+            }
+        };
+        let stake = Self::identity_stake(id, (did, aye_or_nay));
+        let new_stake = stake.saturating_sub(old).saturating_add(new);
+        if new_stake.is_zero() {
+            <IdentityStakes<T>>::remove(id, (did, aye_or_nay));
+        } else {
+            <IdentityStakes<T>>::insert(id, (did, aye_or_nay), new_stake);
+        }
+        (
+            Self::identity_vote_weight(mode, old, stake, conviction),
+            Self::identity_vote_weight(mode, new, new_stake, conviction),
+        )
+    }
+
+    /// Stores the conviction locks of `voter` and locks the largest amount among them. It
+    /// returns the locked amount.
+    fn unsafe_update_conviction_locks(
+        voter: &T::AccountId,
+        locks: Vec<ConvictionLock<BalanceOf<T>, T::BlockNumber>>,
+    ) -> BalanceOf<T> {
+        let locked = locks
+            .iter()
+            .fold(Zero::zero(), |max: BalanceOf<T>, lock| max.max(lock.amount));
+        if locks.is_empty() {
+            <T as Trait>::Currency::remove_lock(PIPS_LOCK_ID, voter);
+            <ConvictionLocks<T>>::remove(voter);
+        } else {
+            <T as Trait>::Currency::set_lock(PIPS_LOCK_ID, voter, locked, WithdrawReasons::all());
+            <ConvictionLocks<T>>::insert(voter, locks);
+        }
+        locked
+    }
+
     /// It inserts the vote and updates the accountability of target proposal.
-    fn unsafe_vote(id: PipId, proposer: T::AccountId, vote: Vote<BalanceOf<T>>) -> DispatchResult {
+    fn unsafe_vote(
+        id: PipId,
+        proposer: T::AccountId,
+        vote: Vote<BalanceOf<T>>,
+        conviction: Conviction,
+    ) -> DispatchResult {
         let mut stats = Self::proposal_result(id);
         match vote {
             Vote::Yes(deposit) => {
@@ -1323,6 +2087,18 @@ impl<T: Trait> Module<T> {
                     .ayes_stake
                     .checked_add(&deposit)
                     .ok_or_else(|| Error::<T>::StakeAmountOfVotesExceeded)?;
+                let (old_weight, new_weight) = Self::unsafe_update_identity_stake(
+                    id,
+                    &proposer,
+                    true,
+                    Zero::zero(),
+                    deposit,
+                    conviction,
+                );
+                stats.ayes_weight = stats
+                    .ayes_weight
+                    .saturating_sub(old_weight)
+                    .saturating_add(new_weight);
             }
             Vote::No(deposit) => {
                 stats.nays_count += stats
@@ -1333,6 +2109,18 @@ impl<T: Trait> Module<T> {
                     .nays_stake
                     .checked_add(&deposit)
                     .ok_or_else(|| Error::<T>::StakeAmountOfVotesExceeded)?;
+                let (old_weight, new_weight) = Self::unsafe_update_identity_stake(
+                    id,
+                    &proposer,
+                    false,
+                    Zero::zero(),
+                    deposit,
+                    conviction,
+                );
+                stats.nays_weight = stats
+                    .nays_weight
+                    .saturating_sub(old_weight)
+                    .saturating_add(new_weight);
             }
             Vote::None => {
                 // It should be unreachable because public API only allows binary options.
@@ -1341,10 +2129,90 @@ impl<T: Trait> Module<T> {
         };
 
         <ProposalResult<T>>::insert(id, stats);
+        if conviction != Conviction::None {
+            <VoteConvictions<T>>::insert(id, &proposer, conviction);
+        }
         <ProposalVotes<T>>::insert(id, proposer, vote);
         Ok(())
     }
 
+    /// Counts the voting power delegated to `delegate` in a vote on proposal `id`. Delegators
+    /// that voted on the proposal, or whose power is already counted, are skipped.
+    fn unsafe_cast_delegated_votes(id: PipId, delegate: IdentityId, aye_or_nay: bool) {
+        let mut stats = Self::proposal_result(id);
+        for delegator in Self::delegators(delegate) {
+            if Self::proposal_vote(id, &delegator) != Vote::None
+                || <DelegatedVotes<T>>::contains_key(id, &delegator)
+            {
+                continue;
+            }
+            if let Some(delegation) = Self::delegation(&delegator) {
+                let amount = delegation.amount;
+                let (old_weight, new_weight) = Self::unsafe_update_identity_stake(
+                    id,
+                    &delegator,
+                    aye_or_nay,
+                    Zero::zero(),
+                    amount,
+                    Conviction::None,
+                );
+                let vote = if aye_or_nay {
+                    stats.ayes_stake = stats.ayes_stake.saturating_add(amount);
+                    stats.ayes_weight = stats
+                        .ayes_weight
+                        .saturating_sub(old_weight)
+                        .saturating_add(new_weight);
+                    Vote::Yes(amount)
+                } else {
+                    stats.nays_stake = stats.nays_stake.saturating_add(amount);
+                    stats.nays_weight = stats
+                        .nays_weight
+                        .saturating_sub(old_weight)
+                        .saturating_add(new_weight);
+                    Vote::No(amount)
+                };
+                <DelegatedVotes<T>>::insert(id, &delegator, vote);
+            }
+        }
+        <ProposalResult<T>>::insert(id, stats);
+    }
+
+    /// Retracts the voting power of `delegator` from the vote its delegate cast on proposal `id`.
+    fn unsafe_retract_delegated_vote(id: PipId, delegator: &T::AccountId) {
+        if !<DelegatedVotes<T>>::contains_key(id, delegator) {
+            return;
+        }
+        let vote = <DelegatedVotes<T>>::take(id, delegator);
+        let (aye_or_nay, amount) = match vote {
+            Vote::Yes(amount) => (true, amount),
+            Vote::No(amount) => (false, amount),
+            Vote::None => return,
+        };
+        let (old_weight, new_weight) = Self::unsafe_update_identity_stake(
+            id,
+            delegator,
+            aye_or_nay,
+            amount,
+            Zero::zero(),
+            Conviction::None,
+        );
+        <ProposalResult<T>>::mutate(id, |stats| {
+            if aye_or_nay {
+                stats.ayes_stake = stats.ayes_stake.saturating_sub(amount);
+                stats.ayes_weight = stats
+                    .ayes_weight
+                    .saturating_sub(old_weight)
+                    .saturating_add(new_weight);
+            } else {
+                stats.nays_stake = stats.nays_stake.saturating_sub(amount);
+                stats.nays_weight = stats
+                    .nays_weight
+                    .saturating_sub(old_weight)
+                    .saturating_add(new_weight);
+            }
+        });
+    }
+
     /// Returns a reportable representation of a proposal taking care that the reported data are not
     /// too large.
     fn reportable_proposal_data(proposal: &T::Proposal) -> ProposalData {
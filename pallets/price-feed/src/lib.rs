@@ -0,0 +1,159 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Price Feed Module
+//!
+//! This module stores the price of one POLYX in a reference unit (e.g. USD), as reported by a
+//! trusted feeder origin such as the governance committee or a multisig.
+//!
+//! Every price is stored together with the time it was observed. A price older than
+//! `MaxPriceAge` is stale but remains the last known rate, so consumers can keep using it while
+//! being told that the feed has not been updated.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - [submit_price](Module::submit_price) - It records a new price observation.
+//! - [set_max_price_age](Module::set_max_price_age) - It changes the age after which a price is stale.
+//!
+//! ### Public Functions
+//!
+//! - [current_price](Module::current_price) - It returns the last known price and whether it is stale.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure,
+    traits::EnsureOrigin, weights::SimpleDispatchInfo,
+};
+use frame_system::{self as system, ensure_root};
+use polymesh_common_utilities::price_feed::PriceFeed;
+use primitives::Balance;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// A price observation of one POLYX in base units of the reference unit.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PriceRecord<Moment> {
+    /// The price of one POLYX.
+    pub price: Balance,
+    /// The time at which the price was observed.
+    pub observed_at: Moment,
+}
+
+pub trait Trait: frame_system::Trait + pallet_timestamp::Trait {
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+    /// The origin allowed to submit prices, e.g. the governance committee or a multisig.
+    type FeederOrigin: EnsureOrigin<Self::Origin>;
+}
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// The origin is not allowed to submit prices.
+        NotAuthorised,
+        /// The price must be greater than zero.
+        ZeroPrice,
+        /// The observation is older than the maximum price age.
+        StalePrice,
+        /// The observation is not newer than the last known price.
+        OutdatedPrice,
+        /// The observation time is in the future.
+        FuturePrice,
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as PriceFeed {
+        /// The last known price of one POLYX in the reference unit.
+        pub Price get(fn price): Option<PriceRecord<T::Moment>>;
+        /// The age after which a price is considered stale. Defaults to one day.
+        pub MaxPriceAge get(fn max_price_age): T::Moment = T::Moment::from(86_400_000u32);
+    }
+}
+
+decl_event! {
+    pub enum Event<T> where Moment = <T as pallet_timestamp::Trait>::Moment {
+        /// A new price was recorded.
+        /// price, observation time.
+        PriceSubmitted(Balance, Moment),
+        /// The maximum price age changed.
+        MaxPriceAgeSet(Moment),
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// Records the price of one POLYX in the reference unit as observed at `observed_at`.
+        ///
+        /// # Errors
+        /// * `NotAuthorised` - Only the feeder origin is allowed.
+        /// * `ZeroPrice` - The price is zero.
+        /// * `StalePrice` - The observation is older than the maximum price age.
+        /// * `OutdatedPrice` - A newer or equally old price is already known.
+        /// * `FuturePrice` - The observation time is after the current block time.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn submit_price(origin, price: Balance, observed_at: T::Moment) -> DispatchResult {
+            T::FeederOrigin::try_origin(origin).map_err(|_| Error::<T>::NotAuthorised)?;
+            ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+            let now = <pallet_timestamp::Module<T>>::get();
+            ensure!(observed_at <= now, Error::<T>::FuturePrice);
+            ensure!(
+                now.saturating_sub(observed_at) <= Self::max_price_age(),
+                Error::<T>::StalePrice
+            );
+            if let Some(last) = Self::price() {
+                ensure!(observed_at > last.observed_at, Error::<T>::OutdatedPrice);
+            }
+
+            <Price<T>>::put(PriceRecord { price, observed_at });
+            Self::deposit_event(RawEvent::PriceSubmitted(price, observed_at));
+            Ok(())
+        }
+
+        /// Changes the age after which a price is stale for the root origin.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Only root allowed.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn set_max_price_age(origin, max_price_age: T::Moment) -> DispatchResult {
+            ensure_root(origin)?;
+            <MaxPriceAge<T>>::put(max_price_age);
+            Self::deposit_event(RawEvent::MaxPriceAgeSet(max_price_age));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Returns the last known price and whether it is older than the maximum price age.
+    pub fn current_price() -> Option<(Balance, bool)> {
+        Self::price().map(|record| {
+            let now = <pallet_timestamp::Module<T>>::get();
+            let stale = now.saturating_sub(record.observed_at) > Self::max_price_age();
+            (record.price, stale)
+        })
+    }
+}
+
+impl<T: Trait> PriceFeed for Module<T> {
+    fn polyx_price() -> Option<(Balance, bool)> {
+        Self::current_price()
+    }
+}
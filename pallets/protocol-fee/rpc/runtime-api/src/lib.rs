@@ -19,6 +19,7 @@
 
 use codec::{Decode, Encode};
 use polymesh_common_utilities::protocol_fee::ProtocolOp;
+use polymesh_primitives::IdentityId;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::traits::{SaturatedConversion, UniqueSaturatedInto};
@@ -36,8 +37,28 @@ impl<T: UniqueSaturatedInto<u64>> From<T> for CappedFee {
     }
 }
 
+/// The fee of an operation in POLYX and in the reference unit of the price feed.
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeeDetails {
+    /// The fee in POLYX.
+    pub fee: CappedFee,
+    /// The fee in the reference unit, if a price is known.
+    pub reference_fee: Option<CappedFee>,
+    /// Whether the price used for the conversion is stale.
+    pub stale_price: bool,
+}
+
 sp_api::decl_runtime_apis! {
+    /// Version 2 returns the fee in the reference unit along with the fee in POLYX.
+    #[api_version(2)]
     pub trait ProtocolFeeApi {
+        /// Computes the fee of `op` before the discount of the identity paying it.
+        fn compute_fee(op: ProtocolOp) -> FeeDetails;
+        #[changed_in(2)]
         fn compute_fee(op: ProtocolOp) -> CappedFee;
+        fn fees_paid(did: IdentityId, op: ProtocolOp) -> CappedFee;
+        fn total_fees_paid(did: IdentityId) -> CappedFee;
     }
 }
@@ -15,8 +15,11 @@
 
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use pallet_protocol_fee_rpc_runtime_api::{CappedFee, ProtocolFeeApi as ProtocolFeeRuntimeApi};
+use pallet_protocol_fee_rpc_runtime_api::{
+    CappedFee, FeeDetails, ProtocolFeeApi as ProtocolFeeRuntimeApi,
+};
 use polymesh_common_utilities::protocol_fee::ProtocolOp;
+use polymesh_primitives::IdentityId;
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{generic::BlockId, traits::Block as BlockT};
@@ -24,8 +27,21 @@ use std::sync::Arc;
 
 #[rpc]
 pub trait ProtocolFeeApi<BlockHash> {
+    /// Computes the fee of `op` before the discount of the identity paying it. The discount of
+    /// an identity is the best of its volume tier and the discounts of the claims it holds.
     #[rpc(name = "protocolFee_computeFee")]
-    fn compute_fee(&self, op: ProtocolOp, at: Option<BlockHash>) -> Result<CappedFee>;
+    fn compute_fee(&self, op: ProtocolOp, at: Option<BlockHash>) -> Result<FeeDetails>;
+
+    #[rpc(name = "protocolFee_feesPaid")]
+    fn fees_paid(
+        &self,
+        did: IdentityId,
+        op: ProtocolOp,
+        at: Option<BlockHash>,
+    ) -> Result<CappedFee>;
+
+    #[rpc(name = "protocolFee_totalFeesPaid")]
+    fn total_fees_paid(&self, did: IdentityId, at: Option<BlockHash>) -> Result<CappedFee>;
 }
 
 /// A struct that implements the [`ProtocolFeeApi`].
@@ -64,16 +80,66 @@ where
         &self,
         op: ProtocolOp,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<CappedFee> {
+    ) -> Result<FeeDetails> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
             // If the block hash is not supplied assume the best block.
             self.client.info().best_hash));
 
-        api.compute_fee(&at, op).map_err(|e| RpcError {
+        // Runtimes before version 2 of the API only return the fee in POLYX.
+        let has_details = api
+            .has_api_with::<dyn ProtocolFeeRuntimeApi<Block>, _>(&at, |version| version >= 2)
+            .unwrap_or(false);
+        let details = if has_details {
+            api.compute_fee(&at, op)
+        } else {
+            #[allow(deprecated)]
+            let fee = api.compute_fee_before_version_2(&at, op);
+            fee.map(|fee| FeeDetails {
+                fee,
+                reference_fee: None,
+                stale_price: false,
+            })
+        };
+        details.map_err(|e| RpcError {
             code: ErrorCode::ServerError(Error::RuntimeError as i64),
             message: "Unable to query dispatch info.".into(),
             data: Some(format!("{:?}", e).into()),
         })
     }
+
+    fn fees_paid(
+        &self,
+        did: IdentityId,
+        op: ProtocolOp,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<CappedFee> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        api.fees_paid(&at, did, op).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(Error::RuntimeError as i64),
+            message: "Unable to query fees paid.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn total_fees_paid(
+        &self,
+        did: IdentityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<CappedFee> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        api.total_fees_paid(&at, did).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(Error::RuntimeError as i64),
+            message: "Unable to query fees paid.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
 }
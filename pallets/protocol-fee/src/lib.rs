@@ -18,6 +18,17 @@
 //! This module stores the fee of each protocol operation, and a common coefficient which is applied on
 //! fee computation.
 //!
+//! An operation can also be priced in a reference unit (e.g. USD). Its fee is then converted into
+//! POLYX using the last known price of the `PriceFeed`, even if that price is stale. The base fee in
+//! POLYX is only used when the operation has no reference fee or no price has been reported yet.
+//!
+//! The fee charged to an identity is reduced by the best of its discounts: the volume tier reached
+//! by the fees it has already paid, or the discount of a claim it holds. Claim discounts are set by
+//! governance as rules on the type, issuer and scope of a claim, so they follow the claims issued
+//! to an identity and lapse when those claims expire or are revoked. Charged fees are split
+//! between the treasury and the validators according to `FeeSplit`, and the rest is burnt. The
+//! fees paid by each identity are accumulated per operation.
+//!
 //! It also provides helper functions to calculate and charge fees on each protocol operation.
 //!
 //! ## Interface
@@ -26,12 +37,19 @@
 //!
 //! - [change_coefficient](Module::change_coefficient) - It changes the fee coefficient.
 //! - [change_base_fee](Module::change_base_fee) - It changes the base fee.
+//! - [change_reference_fee](Module::change_reference_fee) - It changes the fee in the reference unit.
+//! - [set_volume_discounts](Module::set_volume_discounts) - It changes the volume discount tiers.
+//! - [set_claim_discounts](Module::set_claim_discounts) - It changes the claim discounts.
+//! - [change_fee_split](Module::change_fee_split) - It changes how charged fees are split.
 //!
 //! ### Public Functions
 //!
-//! - [compute_fee](Module::compute_fee) - It computes the fee of the operation.
+//! - [compute_fee](Module::compute_fee) - It computes the fee of the operation before discounts.
+//! - [compute_fee_details](Module::compute_fee_details) - It computes the fee of the operation in
+//! POLYX and in the reference unit.
 //! - [charge_fee](Module::charge_fee) - It calculates the fee and charges it.
 //! - [charge_fee_batch](Module::charge_fee_batch) - It calculates the fee and charges it on a batch operation.
+//! - [discount_of](Module::discount_of) - It returns the discount applied to the fees of an identity.
 //!
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -41,20 +59,26 @@ use codec::{Decode, Encode};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     dispatch::{DispatchError, DispatchResult},
-    traits::{Currency, ExistenceRequirement, OnUnbalanced, WithdrawReason},
+    ensure,
+    traits::{Currency, ExistenceRequirement, Imbalance, OnUnbalanced, WithdrawReason},
     weights::SimpleDispatchInfo,
 };
 use frame_system::{self as system, ensure_root};
 use polymesh_common_utilities::{
+    constants::currency::ONE_UNIT,
     identity::Trait as IdentityTrait,
+    price_feed::PriceFeed,
     protocol_fee::{ChargeProtocolFee, ProtocolOp},
     Context, SystematicIssuers,
 };
-use primitives::{traits::IdentityCurrency, IdentityId, PosRatio, Signatory};
+use primitives::{
+    traits::IdentityCurrency, Balance, ClaimRequirement, IdentityId, PosRatio, Signatory,
+};
 use sp_runtime::{
-    traits::{Saturating, Zero},
+    traits::{SaturatedConversion, Saturating, Zero},
     PerThing, Perbill,
 };
+use sp_std::prelude::*;
 
 type BalanceOf<T> =
     <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
@@ -64,12 +88,23 @@ type NegativeImbalanceOf<T> =
 type WithdrawFeeResult<T> = sp_std::result::Result<NegativeImbalanceOf<T>, DispatchError>;
 type Identity<T> = identity::Module<T>;
 
+/// The shares of a charged fee paid to the treasury and to the validators. The remainder is burnt.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct FeeSplit {
+    pub treasury: Perbill,
+    pub validators: Perbill,
+}
+
 pub trait Trait: frame_system::Trait + IdentityTrait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
     /// The currency type in which fees will be paid.
     type Currency: Currency<Self::AccountId> + Send + Sync + IdentityCurrency<Self::AccountId>;
-    /// Handler for the unbalanced reduction when taking protocol fees.
+    /// Handler for the treasury share of protocol fees.
     type OnProtocolFeePayment: OnUnbalanced<NegativeImbalanceOf<Self>>;
+    /// Handler for the validators share of protocol fees.
+    type OnValidatorsFeeShare: OnUnbalanced<NegativeImbalanceOf<Self>>;
+    /// The price of POLYX in the reference unit of the reference fees.
+    type PriceFeed: PriceFeed;
 }
 
 decl_error! {
@@ -82,6 +117,10 @@ decl_error! {
         AccountIdDecode,
         /// Missing current DID
         MissingCurrentIdentity,
+        /// The volume discount thresholds are not strictly ascending.
+        InvalidDiscountTiers,
+        /// The shares of the fee split add up to more than the whole fee.
+        InvalidFeeSplit,
     }
 }
 
@@ -97,6 +136,23 @@ decl_storage! {
                 config.coefficient
             }
         }): PosRatio;
+        /// The mapping of operation names to their fees in the reference unit. Operations without
+        /// a reference fee are charged their base fee.
+        pub ReferenceFees get(fn reference_fees): map hasher(twox_64_concat) ProtocolOp => Balance;
+        /// Discount tiers as (fees already paid by an identity, discount), with strictly
+        /// ascending thresholds.
+        pub VolumeDiscounts get(fn volume_discounts): Vec<(BalanceOf<T>, Perbill)>;
+        /// Discounts as (claim required of an identity, discount).
+        pub ClaimDiscounts get(fn claim_discounts): Vec<(ClaimRequirement, Perbill)>;
+        /// How charged fees are split between the treasury, the validators and burning.
+        pub FeeSplitConfig get(fn fee_split): FeeSplit = FeeSplit {
+            treasury: Perbill::from_percent(80),
+            validators: Perbill::from_percent(20),
+        };
+        /// Fees paid by an identity for an operation.
+        pub FeesPaid get(fn fees_paid): double_map hasher(twox_64_concat) IdentityId, hasher(twox_64_concat) ProtocolOp => BalanceOf<T>;
+        /// Fees paid by an identity for all operations.
+        pub TotalFeesPaid get(fn total_fees_paid): map hasher(twox_64_concat) IdentityId => BalanceOf<T>;
     }
 }
 
@@ -108,6 +164,14 @@ decl_event! {
         CoefficientSet(IdentityId, PosRatio),
         /// Fee charged.
         FeeCharged(IdentityId, Balance),
+        /// The fee of an operation in the reference unit.
+        ReferenceFeeSet(IdentityId, ProtocolOp, primitives::Balance),
+        /// The volume discount tiers.
+        VolumeDiscountsSet(IdentityId, Vec<(Balance, Perbill)>),
+        /// The claim discounts.
+        ClaimDiscountsSet(IdentityId, Vec<(ClaimRequirement, Perbill)>),
+        /// The fee split.
+        FeeSplitSet(IdentityId, FeeSplit),
     }
 }
 
@@ -147,29 +211,137 @@ decl_module! {
             Ok(())
         }
 
+        /// Changes the fee of an operation in the reference unit for the root origin. A zero fee
+        /// prices the operation by its base fee again.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Only root allowed.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn change_reference_fee(origin, op: ProtocolOp, reference_fee: Balance) ->
+            DispatchResult
+        {
+            ensure_root(origin)?;
+            let id = Context::current_identity::<Identity<T>>().unwrap_or(SystematicIssuers::Committee.as_id());
+
+            <ReferenceFees>::insert(op, reference_fee);
+            Self::deposit_event(RawEvent::ReferenceFeeSet(id, op, reference_fee));
+            Ok(())
+        }
+
+        /// Changes the volume discount tiers for the root origin. Each tier is the amount of fees
+        /// an identity must have paid before the discount applies.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Only root allowed.
+        /// * `InvalidDiscountTiers` - The thresholds are not strictly ascending.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn set_volume_discounts(origin, tiers: Vec<(BalanceOf<T>, Perbill)>) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                tiers.windows(2).all(|pair| pair[0].0 < pair[1].0),
+                Error::<T>::InvalidDiscountTiers
+            );
+            let id = Context::current_identity::<Identity<T>>().unwrap_or(SystematicIssuers::Committee.as_id());
+
+            <VolumeDiscounts<T>>::put(&tiers);
+            Self::deposit_event(RawEvent::VolumeDiscountsSet(id, tiers));
+            Ok(())
+        }
+
+        /// Replaces the discounts on all protocol fees of the identities holding a claim for the
+        /// root origin. An identity holding several of the claims gets the best discount.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Only root allowed.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn set_claim_discounts(origin, discounts: Vec<(ClaimRequirement, Perbill)>) -> DispatchResult {
+            ensure_root(origin)?;
+            let id = Context::current_identity::<Identity<T>>().unwrap_or(SystematicIssuers::Committee.as_id());
+
+            <ClaimDiscounts>::put(&discounts);
+            Self::deposit_event(RawEvent::ClaimDiscountsSet(id, discounts));
+            Ok(())
+        }
+
+        /// Changes the shares of charged fees paid to the treasury and to the validators for the
+        /// root origin. The remainder is burnt.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Only root allowed.
+        /// * `InvalidFeeSplit` - The shares add up to more than the whole fee.
+        #[weight = SimpleDispatchInfo::FixedOperational(500_000)]
+        pub fn change_fee_split(origin, split: FeeSplit) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                split.treasury.deconstruct().saturating_add(split.validators.deconstruct())
+                    <= Perbill::ACCURACY,
+                Error::<T>::InvalidFeeSplit
+            );
+            let id = Context::current_identity::<Identity<T>>().unwrap_or(SystematicIssuers::Committee.as_id());
+
+            <FeeSplitConfig>::put(split);
+            Self::deposit_event(RawEvent::FeeSplitSet(id, split));
+            Ok(())
+        }
+
     }
 }
 
 impl<T: Trait> Module<T> {
-    /// Computes the fee of the operation as `(base_fee * coefficient.0) / coefficient.1`.
+    /// Computes the fee of the operation as `(fee * coefficient.0) / coefficient.1`, where `fee`
+    /// is the reference fee converted into POLYX or, failing that, the base fee. It does not
+    /// apply the discount of any identity, see `discount_of`.
     pub fn compute_fee(op: ProtocolOp) -> BalanceOf<T> {
+        Self::compute_fee_details(op).0
+    }
+
+    /// Computes the fee of the operation in POLYX and, if a price is known, in the reference
+    /// unit. The last value tells whether the price used for the conversion is stale.
+    pub fn compute_fee_details(op: ProtocolOp) -> (BalanceOf<T>, Option<Balance>, bool) {
         let coefficient = Self::coefficient();
         let ratio = Perbill::from_rational_approximation(coefficient.0, coefficient.1);
-        ratio * Self::base_fees(op)
+        let reference_fee = Self::reference_fees(op);
+        match T::PriceFeed::polyx_price() {
+            Some((price, stale)) if !reference_fee.is_zero() => {
+                let reference_fee = ratio * reference_fee;
+                let fee = reference_fee.saturating_mul(ONE_UNIT) / price;
+                (fee.saturated_into(), Some(reference_fee), stale)
+            }
+            Some((price, stale)) => {
+                let fee = ratio * Self::base_fees(op);
+                let reference_fee =
+                    fee.saturated_into::<Balance>().saturating_mul(price) / ONE_UNIT;
+                (fee, Some(reference_fee), stale)
+            }
+            None => (ratio * Self::base_fees(op), None, false),
+        }
+    }
+
+    /// Returns the discount applied to the fees of `did`, which is the best of its volume tier and
+    /// the discounts of the claims it holds.
+    pub fn discount_of(did: IdentityId) -> Perbill {
+        let paid = Self::total_fees_paid(did);
+        let volume_discount = Self::volume_discounts()
+            .into_iter()
+            .take_while(|(threshold, _)| *threshold <= paid)
+            .last()
+            .map_or_else(Perbill::default, |(_, discount)| discount);
+        let claim_discount = Self::claim_discounts()
+            .into_iter()
+            .filter(|(requirement, _)| <Identity<T>>::holds(did, requirement))
+            .map(|(_, discount)| discount)
+            .max()
+            .unwrap_or_default();
+        volume_discount.max(claim_discount)
     }
 
     /// Computes the fee of the operation and charges it to the given signatory. The fee is then
-    /// credited to the intended recipients according to the implementation of
-    /// `OnProtocolFeePayment`.
+    /// split between the treasury, the validators and burning according to `FeeSplit`.
     pub fn charge_fee(signatory: &Signatory, op: ProtocolOp) -> DispatchResult {
-        let fee = Self::compute_fee(op);
+        let fee = Self::charge_fees(signatory, op, 1)?;
         if fee.is_zero() {
             return Ok(());
         }
-        let imbalance = Self::withdraw_fee(signatory, fee)?;
-        // Pay the fee to the intended recipients depending on the implementation of
-        // `OnProtocolFeePayment`.
-        T::OnProtocolFeePayment::on_unbalanced(imbalance);
         let id = Context::current_identity::<Identity<T>>()
             .ok_or_else(|| Error::<T>::MissingCurrentIdentity)?;
         Self::deposit_event(RawEvent::FeeCharged(id, fee));
@@ -179,10 +351,41 @@ impl<T: Trait> Module<T> {
     /// Computes the fee for `count` similar operations, and charges that fee to the given
     /// signatory.
     pub fn charge_fee_batch(signatory: &Signatory, op: ProtocolOp, count: usize) -> DispatchResult {
-        let fee = Self::compute_fee(op).saturating_mul(<BalanceOf<T>>::from(count as u32));
+        Self::charge_fees(signatory, op, count).map(|_| ())
+    }
+
+    /// Charges the discounted fee of `count` operations, records it against the current
+    /// identity and returns it.
+    fn charge_fees(
+        signatory: &Signatory,
+        op: ProtocolOp,
+        count: usize,
+    ) -> sp_std::result::Result<BalanceOf<T>, DispatchError> {
+        let did = Context::current_identity::<Identity<T>>();
+        let mut fee = Self::compute_fee(op).saturating_mul(<BalanceOf<T>>::from(count as u32));
+        if let Some(did) = did {
+            fee = fee.saturating_sub(Self::discount_of(did) * fee);
+        }
+        if fee.is_zero() {
+            return Ok(fee);
+        }
         let imbalance = Self::withdraw_fee(signatory, fee)?;
-        T::OnProtocolFeePayment::on_unbalanced(imbalance);
-        Ok(())
+        Self::split_fee(imbalance);
+        if let Some(did) = did {
+            <FeesPaid<T>>::mutate(did, op, |paid| *paid = paid.saturating_add(fee));
+            <TotalFeesPaid<T>>::mutate(did, |paid| *paid = paid.saturating_add(fee));
+        }
+        Ok(fee)
+    }
+
+    /// Pays the shares of a charged fee to the treasury and to the validators, and burns the rest.
+    fn split_fee(imbalance: NegativeImbalanceOf<T>) {
+        let split = Self::fee_split();
+        let fee = imbalance.peek();
+        let (treasury, rest) = imbalance.split(split.treasury * fee);
+        let (validators, _burnt) = rest.split(split.validators * fee);
+        T::OnProtocolFeePayment::on_unbalanced(treasury);
+        T::OnValidatorsFeeShare::on_unbalanced(validators);
     }
 
     /// Withdraws a precomputed fee.
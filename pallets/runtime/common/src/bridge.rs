@@ -13,10 +13,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-//! # Bridge from Ethereum to Polymesh
+//! # Bridge between Ethereum and Polymesh
 //!
-//! This module implements a one-way bridge between Polymath Classic on the Ethereum side, and
-//! Polymesh native. It mints POLYX on Polymesh in return for permanently locked ERC20 POLY tokens.
+//! This module implements a bridge between Polymath Classic on the Ethereum side, and Polymesh
+//! native. It mints POLYX on Polymesh in return for permanently locked ERC20 POLY tokens and, in
+//! the opposite direction, burns POLYX on Polymesh to be released as POLY on Ethereum.
 //!
 //! ## Overview
 //!
@@ -47,6 +48,23 @@
 //!
 //! - **bridge limit whitelist**: Identities not constrained by the bridge limit.
 //!
+//! - **bridge limit tier**: A named bridge limit that replaces the default bridge limit for the
+//! identities assigned to it, either directly or by holding a claim of a given type, issuer and
+//! scope. A direct assignment takes precedence over claims. Of several matching claims, the tier
+//! with the highest amount applies.
+//!
+//! - **aggregate bridge limit**: The maximum number of POLYX bridged by all identities together
+//! within a set interval of blocks. Whitelisted identities and forced transactions are not
+//! constrained by it but count towards it. The aggregate limit is disabled while its interval
+//! duration is zero.
+//!
+//! - **outbound transaction**: a request to transfer POLYX back to an Ethereum address. The POLYX
+//! are burnt when the transaction is requested, and the transaction is identified by a unique nonce
+//! assigned by the bridge module. Outbound transactions are constrained by the bridge limit, the
+//! bridge limit whitelist and the frozen state of the bridge in the same way as inbound ones, with
+//! a separate per-identity bridged total. An outbound transaction is **requested** until the bridge
+//! controller attests its completion on Ethereum, after which it is **completed**.
+//!
 //! ### Transaction State Transitions
 //!
 //! Although the bridge is not implemented as a state machine in the strict sense, the status of a
@@ -87,6 +105,11 @@
 //! - `unfreeze`: Unfreezes transaction handling in the bridge module if it is frozen.
 //! - `change_bridge_limit`: Changes the bridge limits.
 //! - `change_bridge_whitelist`: Changes the bridge limit whitelist.
+//! - `change_limit_tier`: Adds or changes a bridge limit tier.
+//! - `remove_limit_tier`: Removes a bridge limit tier.
+//! - `change_identity_limit_tiers`: Assigns bridge limit tiers to identities.
+//! - `change_claim_limit_tiers`: Replaces the rules assigning bridge limit tiers by claim.
+//! - `change_aggregate_bridge_limit`: Changes the aggregate bridge limit.
 //! - `force_handle_bridge_tx`: Forces handling a transaction by bypassing the bridge limit and
 //! timelock.
 //! - `force_handle_bridge_txs`: Forces handling a vector of transactions.
@@ -96,10 +119,21 @@
 //! - `handle_bridge_txs`: Handles a vector of approved bridge transaction proposals.
 //! - `freeze_txs`: Freezes given bridge transactions.
 //! - `unfreeze_txs`: Unfreezes given bridge transactions.
+//! - `bridge_to_ethereum`: Burns POLYX to be transferred to an Ethereum address.
+//! - `propose_outbound_tx_completion`: Proposes the completion of an outbound transaction, which
+//! amounts to making a multisig proposal.
+//! - `complete_outbound_tx`: Records an approved completion of an outbound transaction.
+//!
+//! ### Public Functions
+//!
+//! - `txs_by_recipient`: Reports the status of the bridge transactions of a recipient.
+//! - `txs_by_tx_hash`: Reports the status of the bridge transactions of an Ethereum transaction.
+//! - `pending_txs`: Reports the timelocked and pending bridge transactions in execution order.
+//! - `bridge_limit_of`: Returns the bridge limit applicable to an identity.
 
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::{Currency, ExistenceRequirement, Get, WithdrawReason};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure,
     weights::{DispatchClass, FunctionOf, SimpleDispatchInfo},
@@ -109,17 +143,27 @@ use pallet_balances as balances;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
 use polymesh_common_utilities::{
+    protocol_fee::{ChargeProtocolFee, ProtocolOp},
     traits::{balances::CheckCdd, identity::Trait as IdentityTrait, CommonTrait},
     Context, SystematicIssuers,
 };
 
-use polymesh_primitives::{AccountKey, IdentityId, Signatory};
-use sp_core::H256;
-use sp_runtime::traits::{CheckedAdd, One, Zero};
+use polymesh_primitives::{AccountKey, ClaimRequirement, IdentityId, Signatory};
+use sp_core::{H160, H256};
+use sp_runtime::{
+    traits::{Bounded, CheckedAdd, One, Zero},
+    DispatchError,
+};
 use sp_std::{convert::TryFrom, prelude::*};
 
+#[cfg(feature = "std")]
+use serde_derive::{Deserialize, Serialize};
+
 type Identity<T> = identity::Module<T>;
 
+/// The name of a bridge limit tier.
+pub type LimitTierName = Vec<u8>;
+
 pub trait Trait: multisig::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
     type Proposal: From<Call<Self>> + Into<<Self as IdentityTrait>::Proposal>;
@@ -131,6 +175,7 @@ pub trait Trait: multisig::Trait {
 
 /// The status of a bridge transaction.
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum BridgeTxStatus {
     /// No such transaction in the system.
     Absent,
@@ -181,8 +226,43 @@ pub struct BridgeTxDetail<Balance, BlockNumber> {
     pub tx_hash: H256,
 }
 
+/// The status of an outbound bridge transaction.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OutboundTxStatus {
+    /// The POLYX have been burnt and the transaction is waiting to be executed on Ethereum.
+    Requested,
+    /// The bridge controller has attested the execution of the transaction on Ethereum.
+    Completed,
+}
+
+impl Default for OutboundTxStatus {
+    fn default() -> Self {
+        OutboundTxStatus::Requested
+    }
+}
+
+/// A unique burn-and-release bridge transaction from Polymesh to Ethereum.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct OutboundBridgeTx<Account, Balance, BlockNumber> {
+    /// The nonce assigned to this transaction by the bridge module.
+    pub nonce: u32,
+    /// The account whose POLYX have been burnt.
+    pub sender: Account,
+    /// The recipient address on Ethereum.
+    pub recipient: H160,
+    /// Amount of POLYX tokens burnt.
+    pub amount: Balance,
+    /// Block number at which the transaction was requested.
+    pub requested_at: BlockNumber,
+    /// Status of the outbound transaction.
+    pub status: OutboundTxStatus,
+    /// Ethereum release transaction hash, set on completion.
+    pub tx_hash: H256,
+}
+
 /// The status of a handled transaction for reporting purposes.
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum HandledTxStatus {
     /// The transaction has been successfully handled.
     Success,
@@ -196,6 +276,24 @@ impl Default for HandledTxStatus {
     }
 }
 
+/// The full status of a bridge transaction for reporting purposes.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Default)]
+pub struct BridgeTxReport<Account, Balance, BlockNumber> {
+    /// The bridge transaction.
+    pub bridge_tx: BridgeTx<Account, Balance>,
+    /// Status of the bridge transaction. A proposed transaction is `Absent` until the bridge
+    /// controller approves its proposal.
+    pub status: BridgeTxStatus,
+    /// Identifier of the proposal of the bridge controller multisig, if proposed.
+    pub proposal_id: Option<u64>,
+    /// Number of approvals of the proposal.
+    pub approvals: u64,
+    /// Block number at which this transaction was executed or is planned to be executed.
+    pub execution_block: BlockNumber,
+    /// Result of the last attempt to handle this transaction, if any.
+    pub handled: Option<HandledTxStatus>,
+}
+
 decl_error! {
     pub enum Error for Module<T: Trait> {
         /// The bridge controller address is not set.
@@ -235,7 +333,13 @@ decl_error! {
         /// The transaction is timelocked.
         TimelockedTx,
         /// Missing Current Identity
-        MissingCurrentIdentity
+        MissingCurrentIdentity,
+        /// There is no such outbound transaction.
+        NoSuchOutboundTx,
+        /// The outbound transaction has already been completed.
+        OutboundTxAlreadyCompleted,
+        /// There is no such bridge limit tier.
+        NoSuchLimitTier,
     }
 }
 
@@ -297,11 +401,81 @@ decl_storage! {
         BridgeLimit get(fn bridge_limit) config(): (T::Balance, T::BlockNumber);
 
         /// Amount of POLYX bridged by the identity in last block interval. Fields: the bridged
-        /// amount and the last interval number.
+        /// amount and the start block of the last interval.
+        ///
+        /// Entries recorded before intervals were identified by their start block hold an
+        /// interval number instead, which never matches the current interval start, so those
+        /// totals reset once on the next bridge transaction of the identity.
         PolyxBridged get(fn polyx_bridged): map hasher(twox_64_concat) IdentityId => (T::Balance, T::BlockNumber);
 
         /// Identities not constrained by the bridge limit.
         BridgeLimitWhitelist get(fn bridge_whitelist): map hasher(twox_64_concat) IdentityId => bool;
+
+        /// Amount of POLYX bridged out by the identity in last block interval. Fields: the bridged
+        /// amount and the start block of the last interval.
+        PolyxBridgedOut get(fn polyx_bridged_out): map hasher(twox_64_concat) IdentityId => (T::Balance, T::BlockNumber);
+
+        /// The number of outbound transactions so far, used as the nonce of the next one.
+        OutboundTxCount get(fn outbound_tx_count): u32;
+
+        /// Outbound transactions identified by their nonce.
+        OutboundTxs get(fn outbound_txs):
+            map hasher(twox_64_concat) u32 => Option<OutboundBridgeTx<T::AccountId, T::Balance, T::BlockNumber>>;
+
+        /// Bridge limit tiers by name. Fields: POLYX amount and the block interval duration.
+        LimitTiers get(fn limit_tier):
+            map hasher(blake2_128_concat) LimitTierName => Option<(T::Balance, T::BlockNumber)>;
+
+        /// Bridge limit tiers assigned to identities directly.
+        IdentityLimitTiers get(fn identity_limit_tier):
+            map hasher(twox_64_concat) IdentityId => Option<LimitTierName>;
+
+        /// Rules assigning bridge limit tiers to the identities holding a claim.
+        ClaimLimitTiers get(fn claim_limit_tiers): Vec<(ClaimRequirement, LimitTierName)>;
+
+        /// The maximum number of POLYX bridged by all identities together within a set interval of
+        /// blocks. Fields: POLYX amount and the block interval duration. Disabled if the duration
+        /// is zero.
+        AggregateBridgeLimit get(fn aggregate_bridge_limit): (T::Balance, T::BlockNumber);
+
+        /// Amount of POLYX bridged by all identities in last block interval of the aggregate
+        /// bridge limit. Fields: the bridged amount and the start block of the last interval.
+        PolyxBridgedTotal get(fn polyx_bridged_total): (T::Balance, T::BlockNumber);
+
+        /// Bridge transactions proposed, handled or frozen so far, identified with pairs of the
+        /// recipient account and the bridge transaction nonce.
+        RecipientTxs get(fn recipient_tx):
+            double_map
+                hasher(blake2_128_concat) T::AccountId,
+                hasher(blake2_128_concat) u32
+            =>
+                Option<BridgeTx<T::AccountId, T::Balance>>;
+
+        /// Nonces of the bridge transactions in `RecipientTxs` by recipient account, in the order
+        /// they were recorded.
+        RecipientTxNonces get(fn recipient_tx_nonces):
+            map hasher(blake2_128_concat) T::AccountId => Vec<u32>;
+
+        /// Recipient accounts and nonces of the bridge transactions of an Ethereum transaction.
+        TxHashTxs get(fn tx_hash_txs): map hasher(blake2_128_concat) H256 => Vec<(T::AccountId, u32)>;
+
+        /// Multisig account and proposal identifier of the proposal of a bridge transaction,
+        /// identified with pairs of the recipient account and the bridge transaction nonce.
+        BridgeTxProposals get(fn bridge_tx_proposal):
+            double_map
+                hasher(blake2_128_concat) T::AccountId,
+                hasher(blake2_128_concat) u32
+            =>
+                Option<(T::AccountId, u64)>;
+
+        /// Result of the last attempt to handle a bridge transaction, identified with pairs of
+        /// the recipient account and the bridge transaction nonce.
+        HandledTxs get(fn handled_tx):
+            double_map
+                hasher(blake2_128_concat) T::AccountId,
+                hasher(blake2_128_concat) u32
+            =>
+                Option<HandledTxStatus>;
     }
     add_extra_genesis {
         // TODO: Remove multisig creator and add systematic CDD for the bridge multisig.
@@ -347,6 +521,23 @@ decl_event! {
         TxsHandled(Vec<(u32, HandledTxStatus)>),
         /// Bridge Tx Scheduled
         BridgeTxScheduled(IdentityId, BridgeTx<AccountId, Balance>, BlockNumber),
+        /// POLYX have been burnt to be transferred to Ethereum.
+        OutboundTxRequested(IdentityId, OutboundBridgeTx<AccountId, Balance, BlockNumber>),
+        /// A bridge limit tier has been added or changed.
+        /// Parameters: caller DID, tier name, POLYX amount, block interval duration.
+        LimitTierChanged(IdentityId, LimitTierName, Balance, BlockNumber),
+        /// A bridge limit tier has been removed.
+        LimitTierRemoved(IdentityId, LimitTierName),
+        /// The bridge limit tier of an identity has been assigned or unassigned.
+        /// Parameters: caller DID, identity, tier name.
+        IdentityLimitTierChanged(IdentityId, IdentityId, Option<LimitTierName>),
+        /// The rules assigning bridge limit tiers by claim have been replaced.
+        ClaimLimitTiersChanged(IdentityId, Vec<(ClaimRequirement, LimitTierName)>),
+        /// The aggregate bridge limit has been updated.
+        AggregateBridgeLimitUpdated(IdentityId, Balance, BlockNumber),
+        /// The execution of an outbound transaction on Ethereum has been attested.
+        /// Parameters: caller DID, outbound transaction nonce, Ethereum transaction hash.
+        OutboundTxCompleted(IdentityId, u32, H256),
     }
 }
 
@@ -445,13 +636,97 @@ decl_module! {
             Ok(())
         }
 
+        /// Adds or changes the bridge limit tier `tier` to `amount` POLYX per `duration` blocks.
+        #[weight = SimpleDispatchInfo::FixedOperational(50_000)]
+        pub fn change_limit_tier(
+            origin,
+            tier: LimitTierName,
+            amount: T::Balance,
+            duration: T::BlockNumber
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let current_did = Context::current_identity_or::<Identity<T>>(&(AccountKey::try_from(sender.encode())?))?;
+            ensure!(sender == Self::admin(), Error::<T>::BadAdmin);
+            ensure!(!duration.is_zero(), Error::<T>::DivisionByZero);
+            <LimitTiers<T>>::insert(&tier, (amount, duration));
+            Self::deposit_event(RawEvent::LimitTierChanged(current_did, tier, amount, duration));
+            Ok(())
+        }
+
+        /// Removes the bridge limit tier `tier`. The identities assigned to it fall back to their
+        /// remaining matching tiers or to the default bridge limit.
+        #[weight = SimpleDispatchInfo::FixedOperational(50_000)]
+        pub fn remove_limit_tier(origin, tier: LimitTierName) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let current_did = Context::current_identity_or::<Identity<T>>(&(AccountKey::try_from(sender.encode())?))?;
+            ensure!(sender == Self::admin(), Error::<T>::BadAdmin);
+            ensure!(<LimitTiers<T>>::contains_key(&tier), Error::<T>::NoSuchLimitTier);
+            <LimitTiers<T>>::remove(&tier);
+            Self::deposit_event(RawEvent::LimitTierRemoved(current_did, tier));
+            Ok(())
+        }
+
+        /// Assigns bridge limit tiers to identities. `None` unassigns the tier of an identity.
+        #[weight = SimpleDispatchInfo::FixedOperational(50_000)]
+        pub fn change_identity_limit_tiers(
+            origin,
+            tiers: Vec<(IdentityId, Option<LimitTierName>)>
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let current_did = Context::current_identity_or::<Identity<T>>(&(AccountKey::try_from(sender.encode())?))?;
+            ensure!(sender == Self::admin(), Error::<T>::BadAdmin);
+            for (_, tier) in &tiers {
+                if let Some(tier) = tier {
+                    ensure!(<LimitTiers<T>>::contains_key(tier), Error::<T>::NoSuchLimitTier);
+                }
+            }
+            for (did, tier) in tiers {
+                <IdentityLimitTiers>::mutate(did, |old_tier| *old_tier = tier.clone());
+                Self::deposit_event(RawEvent::IdentityLimitTierChanged(current_did, did, tier));
+            }
+            Ok(())
+        }
+
+        /// Replaces the rules assigning bridge limit tiers to the identities holding a claim.
+        #[weight = SimpleDispatchInfo::FixedOperational(50_000)]
+        pub fn change_claim_limit_tiers(origin, rules: Vec<(ClaimRequirement, LimitTierName)>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let current_did = Context::current_identity_or::<Identity<T>>(&(AccountKey::try_from(sender.encode())?))?;
+            ensure!(sender == Self::admin(), Error::<T>::BadAdmin);
+            ensure!(
+                rules.iter().all(|(_, tier)| <LimitTiers<T>>::contains_key(tier)),
+                Error::<T>::NoSuchLimitTier
+            );
+            <ClaimLimitTiers>::put(rules.clone());
+            Self::deposit_event(RawEvent::ClaimLimitTiersChanged(current_did, rules));
+            Ok(())
+        }
+
+        /// Changes the aggregate bridge limit. A zero `duration` disables it.
+        #[weight = SimpleDispatchInfo::FixedOperational(50_000)]
+        pub fn change_aggregate_bridge_limit(
+            origin,
+            amount: T::Balance,
+            duration: T::BlockNumber
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let current_did = Context::current_identity_or::<Identity<T>>(&(AccountKey::try_from(sender.encode())?))?;
+            ensure!(sender == Self::admin(), Error::<T>::BadAdmin);
+            <AggregateBridgeLimit<T>>::put((amount, duration));
+            Self::deposit_event(RawEvent::AggregateBridgeLimitUpdated(current_did, amount, duration));
+            Ok(())
+        }
+
         /// Forces handling a transaction by bypassing the bridge limit and timelock.
         #[weight = SimpleDispatchInfo::FixedOperational(250_000)]
         pub fn force_handle_bridge_tx(origin, bridge_tx: BridgeTx<T::AccountId, T::Balance>) -> DispatchResult {
             // NB: To avoid code duplication, this uses a hacky approach of temporarily whitelisting the did
             let sender = ensure_signed(origin)?;
             ensure!(sender == Self::admin(), Error::<T>::BadAdmin);
-            Self::force_handle_signed_bridge_tx(bridge_tx)
+            let (recipient, nonce) = (bridge_tx.recipient.clone(), bridge_tx.nonce);
+            let result = Self::force_handle_signed_bridge_tx(bridge_tx);
+            Self::note_handled_tx(&recipient, nonce, &result);
+            result
         }
 
         /// Forces handling a vector of transactions by bypassing the bridge limit and timelock.
@@ -523,7 +798,10 @@ decl_module! {
             DispatchResult
         {
             let sender = ensure_signed(origin)?;
-            Self::handle_signed_bridge_tx(&sender, bridge_tx)
+            let (recipient, nonce) = (bridge_tx.recipient.clone(), bridge_tx.nonce);
+            let result = Self::handle_signed_bridge_tx(&sender, bridge_tx);
+            Self::note_handled_tx(&recipient, nonce, &result);
+            result
         }
 
         /// Handles a vector of approved bridge transaction proposals. The vector is processed until
@@ -576,6 +854,7 @@ decl_module! {
                 let tx_details = Self::bridge_tx_details(&bridge_tx.recipient, &bridge_tx.nonce);
                 ensure!(tx_details.status != BridgeTxStatus::Handled, Error::<T>::ProposalAlreadyHandled);
                 <BridgeTxDetails<T>>::mutate(&bridge_tx.recipient, &bridge_tx.nonce, |tx_detail| tx_detail.status = BridgeTxStatus::Frozen);
+                Self::note_bridge_tx(&bridge_tx);
                 Self::deposit_event(RawEvent::FrozenTx(current_did, bridge_tx));
             }
             Ok(())
@@ -613,6 +892,100 @@ decl_module! {
             Ok(())
         }
 
+        /// Burns `amount` POLYX of the sender to be transferred to `recipient` on Ethereum. The
+        /// sender must have a valid CDD and the amount is accounted against the bridge limit of
+        /// its identity unless whitelisted. The sender pays the protocol fee of the transaction.
+        #[weight = SimpleDispatchInfo::FixedNormal(500_000)]
+        pub fn bridge_to_ethereum(origin, recipient: H160, amount: T::Balance) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(!Self::frozen(), Error::<T>::Frozen);
+            let sender_key = AccountKey::try_from(sender.encode())?;
+            let did = T::CddChecker::get_key_cdd_did(&sender_key).ok_or(Error::<T>::NoValidCdd)?;
+
+            let bridged_out = if Self::bridge_whitelist(did) {
+                None
+            } else {
+                Some(Self::bridged_within_limit(
+                    amount,
+                    Self::polyx_bridged_out(did),
+                    Self::bridge_limit_of(did),
+                )?)
+            };
+            let neg_imbalance = <balances::Module<T>>::withdraw(
+                &sender,
+                amount,
+                WithdrawReason::Transfer.into(),
+                ExistenceRequirement::KeepAlive,
+            )?;
+            if let Err(e) = <<T as IdentityTrait>::ProtocolFee>::charge_fee(
+                &Signatory::from(sender_key),
+                ProtocolOp::BridgeProposeBridgeTx,
+            ) {
+                // Return the withdrawn POLYX if the fee cannot be paid.
+                let _ = <balances::Module<T>>::resolve_creating(&sender, neg_imbalance);
+                return Err(e);
+            }
+            if let Some(bridged_out) = bridged_out {
+                <PolyxBridgedOut<T>>::insert(did, bridged_out);
+            }
+
+            let nonce = Self::outbound_tx_count();
+            <OutboundTxCount>::put(nonce + 1);
+            let outbound_tx = OutboundBridgeTx {
+                nonce,
+                sender,
+                recipient,
+                amount,
+                requested_at: <system::Module<T>>::block_number(),
+                status: OutboundTxStatus::Requested,
+                tx_hash: Default::default(),
+            };
+            <OutboundTxs<T>>::insert(nonce, outbound_tx.clone());
+            Self::deposit_event(RawEvent::OutboundTxRequested(did, outbound_tx));
+            Ok(())
+        }
+
+        /// Proposes the completion of an outbound transaction, which amounts to making a multisig
+        /// proposal for the completion if it is new or approving an existing proposal if it has
+        /// already been proposed.
+        #[weight = SimpleDispatchInfo::FixedOperational(800_000)]
+        pub fn propose_outbound_tx_completion(origin, nonce: u32, tx_hash: H256) -> DispatchResult {
+            ensure!(Self::controller() != Default::default(), Error::<T>::ControllerNotSet);
+            let sender = ensure_signed(origin)?;
+            let sender_signer = Signatory::from(AccountKey::try_from(sender.encode())?);
+            let proposal = <T as Trait>::Proposal::from(Call::<T>::complete_outbound_tx(nonce, tx_hash));
+            <multisig::Module<T>>::create_or_approve_proposal(
+                Self::controller(),
+                sender_signer,
+                Box::new(proposal.into()),
+                None,
+                None,
+            )
+        }
+
+        /// Records the execution of an outbound transaction on Ethereum. Can only be called by
+        /// the bridge controller or the admin.
+        #[weight = SimpleDispatchInfo::FixedOperational(250_000)]
+        pub fn complete_outbound_tx(origin, nonce: u32, tx_hash: H256) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(
+                sender == Self::controller() || sender == Self::admin(),
+                Error::<T>::BadCaller
+            );
+            let mut outbound_tx = Self::outbound_txs(nonce).ok_or(Error::<T>::NoSuchOutboundTx)?;
+            ensure!(
+                outbound_tx.status == OutboundTxStatus::Requested,
+                Error::<T>::OutboundTxAlreadyCompleted
+            );
+            outbound_tx.status = OutboundTxStatus::Completed;
+            outbound_tx.tx_hash = tx_hash;
+            <OutboundTxs<T>>::insert(nonce, outbound_tx);
+
+            let current_did = Context::current_identity::<Identity<T>>()
+                .unwrap_or(SystematicIssuers::Committee.as_id());
+            Self::deposit_event(RawEvent::OutboundTxCompleted(current_did, nonce, tx_hash));
+            Ok(())
+        }
     }
 }
 
@@ -621,25 +994,148 @@ impl<T: Trait> Module<T> {
         Self::controller()
     }
 
+    /// Reports the status of the bridge transactions of `recipient`.
+    pub fn txs_by_recipient(
+        recipient: T::AccountId,
+    ) -> Vec<BridgeTxReport<T::AccountId, T::Balance, T::BlockNumber>> {
+        Self::recipient_tx_nonces(&recipient)
+            .into_iter()
+            .filter_map(|nonce| Self::recipient_tx(&recipient, &nonce))
+            .map(Self::bridge_tx_report)
+            .collect()
+    }
+
+    /// Reports the status of the bridge transactions of the Ethereum transaction `tx_hash`.
+    pub fn txs_by_tx_hash(
+        tx_hash: H256,
+    ) -> Vec<BridgeTxReport<T::AccountId, T::Balance, T::BlockNumber>> {
+        Self::tx_hash_txs(tx_hash)
+            .into_iter()
+            .filter_map(|(recipient, nonce)| Self::recipient_tx(&recipient, &nonce))
+            .map(Self::bridge_tx_report)
+            .collect()
+    }
+
+    /// Reports up to `count` timelocked and pending bridge transactions, skipping the first
+    /// `start` of them, in the order they are going to be executed.
+    pub fn pending_txs(
+        start: u32,
+        count: u32,
+    ) -> Vec<BridgeTxReport<T::AccountId, T::Balance, T::BlockNumber>> {
+        let mut reports = <TimelockedTxs<T>>::iter()
+            .flatten()
+            .map(Self::bridge_tx_report)
+            .filter(|report| match report.status {
+                BridgeTxStatus::Timelocked | BridgeTxStatus::Pending(_) => true,
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+        reports.sort_by(|a, b| {
+            (a.execution_block, &a.bridge_tx.recipient, a.bridge_tx.nonce).cmp(&(
+                b.execution_block,
+                &b.bridge_tx.recipient,
+                b.bridge_tx.nonce,
+            ))
+        });
+        reports
+            .into_iter()
+            .skip(start as usize)
+            .take(count as usize)
+            .collect()
+    }
+
+    /// Builds the full status report of `bridge_tx`.
+    fn bridge_tx_report(
+        bridge_tx: BridgeTx<T::AccountId, T::Balance>,
+    ) -> BridgeTxReport<T::AccountId, T::Balance, T::BlockNumber> {
+        let details = Self::bridge_tx_details(&bridge_tx.recipient, &bridge_tx.nonce);
+        let proposal = Self::bridge_tx_proposal(&bridge_tx.recipient, &bridge_tx.nonce);
+        let approvals = proposal
+            .as_ref()
+            .map_or(0, |proposal| <multisig::Module<T>>::tx_approvals(proposal));
+        let handled = Self::handled_tx(&bridge_tx.recipient, &bridge_tx.nonce);
+        BridgeTxReport {
+            bridge_tx,
+            status: details.status,
+            proposal_id: proposal.map(|(_, id)| id),
+            approvals,
+            execution_block: details.execution_block,
+            handled,
+        }
+    }
+
+    /// Records `bridge_tx` in the bridge transaction history if it is not there yet.
+    fn note_bridge_tx(bridge_tx: &BridgeTx<T::AccountId, T::Balance>) {
+        if <RecipientTxs<T>>::contains_key(&bridge_tx.recipient, &bridge_tx.nonce) {
+            return;
+        }
+        <RecipientTxs<T>>::insert(&bridge_tx.recipient, &bridge_tx.nonce, bridge_tx);
+        <RecipientTxNonces<T>>::append_or_insert(&bridge_tx.recipient, [bridge_tx.nonce].iter());
+        <TxHashTxs<T>>::append_or_insert(
+            &bridge_tx.tx_hash,
+            [(bridge_tx.recipient.clone(), bridge_tx.nonce)].iter(),
+        );
+    }
+
+    /// Records the result of handling the bridge transaction `nonce` of `recipient`.
+    fn note_handled_tx(recipient: &T::AccountId, nonce: u32, result: &DispatchResult) {
+        let status = match result {
+            Ok(()) => HandledTxStatus::Success,
+            Err(e) => HandledTxStatus::Error(e.encode()),
+        };
+        <HandledTxs<T>>::insert(recipient, &nonce, status);
+    }
+
+    /// Records the bridge transactions of a proposal `proposal` of the bridge controller.
+    fn note_proposed_txs(
+        bridge_txs: &[BridgeTx<T::AccountId, T::Balance>],
+        proposal: &<T as IdentityTrait>::Proposal,
+    ) {
+        let controller = Self::controller();
+        let proposal_id = <multisig::Module<T>>::proposal_ids(&controller, proposal);
+        for bridge_tx in bridge_txs {
+            if let Some(id) = proposal_id {
+                <BridgeTxProposals<T>>::insert(
+                    &bridge_tx.recipient,
+                    &bridge_tx.nonce,
+                    (controller.clone(), id),
+                );
+            }
+            Self::note_bridge_tx(bridge_tx);
+        }
+    }
+
     /// Issues the transacted amount to the recipient.
     fn issue(recipient: &T::AccountId, amount: &T::Balance) -> DispatchResult {
         if let Some(did) =
             T::CddChecker::get_key_cdd_did(&AccountKey::try_from(recipient.encode())?)
         {
-            if !Self::bridge_whitelist(did) {
-                let current_block_number = <system::Module<T>>::block_number();
-                let (limit, interval_duration) = Self::bridge_limit();
-                ensure!(!interval_duration.is_zero(), Error::<T>::DivisionByZero);
-                let current_interval = current_block_number / interval_duration;
-                let (bridged, last_interval) = Self::polyx_bridged(did);
-                let mut total_mint = *amount;
-                if last_interval == current_interval {
-                    total_mint = total_mint
-                        .checked_add(&bridged)
-                        .ok_or(Error::<T>::Overflow)?;
-                }
-                ensure!(total_mint <= limit, Error::<T>::BridgeLimitReached);
-                <PolyxBridged<T>>::insert(did, (total_mint, current_interval))
+            let exempt = Self::bridge_whitelist(did);
+            let bridged = if exempt {
+                None
+            } else {
+                Some(Self::bridged_within_limit(
+                    *amount,
+                    Self::polyx_bridged(did),
+                    Self::bridge_limit_of(did),
+                )?)
+            };
+            let (limit, duration) = Self::aggregate_bridge_limit();
+            if !duration.is_zero() {
+                let limit = if exempt {
+                    T::Balance::max_value()
+                } else {
+                    limit
+                };
+                let bridged_total = Self::bridged_within_limit(
+                    *amount,
+                    Self::polyx_bridged_total(),
+                    (limit, duration),
+                )?;
+                <PolyxBridgedTotal<T>>::put(bridged_total);
+            }
+            if let Some(bridged) = bridged {
+                <PolyxBridged<T>>::insert(did, bridged);
             }
         } else {
             return Err(Error::<T>::NoValidCdd.into());
@@ -650,6 +1146,46 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Returns the bridge limit of `did`: the tier assigned to it directly, or else the tier with
+    /// the highest amount among the tiers of the claims it holds, or else the default bridge
+    /// limit. Fields: POLYX amount and the block interval duration.
+    pub fn bridge_limit_of(did: IdentityId) -> (T::Balance, T::BlockNumber) {
+        if let Some(limit) = Self::identity_limit_tier(did).and_then(Self::limit_tier) {
+            return limit;
+        }
+        Self::claim_limit_tiers()
+            .into_iter()
+            .filter(|(requirement, _)| <Identity<T>>::holds(did, requirement))
+            .filter_map(|(_, tier)| Self::limit_tier(tier))
+            .max_by_key(|(amount, _)| *amount)
+            .unwrap_or_else(Self::bridge_limit)
+    }
+
+    /// Adds `amount` to the total `bridged` in the current block interval of `limit`.
+    ///
+    /// Intervals are identified by their start block rather than by their number, so a total
+    /// recorded under another interval duration, e.g. before a change of the bridge limit tier,
+    /// is only carried over if it was recorded within the current interval.
+    ///
+    /// # Errors
+    /// * `BridgeLimitReached` if the new total exceeds the bridge limit.
+    fn bridged_within_limit(
+        amount: T::Balance,
+        (bridged, last_interval_start): (T::Balance, T::BlockNumber),
+        (limit, interval_duration): (T::Balance, T::BlockNumber),
+    ) -> Result<(T::Balance, T::BlockNumber), DispatchError> {
+        let current_block_number = <system::Module<T>>::block_number();
+        ensure!(!interval_duration.is_zero(), Error::<T>::DivisionByZero);
+        let current_interval_start =
+            current_block_number - current_block_number % interval_duration;
+        let mut total = amount;
+        if last_interval_start >= current_interval_start {
+            total = total.checked_add(&bridged).ok_or(Error::<T>::Overflow)?;
+        }
+        ensure!(total <= limit, Error::<T>::BridgeLimitReached);
+        Ok((total, current_interval_start))
+    }
+
     /// Handles a bridge transaction proposal immediately.
     fn handle_bridge_tx_now(
         bridge_tx: BridgeTx<T::AccountId, T::Balance>,
@@ -758,7 +1294,10 @@ impl<T: Trait> Module<T> {
     fn handle_timelocked_txs(block_number: T::BlockNumber) {
         let txs = <TimelockedTxs<T>>::take(block_number);
         for tx in txs {
-            if let Err(e) = Self::handle_bridge_tx_now(tx, false) {
+            let (recipient, nonce) = (tx.recipient.clone(), tx.nonce);
+            let result = Self::handle_bridge_tx_now(tx, false);
+            Self::note_handled_tx(&recipient, nonce, &result);
+            if let Err(e) = result {
                 sp_runtime::print(e);
             }
         }
@@ -770,13 +1309,17 @@ impl<T: Trait> Module<T> {
         bridge_tx: BridgeTx<T::AccountId, T::Balance>,
     ) -> DispatchResult {
         let sender_signer = Signatory::from(AccountKey::try_from(sender.encode())?);
-        let proposal = <T as Trait>::Proposal::from(Call::<T>::handle_bridge_tx(bridge_tx));
-        let boxed_proposal = Box::new(proposal.into());
+        let proposal: <T as IdentityTrait>::Proposal =
+            <T as Trait>::Proposal::from(Call::<T>::handle_bridge_tx(bridge_tx.clone())).into();
         <multisig::Module<T>>::create_or_approve_proposal(
             Self::controller(),
             sender_signer,
-            boxed_proposal,
-        )
+            Box::new(proposal.clone()),
+            None,
+            None,
+        )?;
+        Self::note_proposed_txs(&[bridge_tx], &proposal);
+        Ok(())
     }
 
     /// Proposes a vector of bridge transaction. The bridge controller must be set.
@@ -785,13 +1328,17 @@ impl<T: Trait> Module<T> {
         bridge_txs: Vec<BridgeTx<T::AccountId, T::Balance>>,
     ) -> DispatchResult {
         let sender_signer = Signatory::from(AccountKey::try_from(sender.encode())?);
-        let proposal = <T as Trait>::Proposal::from(Call::<T>::handle_bridge_txs(bridge_txs));
-        let boxed_proposal = Box::new(proposal.into());
+        let proposal: <T as IdentityTrait>::Proposal =
+            <T as Trait>::Proposal::from(Call::<T>::handle_bridge_txs(bridge_txs.clone())).into();
         <multisig::Module<T>>::create_or_approve_proposal(
             Self::controller(),
             sender_signer,
-            boxed_proposal,
-        )
+            Box::new(proposal.clone()),
+            None,
+            None,
+        )?;
+        Self::note_proposed_txs(&bridge_txs, &proposal);
+        Ok(())
     }
 
     /// Handles an approved bridge transaction proposal.
@@ -808,6 +1355,7 @@ impl<T: Trait> Module<T> {
                     sender == &Self::controller() || sender == &Self::admin(),
                     Error::<T>::BadCaller
                 );
+                Self::note_bridge_tx(&bridge_tx);
                 let timelock = Self::timelock();
                 if timelock.is_zero() {
                     return Self::handle_bridge_tx_now(bridge_tx, false);
@@ -844,6 +1392,7 @@ impl<T: Trait> Module<T> {
         bridge_tx: BridgeTx<T::AccountId, T::Balance>,
     ) -> DispatchResult {
         // NB: To avoid code duplication, this uses a hacky approach of temporarily whitelisting the did
+        Self::note_bridge_tx(&bridge_tx);
         if let Some(did) = T::CddChecker::get_key_cdd_did(&AccountKey::try_from(
             bridge_tx.recipient.clone().encode(),
         )?) {
@@ -872,10 +1421,12 @@ impl<T: Trait> Module<T> {
         F: Fn(BridgeTx<T::AccountId, T::Balance>) -> DispatchResult,
     {
         let g = |tx: BridgeTx<T::AccountId, T::Balance>| {
-            let nonce = tx.nonce;
+            let (recipient, nonce) = (tx.recipient.clone(), tx.nonce);
+            let result = f(tx);
+            Self::note_handled_tx(&recipient, nonce, &result);
             (
                 nonce,
-                if let Err(e) = f(tx) {
+                if let Err(e) = result {
                     HandledTxStatus::Error(e.encode())
                 } else {
                     HandledTxStatus::Success
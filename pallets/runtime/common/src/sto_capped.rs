@@ -48,7 +48,11 @@ use pallet_balances as balances;
 use pallet_compliance_manager as compliance_manager;
 use pallet_identity as identity;
 use polymesh_common_utilities::{
-    asset::Trait as AssetTrait, balances::Trait as BalancesTrait, CommonTrait, Context,
+    asset::Trait as AssetTrait,
+    balances::Trait as BalancesTrait,
+    identity::Trait as IdentityTrait,
+    protocol_fee::{ChargeProtocolFee, ProtocolOp},
+    CommonTrait, Context,
 };
 use polymesh_primitives::{AccountKey, IdentityId, Signatory, Ticker};
 
@@ -202,6 +206,10 @@ decl_module! {
 
             let sold:T::Balance = 0.into();
             ensure!(Self::is_owner(&ticker, did), Error::<T>::NotAnOwner);
+            <<T as IdentityTrait>::ProtocolFee>::charge_fee(
+                &sender,
+                ProtocolOp::StoCappedLaunchSto
+            )?;
 
             let sto = STO {
                 beneficiary_did,
@@ -9,7 +9,7 @@ use pallet_asset::{
 use pallet_balances as balances;
 use pallet_compliance_manager as compliance_manager;
 use pallet_identity as identity;
-use polymesh_common_utilities::{constants::*, traits::balances::Memo};
+use polymesh_common_utilities::{constants::*, protocol_fee::ProtocolOp, traits::balances::Memo};
 use polymesh_primitives::{
     AccountKey, AuthorizationData, Document, IdentityId, LinkData, Signatory, SmartExtension,
     SmartExtensionType, Ticker,
@@ -35,6 +35,7 @@ type Balances = balances::Module<TestStorage>;
 type Asset = asset::Module<TestStorage>;
 type Timestamp = pallet_timestamp::Module<TestStorage>;
 type ComplianceManager = compliance_manager::Module<TestStorage>;
+type ProtocolFee = pallet_protocol_fee::Module<TestStorage>;
 type AssetError = asset::Error<TestStorage>;
 type OffChainSignature = AnySignature;
 type Origin = <TestStorage as frame_system::Trait>::Origin;
@@ -1210,11 +1211,22 @@ fn add_extension_successfully() {
             is_archive: false,
         };
 
+        let fee = 100;
+        assert_ok!(ProtocolFee::change_base_fee(
+            Origin::system(frame_system::RawOrigin::Root),
+            ProtocolOp::AssetAddExtension,
+            fee
+        ));
+        let balance = Balances::free_balance(&AccountKeyring::Dave.public());
         assert_ok!(Asset::add_extension(
             owner_signed.clone(),
             ticker,
             extension_details.clone(),
         ));
+        assert_eq!(
+            Balances::free_balance(&AccountKeyring::Dave.public()),
+            balance - fee
+        );
 
         // verify the data within the runtime
         assert_eq!(
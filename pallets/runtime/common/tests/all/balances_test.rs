@@ -1,6 +1,6 @@
 use super::{
     storage::{
-        make_account, make_account_with_balance, make_account_without_cdd,
+        make_account, make_account_with_balance, make_account_without_cdd, make_cdd_id,
         register_keyring_account, EventTest, TestStorage,
     },
     ExtBuilder,
@@ -427,7 +427,7 @@ fn check_top_up_identity_balance() {
             assert_ok!(Identity::add_claim(
                 Origin::signed(AccountKeyring::Ferdie.public()),
                 acc_did,
-                Claim::CustomerDueDiligence,
+                Claim::CustomerDueDiligence(make_cdd_id(acc_did.as_bytes())),
                 None
             ));
 
@@ -7,8 +7,12 @@ use frame_support::{assert_err, assert_ok, traits::Currency, StorageDoubleMap};
 use pallet_balances as balances;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
-use polymesh_primitives::{AccountKey, Signatory};
-use polymesh_runtime_common::bridge::{self, BridgeTx, BridgeTxStatus};
+use polymesh_common_utilities::{protocol_fee::ProtocolOp, Context};
+use polymesh_primitives::{AccountKey, Claim, ClaimRequirement, ClaimType, Signatory};
+use polymesh_runtime_common::bridge::{
+    self, BridgeTx, BridgeTxStatus, HandledTxStatus, OutboundBridgeTx, OutboundTxStatus,
+};
+use sp_core::{H160, H256};
 use sp_runtime::traits::OnInitialize;
 use test_client::AccountKeyring;
 
@@ -16,7 +20,9 @@ type Bridge = bridge::Module<TestStorage>;
 type Error = bridge::Error<TestStorage>;
 type Balances = balances::Module<TestStorage>;
 type Authorizations = identity::Authorizations<TestStorage>;
+type Identity = identity::Module<TestStorage>;
 type MultiSig = multisig::Module<TestStorage>;
+type ProtocolFee = pallet_protocol_fee::Module<TestStorage>;
 type Origin = <TestStorage as frame_system::Trait>::Origin;
 type System = frame_system::Module<TestStorage>;
 
@@ -64,6 +70,7 @@ fn can_issue_to_identity_we() {
             Signatory::from(dave_key)
         ],
         2,
+        None,
     ));
 
     assert_eq!(MultiSig::ms_signs_required(controller), 2);
@@ -181,6 +188,7 @@ fn can_change_controller() {
                 Signatory::from(dave_key)
             ],
             2,
+            None,
         ));
 
         assert_eq!(MultiSig::ms_signs_required(controller), 2);
@@ -289,6 +297,7 @@ fn do_freeze_and_unfreeze_bridge() {
         alice.clone(),
         vec![Signatory::from(bob_key), Signatory::from(charlie_key),],
         2,
+        None,
     ));
 
     assert_eq!(MultiSig::ms_signs_required(controller), 2);
@@ -420,6 +429,7 @@ fn do_timelock_txs() {
         alice.clone(),
         vec![Signatory::from(bob_key), Signatory::from(charlie_key),],
         1,
+        None,
     ));
 
     assert_eq!(MultiSig::ms_signs_required(controller), 1);
@@ -540,6 +550,7 @@ fn do_rate_limit() {
         alice.clone(),
         vec![Signatory::from(bob_key), Signatory::from(charlie_key),],
         1,
+        None,
     ));
 
     assert_eq!(MultiSig::ms_signs_required(controller), 1);
@@ -667,6 +678,7 @@ fn do_whitelist() {
         alice.clone(),
         vec![Signatory::from(bob_key), Signatory::from(charlie_key),],
         1,
+        None,
     ));
 
     assert_eq!(MultiSig::ms_signs_required(controller), 1);
@@ -791,6 +803,7 @@ fn do_force_mint() {
         alice.clone(),
         vec![Signatory::from(bob_key), Signatory::from(charlie_key),],
         1,
+        None,
     ));
 
     assert_eq!(MultiSig::ms_signs_required(controller), 1);
@@ -884,3 +897,436 @@ fn do_force_mint() {
         BridgeTxStatus::Handled
     );
 }
+
+#[test]
+fn can_bridge_to_ethereum() {
+    ExtBuilder::default()
+        .existential_deposit(1_000)
+        .monied(true)
+        .build()
+        .execute_with(do_bridge_to_ethereum);
+}
+
+fn do_bridge_to_ethereum() {
+    let alice_did = register_keyring_account(AccountKeyring::Alice).unwrap();
+    let alice = Origin::signed(AccountKeyring::Alice.public());
+    let bob = Origin::signed(AccountKeyring::Bob.public());
+    let admin = Origin::system(frame_system::RawOrigin::Signed(Default::default()));
+    let recipient = H160::repeat_byte(1);
+    assert_ok!(Bridge::change_bridge_limit(admin.clone(), 1_000, 1));
+
+    // POLYX are burnt and the outbound transaction is recorded with a nonce.
+    let alices_balance = || Balances::free_balance(&AccountKeyring::Alice.public());
+    let starting_alices_balance = alices_balance();
+    let starting_issuance = Balances::total_issuance();
+    assert_ok!(Bridge::bridge_to_ethereum(alice.clone(), recipient, 600));
+    assert_eq!(alices_balance(), starting_alices_balance - 600);
+    assert_eq!(Balances::total_issuance(), starting_issuance - 600);
+    assert_eq!(
+        Bridge::outbound_txs(0),
+        Some(OutboundBridgeTx {
+            nonce: 0,
+            sender: AccountKeyring::Alice.public(),
+            recipient,
+            amount: 600,
+            requested_at: System::block_number(),
+            status: OutboundTxStatus::Requested,
+            tx_hash: Default::default(),
+        })
+    );
+
+    // Outbound transactions respect the bridge limit, unless whitelisted.
+    assert_err!(
+        Bridge::bridge_to_ethereum(alice.clone(), recipient, 500),
+        Error::BridgeLimitReached
+    );
+    assert_ok!(Bridge::change_bridge_whitelist(
+        admin.clone(),
+        vec![(alice_did, true)]
+    ));
+    assert_ok!(Bridge::bridge_to_ethereum(alice.clone(), recipient, 500));
+    assert_eq!(Bridge::outbound_tx_count(), 2);
+    assert_eq!(Bridge::polyx_bridged_out(alice_did).0, 600);
+
+    // No outbound transactions while the bridge is frozen.
+    assert_ok!(Bridge::freeze(admin.clone()));
+    assert_err!(
+        Bridge::bridge_to_ethereum(alice.clone(), recipient, 100),
+        Error::Frozen
+    );
+    assert_ok!(Bridge::unfreeze(admin.clone()));
+
+    // Only the controller or the admin attest completion.
+    let tx_hash = H256::repeat_byte(2);
+    assert_err!(
+        Bridge::complete_outbound_tx(bob, 0, tx_hash),
+        Error::BadCaller
+    );
+    assert_err!(
+        Bridge::complete_outbound_tx(admin.clone(), 5, tx_hash),
+        Error::NoSuchOutboundTx
+    );
+    assert_ok!(Bridge::complete_outbound_tx(admin.clone(), 0, tx_hash));
+    let completed = Bridge::outbound_txs(0).unwrap();
+    assert_eq!(completed.status, OutboundTxStatus::Completed);
+    assert_eq!(completed.tx_hash, tx_hash);
+    assert_err!(
+        Bridge::complete_outbound_tx(admin, 0, tx_hash),
+        Error::OutboundTxAlreadyCompleted
+    );
+}
+
+#[test]
+fn can_report_bridge_txs() {
+    ExtBuilder::default()
+        .existential_deposit(1_000)
+        .monied(true)
+        .build()
+        .execute_with(do_report_bridge_txs);
+}
+
+fn do_report_bridge_txs() {
+    let alice = Origin::signed(AccountKeyring::Alice.public());
+    let bob_key = AccountKey::from(AccountKeyring::Bob.public());
+    let charlie_key = AccountKey::from(AccountKeyring::Charlie.public());
+    let bob = Origin::signed(AccountKeyring::Bob.public());
+    let charlie = Origin::signed(AccountKeyring::Charlie.public());
+    register_keyring_account_with_balance(AccountKeyring::Alice, 1_000).unwrap();
+
+    let controller = MultiSig::get_next_multisig_address(AccountKeyring::Alice.public());
+    assert_ok!(MultiSig::create_multisig(
+        alice.clone(),
+        vec![Signatory::from(bob_key), Signatory::from(charlie_key)],
+        2,
+        None,
+    ));
+    let last_authorization = |account: AccountKey| {
+        <Authorizations>::iter_prefix(Signatory::from(account))
+            .next()
+            .unwrap()
+            .auth_id
+    };
+    assert_ok!(MultiSig::accept_multisig_signer_as_key(
+        bob.clone(),
+        last_authorization(bob_key)
+    ));
+    assert_ok!(MultiSig::accept_multisig_signer_as_key(
+        charlie.clone(),
+        last_authorization(charlie_key)
+    ));
+    let admin = Origin::system(frame_system::RawOrigin::Signed(Default::default()));
+    assert_ok!(Bridge::change_controller(admin.clone(), controller));
+    assert_ok!(Bridge::change_bridge_limit(
+        admin.clone(),
+        1_000_000_000_000_000_000_000_000,
+        1
+    ));
+    let timelock = 3;
+    assert_ok!(Bridge::change_timelock(admin.clone(), timelock));
+
+    let bridge_tx = |nonce, tx_hash| BridgeTx {
+        nonce,
+        recipient: AccountKeyring::Alice.public(),
+        amount: 1_000_000,
+        tx_hash,
+    };
+    let (hash1, hash2) = (H256::repeat_byte(1), H256::repeat_byte(2));
+    let tx1 = bridge_tx(1, hash1);
+    let tx2 = bridge_tx(2, hash2);
+
+    // A proposed transaction is reported with its approvals.
+    assert!(Bridge::txs_by_recipient(AccountKeyring::Alice.public()).is_empty());
+    assert_ok!(Bridge::propose_bridge_tx(bob.clone(), tx1.clone()));
+    let reports = Bridge::txs_by_recipient(AccountKeyring::Alice.public());
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].bridge_tx, tx1);
+    assert_eq!(reports[0].status, BridgeTxStatus::Absent);
+    assert_eq!(reports[0].proposal_id, Some(0));
+    assert_eq!(reports[0].approvals, 1);
+    assert_eq!(reports[0].handled, None);
+    assert!(Bridge::pending_txs(0, 10).is_empty());
+
+    // Approved transactions are timelocked.
+    assert_ok!(Bridge::propose_bridge_tx(charlie.clone(), tx1.clone()));
+    assert_ok!(Bridge::propose_bridge_tx(bob.clone(), tx2.clone()));
+    assert_ok!(Bridge::propose_bridge_tx(charlie.clone(), tx2.clone()));
+    let unlock_block_number = System::block_number() + timelock + 1;
+    let reports = Bridge::txs_by_tx_hash(hash1);
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].bridge_tx, tx1);
+    assert_eq!(reports[0].status, BridgeTxStatus::Timelocked);
+    assert_eq!(reports[0].approvals, 2);
+    assert_eq!(reports[0].execution_block, unlock_block_number);
+    assert_eq!(reports[0].handled, Some(HandledTxStatus::Success));
+    assert_eq!(Bridge::txs_by_tx_hash(hash2)[0].proposal_id, Some(1));
+
+    // Pending transactions are paginated in execution order.
+    let pending = |start, count| {
+        Bridge::pending_txs(start, count)
+            .into_iter()
+            .map(|report| report.bridge_tx)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(pending(0, 10), vec![tx1.clone(), tx2.clone()]);
+    assert_eq!(pending(0, 1), vec![tx1.clone()]);
+    assert_eq!(pending(1, 1), vec![tx2.clone()]);
+    assert!(pending(2, 1).is_empty());
+
+    // Handled transactions are no longer pending.
+    for _ in 0..=timelock {
+        next_block();
+    }
+    assert!(Bridge::pending_txs(0, 10).is_empty());
+    let reports = Bridge::txs_by_recipient(AccountKeyring::Alice.public());
+    assert_eq!(reports.len(), 2);
+    assert!(reports
+        .iter()
+        .all(|report| report.status == BridgeTxStatus::Handled
+            && report.handled == Some(HandledTxStatus::Success)));
+}
+
+#[test]
+fn can_use_limit_tiers() {
+    ExtBuilder::default()
+        .existential_deposit(1_000)
+        .monied(true)
+        .build()
+        .execute_with(do_use_limit_tiers);
+}
+
+fn do_use_limit_tiers() {
+    let alice_did = register_keyring_account(AccountKeyring::Alice).unwrap();
+    let bob_did = register_keyring_account(AccountKeyring::Bob).unwrap();
+    let charlie_did = register_keyring_account(AccountKeyring::Charlie).unwrap();
+    let alice = Origin::signed(AccountKeyring::Alice.public());
+    let bob = Origin::signed(AccountKeyring::Bob.public());
+    let admin = Origin::system(frame_system::RawOrigin::Signed(Default::default()));
+    let recipient = H160::repeat_byte(1);
+    let market_maker = b"market maker".to_vec();
+    let accredited = b"accredited".to_vec();
+    assert_ok!(Bridge::change_bridge_limit(admin.clone(), 1_000, 1));
+    assert_ok!(Bridge::change_limit_tier(
+        admin.clone(),
+        market_maker.clone(),
+        10_000,
+        1
+    ));
+    assert_ok!(Bridge::change_limit_tier(
+        admin.clone(),
+        accredited.clone(),
+        5_000,
+        1
+    ));
+    assert_err!(
+        Bridge::change_limit_tier(bob.clone(), accredited.clone(), 5_000, 1),
+        Error::BadAdmin
+    );
+    assert_eq!(Bridge::bridge_limit_of(alice_did), (1_000, 1));
+
+    // Tiers are assigned by claim.
+    assert_err!(
+        Bridge::change_claim_limit_tiers(
+            admin.clone(),
+            vec![(
+                ClaimRequirement {
+                    claim_type: ClaimType::Accredited,
+                    issuer: bob_did,
+                    scope: Some(bob_did),
+                },
+                b"unknown".to_vec(),
+            )]
+        ),
+        Error::NoSuchLimitTier
+    );
+    assert_ok!(Bridge::change_claim_limit_tiers(
+        admin.clone(),
+        vec![(
+            ClaimRequirement {
+                claim_type: ClaimType::Accredited,
+                issuer: bob_did,
+                scope: Some(bob_did),
+            },
+            accredited.clone(),
+        )]
+    ));
+    assert_ok!(Identity::add_claim(
+        bob.clone(),
+        alice_did,
+        Claim::Accredited(bob_did),
+        None
+    ));
+    assert_eq!(Bridge::bridge_limit_of(alice_did), (5_000, 1));
+
+    // A direct assignment takes precedence over claims.
+    assert_ok!(Bridge::change_identity_limit_tiers(
+        admin.clone(),
+        vec![(alice_did, Some(market_maker.clone()))]
+    ));
+    assert_eq!(Bridge::bridge_limit_of(alice_did), (10_000, 1));
+    System::set_block_number(5);
+    assert_ok!(Bridge::bridge_to_ethereum(alice.clone(), recipient, 8_000));
+    assert_err!(
+        Bridge::bridge_to_ethereum(alice.clone(), recipient, 3_000),
+        Error::BridgeLimitReached
+    );
+
+    // A total bridged under another interval duration is not carried over into a later interval.
+    assert_ok!(Bridge::change_limit_tier(
+        admin.clone(),
+        market_maker.clone(),
+        10_000,
+        1_000
+    ));
+    System::set_block_number(5_005);
+    assert_ok!(Bridge::bridge_to_ethereum(alice.clone(), recipient, 3_000));
+    assert_eq!(Bridge::polyx_bridged_out(alice_did), (3_000, 5_000));
+
+    // Removing a tier falls back to the claim tier and then to the default limit.
+    assert_ok!(Bridge::remove_limit_tier(
+        admin.clone(),
+        market_maker.clone()
+    ));
+    assert_eq!(Bridge::bridge_limit_of(alice_did), (5_000, 1));
+    assert_ok!(Bridge::remove_limit_tier(admin.clone(), accredited.clone()));
+    assert_eq!(Bridge::bridge_limit_of(alice_did), (1_000, 1));
+    assert_err!(
+        Bridge::remove_limit_tier(admin.clone(), accredited),
+        Error::NoSuchLimitTier
+    );
+
+    // The aggregate limit constrains all identities together.
+    assert_ok!(Bridge::change_aggregate_bridge_limit(
+        admin.clone(),
+        1_500,
+        1
+    ));
+    let bridge_tx = |nonce, recipient| BridgeTx {
+        nonce,
+        recipient,
+        amount: 1_000,
+        tx_hash: Default::default(),
+    };
+    let status = |nonce, recipient| Bridge::bridge_tx_details(recipient, &nonce).status;
+    let alice_key = AccountKeyring::Alice.public();
+    let charlie_key = AccountKeyring::Charlie.public();
+    assert_ok!(Bridge::handle_bridge_tx(
+        admin.clone(),
+        bridge_tx(1, alice_key)
+    ));
+    assert_eq!(status(1, alice_key), BridgeTxStatus::Handled);
+    assert_ok!(Bridge::handle_bridge_tx(
+        admin.clone(),
+        bridge_tx(1, charlie_key)
+    ));
+    assert_ne!(status(1, charlie_key), BridgeTxStatus::Handled);
+    assert_eq!(Bridge::polyx_bridged_total().0, 1_000);
+
+    // Whitelisted identities are not constrained by the aggregate limit but count towards it.
+    assert_ok!(Bridge::change_bridge_whitelist(
+        admin.clone(),
+        vec![(charlie_did, true)]
+    ));
+    assert_ok!(Bridge::handle_bridge_tx(
+        admin.clone(),
+        bridge_tx(2, charlie_key)
+    ));
+    assert_eq!(status(2, charlie_key), BridgeTxStatus::Handled);
+    assert_eq!(Bridge::polyx_bridged_total().0, 2_000);
+}
+
+#[test]
+fn can_charge_bridge_fee() {
+    ExtBuilder::default()
+        .existential_deposit(1_000)
+        .monied(true)
+        .build()
+        .execute_with(do_charge_bridge_fee);
+}
+
+fn do_charge_bridge_fee() {
+    let alice = Origin::signed(AccountKeyring::Alice.public());
+    let bob_key = AccountKey::from(AccountKeyring::Bob.public());
+    let charlie_key = AccountKey::from(AccountKeyring::Charlie.public());
+    let bob = Origin::signed(AccountKeyring::Bob.public());
+    let charlie = Origin::signed(AccountKeyring::Charlie.public());
+    let dave = Origin::signed(AccountKeyring::Dave.public());
+    register_keyring_account_with_balance(AccountKeyring::Alice, 1_000).unwrap();
+    let dave_did = register_keyring_account(AccountKeyring::Dave).unwrap();
+
+    let controller = MultiSig::get_next_multisig_address(AccountKeyring::Alice.public());
+    assert_ok!(MultiSig::create_multisig(
+        alice.clone(),
+        vec![Signatory::from(bob_key), Signatory::from(charlie_key)],
+        2,
+        None,
+    ));
+    let last_authorization = |account: AccountKey| {
+        <Authorizations>::iter_prefix(Signatory::from(account))
+            .next()
+            .unwrap()
+            .auth_id
+    };
+    assert_ok!(MultiSig::accept_multisig_signer_as_key(
+        bob.clone(),
+        last_authorization(bob_key)
+    ));
+    assert_ok!(MultiSig::accept_multisig_signer_as_key(
+        charlie.clone(),
+        last_authorization(charlie_key)
+    ));
+    let admin = Origin::system(frame_system::RawOrigin::Signed(Default::default()));
+    assert_ok!(Bridge::change_controller(admin.clone(), controller));
+    assert_ok!(Bridge::change_bridge_limit(admin.clone(), 1_000_000, 1));
+    let fee = 100;
+    assert_ok!(ProtocolFee::change_base_fee(
+        Origin::system(frame_system::RawOrigin::Root),
+        ProtocolOp::BridgeProposeBridgeTx,
+        fee
+    ));
+
+    let bridge_tx = |nonce| BridgeTx {
+        nonce,
+        recipient: AccountKeyring::Alice.public(),
+        amount: 1_000_000,
+        tx_hash: Default::default(),
+    };
+    let balance = |keyring: AccountKeyring| Balances::free_balance(&keyring.public());
+    let (bob_balance, charlie_balance, dave_balance) = (
+        balance(AccountKeyring::Bob),
+        balance(AccountKeyring::Charlie),
+        balance(AccountKeyring::Dave),
+    );
+
+    // Relayers proposing or approving bridge transactions are not charged.
+    assert_ok!(Bridge::propose_bridge_tx(bob.clone(), bridge_tx(1)));
+    assert_ok!(Bridge::propose_bridge_tx(charlie.clone(), bridge_tx(1)));
+    assert_ok!(Bridge::propose_bridge_txs(
+        charlie.clone(),
+        vec![bridge_tx(2), bridge_tx(3)]
+    ));
+    assert_eq!(balance(AccountKeyring::Bob), bob_balance);
+    assert_eq!(balance(AccountKeyring::Charlie), charlie_balance);
+
+    // Users bridging POLYX to Ethereum pay the fee on top of the bridged amount.
+    Context::set_current_identity::<Identity>(Some(dave_did));
+    assert_ok!(Bridge::bridge_to_ethereum(
+        dave.clone(),
+        H160::repeat_byte(1),
+        1_000
+    ));
+    assert_eq!(balance(AccountKeyring::Dave), dave_balance - 1_000 - fee);
+
+    // Nothing is bridged if the fee cannot be paid.
+    let dave_balance = balance(AccountKeyring::Dave);
+    assert_ok!(ProtocolFee::change_base_fee(
+        Origin::system(frame_system::RawOrigin::Root),
+        ProtocolOp::BridgeProposeBridgeTx,
+        dave_balance
+    ));
+    assert_err!(
+        Bridge::bridge_to_ethereum(dave, H160::repeat_byte(1), 1_000),
+        pallet_protocol_fee::Error::<TestStorage>::InsufficientAccountBalance
+    );
+    assert_eq!(balance(AccountKeyring::Dave), dave_balance);
+    assert_eq!(Bridge::outbound_tx_count(), 1);
+}
@@ -14,10 +14,10 @@ use pallet_pips::{
     self as pips, Pip, PipDescription, ProposalState, Referendum, ReferendumState, ReferendumType,
     Url,
 };
-use polymesh_common_utilities::Context;
+use polymesh_common_utilities::{Context, SystematicIssuers};
 use polymesh_primitives::IdentityId;
 use sp_core::H256;
-use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::traits::{BlakeTwo256, Hash, OnInitialize};
 use std::convert::TryFrom;
 use test_client::AccountKeyring;
 
@@ -522,6 +522,111 @@ fn release_coordinator_we() {
     assert_eq!(Committee::release_coordinator(), Some(alice_id));
 }
 
+#[test]
+fn automatic_closing() {
+    ExtBuilder::default()
+        .governance_committee_vote_threshold((2, 3))
+        .build()
+        .execute_with(automatic_closing_we);
+}
+
+fn automatic_closing_we() {
+    System::set_block_number(1);
+
+    let root = Origin::system(frame_system::RawOrigin::Root);
+    let (alice_signer, alice_did) = make_account(AccountKeyring::Alice.public()).unwrap();
+    let (bob_signer, bob_did) = make_account(AccountKeyring::Bob.public()).unwrap();
+    let (_, charlie_did) = make_account(AccountKeyring::Charlie.public()).unwrap();
+
+    Context::set_current_identity::<Identity>(Some(IdentityId::from(999)));
+    CommitteeGroup::reset_members(root.clone(), vec![alice_did, bob_did, charlie_did]).unwrap();
+    assert_err!(
+        Committee::set_prime(root.clone(), Some(IdentityId::from(999))),
+        committee::Error::<TestStorage, committee::Instance1>::MemberNotFound
+    );
+    assert_ok!(Committee::set_prime(root.clone(), Some(alice_did)));
+    assert_ok!(Committee::set_voting_period(root.clone(), Some(5)));
+    assert_eq!(Committee::motion_duration(), 5);
+    Context::set_current_identity::<Identity>(None);
+
+    // Alice (prime) approves and Bob rejects the first proposal. Charlie abstains.
+    let approved = make_proposal(1);
+    let approved_hash = BlakeTwo256::hash_of(&approved);
+    assert_ok!(Committee::propose(alice_signer, Box::new(approved)));
+    assert_ok!(Committee::vote(bob_signer.clone(), approved_hash, 0, false));
+
+    // Bob proposes the second one, but nobody else votes.
+    System::set_block_number(3);
+    let rejected = make_proposal(2);
+    let rejected_hash = BlakeTwo256::hash_of(&rejected);
+    assert_ok!(Committee::propose(bob_signer, Box::new(rejected)));
+    assert_eq!(Committee::voting(&rejected_hash).unwrap().end, 8);
+
+    // Proposals are still open before the end of their voting period.
+    Committee::on_initialize(5);
+    assert_eq!(Committee::proposals(), vec![approved_hash, rejected_hash]);
+
+    // Charlie's abstention follows the prime member, so the first proposal is approved.
+    let committee_did = SystematicIssuers::Committee.as_id();
+    let has_event = |event| System::events().iter().any(|r| r.event == event);
+    Committee::on_initialize(6);
+    assert_eq!(Committee::proposals(), vec![rejected_hash]);
+    assert!(has_event(EventTest::committee_Instance1(
+        CommitteeRawEvent::Closed(committee_did, approved_hash, 2, 1)
+    )));
+    assert!(has_event(EventTest::committee_Instance1(
+        CommitteeRawEvent::Approved(committee_did, approved_hash, 2, 1, 3)
+    )));
+
+    // The prime member did not vote on the second one, so abstentions reject it.
+    Committee::on_initialize(8);
+    assert_eq!(Committee::proposals(), vec![]);
+    assert_eq!(Committee::voting(&rejected_hash), None);
+    assert!(has_event(EventTest::committee_Instance1(
+        CommitteeRawEvent::Rejected(committee_did, rejected_hash, 1, 2, 3)
+    )));
+}
+
+#[test]
+fn automatic_closing_is_bounded() {
+    ExtBuilder::default()
+        .governance_committee_vote_threshold((2, 3))
+        .build()
+        .execute_with(automatic_closing_is_bounded_we);
+}
+
+fn automatic_closing_is_bounded_we() {
+    System::set_block_number(1);
+
+    let root = Origin::system(frame_system::RawOrigin::Root);
+    let (alice_signer, alice_did) = make_account(AccountKeyring::Alice.public()).unwrap();
+    let (_, bob_did) = make_account(AccountKeyring::Bob.public()).unwrap();
+    let (_, charlie_did) = make_account(AccountKeyring::Charlie.public()).unwrap();
+
+    Context::set_current_identity::<Identity>(Some(IdentityId::from(999)));
+    CommitteeGroup::reset_members(root.clone(), vec![alice_did, bob_did, charlie_did]).unwrap();
+    assert_ok!(Committee::set_voting_period(root, Some(5)));
+    Context::set_current_identity::<Identity>(None);
+
+    let count = committee::MAX_EXPIRED_CLOSES_PER_BLOCK as u64 + 1;
+    for value in 0..count {
+        assert_ok!(Committee::propose(
+            alice_signer.clone(),
+            Box::new(make_proposal(value))
+        ));
+    }
+    assert_eq!(Committee::proposals().len() as u64, count);
+
+    // Only `MAX_EXPIRED_CLOSES_PER_BLOCK` proposals are closed in a block, oldest first.
+    Committee::on_initialize(6);
+    let last_hash = BlakeTwo256::hash_of(&make_proposal(count - 1));
+    assert_eq!(Committee::proposals(), vec![last_hash]);
+
+    // The rest are closed in the next block.
+    Committee::on_initialize(7);
+    assert_eq!(Committee::proposals(), vec![]);
+}
+
 #[test]
 fn enact_referendum() {
     let committee = vec![
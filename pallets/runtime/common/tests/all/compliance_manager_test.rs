@@ -1,5 +1,6 @@
 use super::{
-    storage::{make_account, register_keyring_account, TestStorage},
+    ext_builder::PROTOCOL_OP_BASE_FEE,
+    storage::{make_account, make_cdd_id, register_keyring_account, TestStorage},
     ExtBuilder,
 };
 
@@ -9,7 +10,7 @@ use pallet_compliance_manager::{self as compliance_manager, AssetTransferRule, E
 use pallet_group::{self as group};
 use pallet_identity::{self as identity, BatchAddClaimItem};
 use polymesh_common_utilities::Context;
-use polymesh_primitives::{Claim, IdentityId, Rule, RuleType, Scope, Ticker};
+use polymesh_primitives::{CddId, Claim, IdentityId, Rule, RuleType, Scope, Ticker};
 
 use chrono::prelude::Utc;
 use frame_support::{assert_err, assert_ok, traits::Currency};
@@ -117,7 +118,7 @@ fn should_add_and_verify_asset_rule_we() {
 
     let receiver_rule1 = Rule {
         issuers: vec![cdd_id],
-        rule_type: RuleType::IsAbsent(Claim::CustomerDueDiligence),
+        rule_type: RuleType::IsAbsent(Claim::CustomerDueDiligence(CddId::default())),
     };
 
     let receiver_rule2 = Rule {
@@ -193,7 +194,7 @@ fn should_add_and_verify_asset_rule_we() {
     assert_ok!(Identity::add_claim(
         cdd_signed.clone(),
         token_owner_did,
-        Claim::CustomerDueDiligence,
+        Claim::CustomerDueDiligence(make_cdd_id(token_owner_did.as_bytes())),
         None,
     ));
 
@@ -278,6 +279,7 @@ fn should_replace_asset_rules_we() {
         .map(|(n, f)| f(n as u32))
         .collect();
 
+    let balance = Balances::free_balance(&token_owner_acc);
     assert_ok!(ComplianceManager::replace_asset_rules(
         token_owner_signed.clone(),
         ticker,
@@ -286,6 +288,22 @@ fn should_replace_asset_rules_we() {
 
     let asset_rules = ComplianceManager::asset_rules(ticker);
     assert_eq!(asset_rules.rules, new_asset_rules);
+    // The rule fee is charged once per rule.
+    assert_eq!(
+        Balances::free_balance(&token_owner_acc),
+        balance - 3 * PROTOCOL_OP_BASE_FEE
+    );
+
+    // Changing rules in batch is charged the rule fee once per rule too.
+    assert_ok!(ComplianceManager::change_asset_rule_batch(
+        token_owner_signed.clone(),
+        ticker,
+        new_asset_rules[1..].to_vec(),
+    ));
+    assert_eq!(
+        Balances::free_balance(&token_owner_acc),
+        balance - 5 * PROTOCOL_OP_BASE_FEE
+    );
 }
 
 #[test]
@@ -506,7 +524,7 @@ fn should_successfully_add_and_use_default_issuers_we() {
     assert_ok!(Identity::add_claim(
         trusted_issuer_signed.clone(),
         receiver_did.clone(),
-        Claim::CustomerDueDiligence,
+        Claim::CustomerDueDiligence(make_cdd_id(receiver_did.as_bytes())),
         Some(99999999999999999u64),
     ));
 
@@ -515,12 +533,12 @@ fn should_successfully_add_and_use_default_issuers_we() {
 
     let sender_rule = Rule {
         issuers: vec![],
-        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence),
+        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence(CddId::default())),
     };
 
     let receiver_rule = Rule {
         issuers: vec![],
-        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence),
+        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence(CddId::default())),
     };
 
     assert_ok!(ComplianceManager::add_active_rule(
@@ -544,7 +562,7 @@ fn should_successfully_add_and_use_default_issuers_we() {
     assert_ok!(Identity::add_claim(
         trusted_issuer_signed.clone(),
         token_owner_did.clone(),
-        Claim::CustomerDueDiligence,
+        Claim::CustomerDueDiligence(make_cdd_id(token_owner_did.as_bytes())),
         Some(99999999999999999u64),
     ));
     assert_ok!(Asset::transfer(
@@ -652,7 +670,7 @@ fn should_modify_vector_of_trusted_issuer_we() {
     assert_ok!(Identity::add_claim(
         trusted_issuer_signed_1.clone(),
         receiver_did.clone(),
-        Claim::CustomerDueDiligence,
+        Claim::CustomerDueDiligence(make_cdd_id(receiver_did.as_bytes())),
         None,
     ));
 
@@ -668,7 +686,7 @@ fn should_modify_vector_of_trusted_issuer_we() {
     assert_ok!(Identity::add_claim(
         trusted_issuer_signed_2.clone(),
         token_owner_did.clone(),
-        Claim::CustomerDueDiligence,
+        Claim::CustomerDueDiligence(make_cdd_id(token_owner_did.as_bytes())),
         None,
     ));
 
@@ -677,12 +695,12 @@ fn should_modify_vector_of_trusted_issuer_we() {
 
     let sender_rule = Rule {
         issuers: vec![],
-        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence),
+        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence(CddId::default())),
     };
 
     let receiver_rule_1 = Rule {
         issuers: vec![],
-        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence),
+        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence(CddId::default())),
     };
 
     let receiver_rule_2 = Rule {
@@ -737,7 +755,7 @@ fn should_modify_vector_of_trusted_issuer_we() {
 
     let receiver_rule_1 = Rule {
         issuers: vec![trusted_issuer_did_1],
-        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence),
+        rule_type: RuleType::IsPresent(Claim::CustomerDueDiligence(CddId::default())),
     };
 
     let receiver_rule_2 = Rule {
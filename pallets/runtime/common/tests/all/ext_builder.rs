@@ -325,6 +325,8 @@ impl ExtBuilder {
             proposal_duration: 10,
             proposal_cool_off_period: 100,
             default_enactment_period: 100,
+            conviction_lock_period: 100,
+            technical_review_period: 100,
         }
         .assimilate_storage(&mut storage)
         .unwrap();
@@ -6,11 +6,13 @@ use super::{
 
 use codec::Encode;
 use frame_support::{assert_err, assert_ok, StorageDoubleMap};
+use pallet_asset::{self as asset, AssetType};
 use pallet_balances as balances;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
 use pallet_transaction_payment::CddAndFeeDetails;
-use polymesh_primitives::{AccountKey, Signatory, TransactionError};
+use polymesh_primitives::{AccountKey, Signatory, Ticker, TransactionError};
+use polymesh_runtime_common::dividend;
 use polymesh_runtime_develop::{fee_details::CddHandler, runtime::Call};
 use sp_runtime::transaction_validity::InvalidTransaction;
 use std::convert::TryFrom;
@@ -18,6 +20,8 @@ use test_client::AccountKeyring;
 
 type MultiSig = multisig::Module<TestStorage>;
 type Balances = balances::Module<TestStorage>;
+type Asset = asset::Module<TestStorage>;
+type AssetError = asset::Error<TestStorage>;
 
 #[test]
 fn cdd_checks() {
@@ -53,7 +57,8 @@ fn cdd_checks() {
             assert_eq!(
                 CddHandler::get_valid_payer(
                     &Call::Identity(identity::Call::register_did(Default::default())),
-                    &alice_did_signatory
+                    &alice_did_signatory,
+                    0
                 ),
                 Ok(Some(alice_did_signatory))
             );
@@ -62,7 +67,8 @@ fn cdd_checks() {
             assert_err!(
                 CddHandler::get_valid_payer(
                     &Call::MultiSig(multisig::Call::change_sigs_required(1)),
-                    &alice_did_signatory
+                    &alice_did_signatory,
+                    0
                 ),
                 InvalidTransaction::Custom(TransactionError::CddRequired as u8)
             );
@@ -71,7 +77,8 @@ fn cdd_checks() {
             assert_err!(
                 CddHandler::get_valid_payer(
                     &Call::MultiSig(multisig::Call::accept_multisig_signer_as_key(0)),
-                    &alice_key_signatory
+                    &alice_key_signatory,
+                    0
                 ),
                 InvalidTransaction::Custom(TransactionError::InvalidAuthorization as u8)
             );
@@ -81,6 +88,7 @@ fn cdd_checks() {
                 alice_signed.clone(),
                 vec![alice_key_signatory],
                 1,
+                None,
             ));
             assert_ok!(MultiSig::make_multisig_signer(
                 alice_signed.clone(),
@@ -95,7 +103,8 @@ fn cdd_checks() {
             assert_err!(
                 CddHandler::get_valid_payer(
                     &Call::MultiSig(multisig::Call::accept_multisig_signer_as_key(alice_auth_id)),
-                    &alice_key_signatory
+                    &alice_key_signatory,
+                    0
                 ),
                 InvalidTransaction::Custom(TransactionError::CddRequired as u8)
             );
@@ -108,6 +117,7 @@ fn cdd_checks() {
                 charlie_signed.clone(),
                 vec![alice_key_signatory],
                 1,
+                None,
             ));
             let alice_auth_id = <identity::Authorizations<TestStorage>>::iter_prefix(
                 Signatory::from(alice_key_signatory),
@@ -124,7 +134,8 @@ fn cdd_checks() {
             assert_eq!(
                 CddHandler::get_valid_payer(
                     &Call::MultiSig(multisig::Call::accept_multisig_signer_as_key(alice_auth_id)),
-                    &alice_key_signatory
+                    &alice_key_signatory,
+                    0
                 ),
                 Ok(Some(charlie_did_signatory))
             );
@@ -133,7 +144,8 @@ fn cdd_checks() {
             assert_eq!(
                 CddHandler::get_valid_payer(
                     &Call::MultiSig(multisig::Call::change_sigs_required(1)),
-                    &charlie_key_signatory
+                    &charlie_key_signatory,
+                    0
                 ),
                 Ok(Some(charlie_key_signatory))
             );
@@ -142,9 +154,104 @@ fn cdd_checks() {
             assert_eq!(
                 CddHandler::get_valid_payer(
                     &Call::Balances(balances::Call::change_charge_did_flag(true)),
-                    &charlie_key_signatory
+                    &charlie_key_signatory,
+                    0
                 ),
                 Ok(Some(charlie_did_signatory))
             );
         });
 }
+
+#[test]
+fn sponsored_fees() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        let (owner_signed, owner_did) = make_account(AccountKeyring::Dave.public()).unwrap();
+        let owner_did_signatory = Signatory::from(owner_did);
+        let (alice_signed, alice_did) = make_account(AccountKeyring::Alice.public()).unwrap();
+        let alice_key_signatory =
+            Signatory::from(AccountKey::try_from(AccountKeyring::Alice.public().encode()).unwrap());
+        let (_, charlie_did) = make_account(AccountKeyring::Charlie.public()).unwrap();
+        let charlie_key_signatory = Signatory::from(
+            AccountKey::try_from(AccountKeyring::Charlie.public().encode()).unwrap(),
+        );
+        let ticker = Ticker::try_from(&[0x01][..]).unwrap();
+        assert_ok!(Asset::create_asset(
+            owner_signed.clone(),
+            vec![0x01].into(),
+            ticker,
+            1_000_000,
+            true,
+            AssetType::default(),
+            vec![],
+            None
+        ));
+        assert_ok!(Balances::top_up_identity_balance(
+            owner_signed.clone(),
+            owner_did,
+            1_000
+        ));
+        let transfer = Call::Asset(asset::Call::transfer(ticker, charlie_did, 10));
+        let claim = Call::Dividend(dividend::Call::claim(ticker, 0));
+
+        // Without a sponsorship the investor pays.
+        assert_eq!(
+            CddHandler::get_valid_payer(&transfer, &alice_key_signatory, 100),
+            Ok(Some(alice_key_signatory))
+        );
+
+        // Only the token owner can sponsor fees.
+        assert_err!(
+            Asset::set_fee_sponsorship(alice_signed, ticker, 300, 200),
+            AssetError::NotAnOwner
+        );
+        assert_ok!(Asset::set_fee_sponsorship(
+            owner_signed.clone(),
+            ticker,
+            300,
+            200
+        ));
+
+        // The sponsor pays within the investor cap.
+        assert_eq!(
+            CddHandler::get_valid_payer(&transfer, &alice_key_signatory, 150),
+            Ok(Some(owner_did_signatory))
+        );
+        assert_eq!(
+            CddHandler::get_valid_payer(&claim, &alice_key_signatory, 50),
+            Ok(Some(owner_did_signatory))
+        );
+        assert_eq!(Asset::sponsored_fees(ticker, alice_did), 200);
+        assert_eq!(Asset::fee_sponsorship(ticker).unwrap().budget, 100);
+
+        // The investor pays once the cap is reached.
+        assert_eq!(
+            CddHandler::get_valid_payer(&transfer, &alice_key_signatory, 1),
+            Ok(Some(alice_key_signatory))
+        );
+
+        // The investor pays when the budget is exhausted.
+        assert_eq!(
+            CddHandler::get_valid_payer(&transfer, &charlie_key_signatory, 150),
+            Ok(Some(charlie_key_signatory))
+        );
+
+        // Other calls are not sponsored.
+        assert_eq!(
+            CddHandler::get_valid_payer(
+                &Call::MultiSig(multisig::Call::change_sigs_required(1)),
+                &charlie_key_signatory,
+                0
+            ),
+            Ok(Some(charlie_key_signatory))
+        );
+
+        // Removing the sponsorship resets the sponsored amounts.
+        assert_ok!(Asset::remove_fee_sponsorship(owner_signed.clone(), ticker));
+        assert_eq!(Asset::fee_sponsorship(ticker), None);
+        assert_eq!(Asset::sponsored_fees(ticker, alice_did), 0);
+        assert_err!(
+            Asset::remove_fee_sponsorship(owner_signed, ticker),
+            AssetError::NoFeeSponsorship
+        );
+    });
+}
@@ -1,18 +1,24 @@
 use super::{
-    storage::{get_identity_id, make_account, register_keyring_account, TestStorage},
+    storage::{
+        get_identity_id, make_account, make_account_with_balance, register_keyring_account,
+        TestStorage,
+    },
     ExtBuilder,
 };
+use pallet_balances as balances;
 use pallet_group::{self as group};
 use pallet_identity as identity;
 use polymesh_common_utilities::{traits::group::GroupTrait, Context};
 use polymesh_primitives::IdentityId;
 
 use frame_support::{assert_err, assert_noop, assert_ok};
+use sp_runtime::traits::OnInitialize;
 use test_client::AccountKeyring;
 
 type CommitteeGroup = group::Module<TestStorage, group::Instance1>;
 type Origin = <TestStorage as frame_system::Trait>::Origin;
 type Identity = identity::Module<TestStorage>;
+type Balances = balances::Module<TestStorage>;
 
 #[test]
 fn query_membership_works() {
@@ -282,3 +288,145 @@ fn disable_member_we() {
         vec![alice_id, charlie_id, bob_id]
     );
 }
+
+#[test]
+fn term_elections() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Eve.public()])
+        .build()
+        .execute_with(term_elections_we);
+}
+
+fn term_elections_we() {
+    let root = Origin::system(frame_system::RawOrigin::Root);
+    let (alice_signer, alice_did) =
+        make_account_with_balance(AccountKeyring::Alice.public(), 1_000).unwrap();
+    let (bob_signer, bob_did) =
+        make_account_with_balance(AccountKeyring::Bob.public(), 600).unwrap();
+    let (charlie_signer, charlie_did) =
+        make_account_with_balance(AccountKeyring::Charlie.public(), 500).unwrap();
+    let (dave_signer, dave_did) =
+        make_account_with_balance(AccountKeyring::Dave.public(), 100).unwrap();
+
+    Context::set_current_identity::<Identity>(Some(IdentityId::from(999)));
+    assert_ok!(CommitteeGroup::reset_members(
+        root.clone(),
+        vec![alice_did, charlie_did]
+    ));
+
+    // Elections are disabled until a term is configured.
+    Context::set_current_identity::<Identity>(Some(bob_did));
+    assert_noop!(
+        CommitteeGroup::submit_candidacy(bob_signer.clone()),
+        group::Error::<TestStorage, group::Instance1>::ElectionsDisabled
+    );
+    Context::set_current_identity::<Identity>(Some(IdentityId::from(999)));
+    assert_noop!(
+        CommitteeGroup::set_election_config(root.clone(), 10, 0),
+        group::Error::<TestStorage, group::Instance1>::InvalidElectionConfig
+    );
+    assert_ok!(CommitteeGroup::set_election_config(root.clone(), 10, 2));
+    assert_eq!(CommitteeGroup::term_end(), 10);
+
+    // Bob, Charlie and Dave run for election. Disabled members cannot.
+    Context::set_current_identity::<Identity>(Some(bob_did));
+    assert_ok!(CommitteeGroup::submit_candidacy(bob_signer.clone()));
+    assert_noop!(
+        CommitteeGroup::submit_candidacy(bob_signer.clone()),
+        group::Error::<TestStorage, group::Instance1>::DuplicateCandidate
+    );
+    Context::set_current_identity::<Identity>(Some(charlie_did));
+    assert_ok!(CommitteeGroup::submit_candidacy(charlie_signer.clone()));
+    Context::set_current_identity::<Identity>(Some(dave_did));
+    assert_ok!(CommitteeGroup::submit_candidacy(dave_signer.clone()));
+    Context::set_current_identity::<Identity>(Some(IdentityId::from(999)));
+    assert_ok!(CommitteeGroup::disable_member(
+        root.clone(),
+        alice_did,
+        None,
+        None
+    ));
+    Context::set_current_identity::<Identity>(Some(alice_did));
+    assert_noop!(
+        CommitteeGroup::submit_candidacy(alice_signer.clone()),
+        group::Error::<TestStorage, group::Instance1>::DisabledMember
+    );
+
+    // Approval votes weighted by the locked stake.
+    assert_noop!(
+        CommitteeGroup::vote_candidates(
+            alice_signer.clone(),
+            vec![bob_did, charlie_did, dave_did],
+            900
+        ),
+        group::Error::<TestStorage, group::Instance1>::InvalidVote
+    );
+    assert_noop!(
+        CommitteeGroup::vote_candidates(alice_signer.clone(), vec![alice_did], 900),
+        group::Error::<TestStorage, group::Instance1>::NotACandidate
+    );
+    assert_noop!(
+        CommitteeGroup::vote_candidates(alice_signer.clone(), vec![bob_did], 995),
+        group::Error::<TestStorage, group::Instance1>::InsufficientBalance
+    );
+    assert_ok!(CommitteeGroup::vote_candidates(
+        alice_signer.clone(),
+        vec![bob_did, charlie_did],
+        900
+    ));
+    let alice_acc = AccountKeyring::Alice.public();
+    assert_eq!(Balances::reserved_balance(alice_acc), 10);
+    assert_eq!(Balances::locks(&alice_acc)[0].amount, 900);
+    Context::set_current_identity::<Identity>(Some(bob_did));
+    assert_ok!(CommitteeGroup::vote_candidates(
+        bob_signer,
+        vec![dave_did],
+        500
+    ));
+    Context::set_current_identity::<Identity>(Some(charlie_did));
+    assert_ok!(CommitteeGroup::vote_candidates(
+        charlie_signer.clone(),
+        vec![dave_did],
+        300
+    ));
+    // A new ballot replaces the previous one without taking another deposit.
+    assert_ok!(CommitteeGroup::vote_candidates(
+        charlie_signer,
+        vec![dave_did, bob_did],
+        450
+    ));
+    let charlie_acc = AccountKeyring::Charlie.public();
+    assert_eq!(Balances::reserved_balance(charlie_acc), 10);
+    assert_eq!(CommitteeGroup::voter_count(), 3);
+
+    // The number of ballots is capped.
+    Context::set_current_identity::<Identity>(Some(dave_did));
+    assert_noop!(
+        CommitteeGroup::vote_candidates(dave_signer, vec![dave_did], 50),
+        group::Error::<TestStorage, group::Instance1>::TooManyVoters
+    );
+    Context::set_current_identity::<Identity>(None);
+
+    // Nothing happens before the end of the term.
+    CommitteeGroup::on_initialize(9);
+    assert_eq!(CommitteeGroup::get_members(), vec![charlie_did]);
+
+    // Bob (1_350) and Dave (950) replace Charlie (900).
+    CommitteeGroup::on_initialize(10);
+    let mut elected = vec![bob_did, dave_did];
+    elected.sort();
+    assert_eq!(CommitteeGroup::get_members(), elected);
+    assert_eq!(
+        CommitteeGroup::get_valid_members(),
+        vec![elected[0], elected[1], alice_did]
+    );
+    assert_eq!(CommitteeGroup::term_end(), 20);
+    assert!(CommitteeGroup::candidates().is_empty());
+    assert_eq!(CommitteeGroup::votes(alice_acc), None);
+    assert_eq!(CommitteeGroup::voter_count(), 0);
+
+    // Stakes and deposits are released.
+    assert!(Balances::locks(&alice_acc).is_empty());
+    assert_eq!(Balances::reserved_balance(alice_acc), 0);
+    assert_eq!(Balances::reserved_balance(charlie_acc), 0);
+}
@@ -1,13 +1,14 @@
 use super::{
     ext_builder::PROTOCOL_OP_BASE_FEE,
     storage::{
-        add_signing_item, authorizations_to, get_identity_id, register_keyring_account,
-        register_keyring_account_with_balance, GovernanceCommittee, TestStorage,
+        add_signing_item, authorizations_to, get_identity_id, make_cdd_id,
+        register_keyring_account, register_keyring_account_with_balance, GovernanceCommittee,
+        TestStorage,
     },
     ExtBuilder,
 };
 
-use pallet_identity_rpc_runtime_api::LinkType;
+use pallet_identity_rpc_runtime_api::{AuthorizationFilter, AuthorizationType, LinkType};
 use polymesh_common_utilities::{
     traits::{
         group::GroupTrait,
@@ -16,23 +17,26 @@ use polymesh_common_utilities::{
     SystematicIssuers,
 };
 use polymesh_primitives::{
-    AccountKey, AuthorizationData, AuthorizationError, Claim, ClaimType, Document, IdentityClaim,
-    IdentityId, LinkData, Permission, Scope, Signatory, SigningItem, Ticker, TransactionError,
+    AccountKey, AuthorizationData, AuthorizationError, CddId, Claim, ClaimType, Document,
+    IdentityClaim, IdentityId, InvestorUid, LinkData, Permission, Scope, Signatory, SigningItem,
+    Ticker, TransactionError,
 };
 use polymesh_runtime_develop::{fee_details::CddHandler, runtime::Call};
 
+use pallet_asset as asset;
 use pallet_balances as balances;
 use pallet_identity::{self as identity, BatchAddClaimItem, BatchRevokeClaimItem, Error};
 use pallet_transaction_payment::CddAndFeeDetails;
 
 use codec::Encode;
-use frame_support::{assert_err, assert_ok, traits::Currency, StorageDoubleMap};
+use frame_support::{assert_err, assert_ok, traits::Currency, StorageDoubleMap, StorageMap};
 use sp_core::H512;
-use sp_runtime::transaction_validity::InvalidTransaction;
+use sp_runtime::{traits::OnRuntimeUpgrade, transaction_validity::InvalidTransaction};
 use test_client::AccountKeyring;
 
 use std::convert::{From, TryFrom};
 
+type Asset = asset::Module<TestStorage>;
 type Balances = balances::Module<TestStorage>;
 // type BalancesCall = <balances::Module<TestStorage> as BTrait>::Call;
 
@@ -85,11 +89,12 @@ fn add_claims_batch() {
     let cdd_claim_did = get_identity_id(AccountKeyring::Eve).unwrap();
 
     let scope = Scope::from(0);
+    let alice_cdd_id = make_cdd_id(alice_did.as_bytes());
 
     let claim_records = vec![
         BatchAddClaimItem {
             target: alice_did,
-            claim: Claim::CustomerDueDiligence,
+            claim: Claim::CustomerDueDiligence(alice_cdd_id),
             expiry: None,
         },
         BatchAddClaimItem {
@@ -124,7 +129,7 @@ fn add_claims_batch() {
     assert_eq!(claim1.expiry, None);
     assert_eq!(claim2.expiry, None);
 
-    assert_eq!(claim1.claim, Claim::CustomerDueDiligence);
+    assert_eq!(claim1.claim, Claim::CustomerDueDiligence(alice_cdd_id));
     assert_eq!(claim2.claim, Claim::Affiliate(scope));
 }
 
@@ -517,6 +522,7 @@ fn frozen_signing_keys_cdd_verification_test_we() {
             None,
         )),
         &Signatory::from(AccountKey::from(bob.0)),
+        0,
     );
     assert_err!(
         payer,
@@ -895,6 +901,109 @@ fn adding_authorizations() {
     });
 }
 
+#[test]
+fn get_authorizations_with_filter() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_did = Signatory::from(register_keyring_account(AccountKeyring::Alice).unwrap());
+        let bob_did = Signatory::from(register_keyring_account(AccountKeyring::Bob).unwrap());
+        let charlie_id = register_keyring_account(AccountKeyring::Charlie).unwrap();
+        let charlie_did = Signatory::from(charlie_id);
+        let ticker50 = Ticker::try_from(&[0x50][..]).unwrap();
+
+        let auth_1 = Identity::add_auth(
+            alice_did,
+            bob_did,
+            AuthorizationData::TransferTicker(ticker50),
+            None,
+        );
+        let auth_2 = Identity::add_auth(
+            alice_did,
+            bob_did,
+            AuthorizationData::TransferTicker(ticker50),
+            Some(100),
+        );
+        let auth_3 = Identity::add_auth(
+            charlie_did,
+            bob_did,
+            AuthorizationData::JoinIdentity(charlie_id),
+            None,
+        );
+        let auth_4 = Identity::add_auth(
+            alice_did,
+            bob_did,
+            AuthorizationData::Custom(ticker50),
+            Some(1_000),
+        );
+        Timestamp::set_timestamp(500);
+
+        let ids = |filter: AuthorizationFilter<Signatory, u64>| {
+            Identity::get_authorizations(bob_did, filter)
+                .into_iter()
+                .map(|auth| auth.auth_id)
+                .collect::<Vec<_>>()
+        };
+
+        // Expired authorizations are skipped unless requested.
+        assert_eq!(
+            ids(AuthorizationFilter::default()),
+            vec![auth_1, auth_3, auth_4]
+        );
+        assert_eq!(
+            ids(AuthorizationFilter {
+                allow_expired: true,
+                ..Default::default()
+            }),
+            vec![auth_1, auth_2, auth_3, auth_4]
+        );
+
+        // Filter by type, issuer and expiry.
+        assert_eq!(
+            ids(AuthorizationFilter {
+                auth_type: Some(AuthorizationType::TransferTicker),
+                ..Default::default()
+            }),
+            vec![auth_1]
+        );
+        assert_eq!(
+            ids(AuthorizationFilter {
+                authorized_by: Some(charlie_did),
+                ..Default::default()
+            }),
+            vec![auth_3]
+        );
+        assert_eq!(
+            ids(AuthorizationFilter {
+                expires_before: Some(2_000),
+                allow_expired: true,
+                ..Default::default()
+            }),
+            vec![auth_2, auth_4]
+        );
+
+        // Pagination.
+        assert_eq!(
+            ids(AuthorizationFilter {
+                limit: Some(2),
+                ..Default::default()
+            }),
+            vec![auth_1, auth_3]
+        );
+        assert_eq!(
+            ids(AuthorizationFilter {
+                start_after: Some(auth_3),
+                limit: Some(2),
+                ..Default::default()
+            }),
+            vec![auth_4]
+        );
+        assert!(ids(AuthorizationFilter {
+            limit: Some(0),
+            ..Default::default()
+        })
+        .is_empty());
+    });
+}
+
 #[test]
 fn removing_authorizations() {
     ExtBuilder::default().build().execute_with(|| {
@@ -1148,6 +1257,7 @@ fn cdd_register_did_test_we() {
     assert_ok!(Identity::cdd_register_did(
         cdd1.clone(),
         alice,
+        make_cdd_id(alice),
         Some(10),
         vec![]
     ));
@@ -1157,12 +1267,28 @@ fn cdd_register_did_test_we() {
     assert_eq!(Identity::has_valid_cdd(alice_id), true);
 
     // Error case: Try account without ID.
-    assert!(Identity::cdd_register_did(non_id, bob_acc, Some(10), vec![]).is_err(),);
+    assert!(
+        Identity::cdd_register_did(non_id, bob_acc, make_cdd_id(bob_acc), Some(10), vec![])
+            .is_err(),
+    );
     // Error case: Try account with ID but it is not part of CDD providers.
-    assert!(Identity::cdd_register_did(Origin::signed(alice), bob_acc, Some(10), vec![]).is_err());
+    assert!(Identity::cdd_register_did(
+        Origin::signed(alice),
+        bob_acc,
+        make_cdd_id(bob_acc),
+        Some(10),
+        vec![]
+    )
+    .is_err());
 
     // CDD 2 registers properly Bob's ID.
-    assert_ok!(Identity::cdd_register_did(cdd2, bob_acc, Some(10), vec![]));
+    assert_ok!(Identity::cdd_register_did(
+        cdd2,
+        bob_acc,
+        make_cdd_id(bob_acc),
+        Some(10),
+        vec![]
+    ));
     let bob_id = get_identity_id(AccountKeyring::Bob).unwrap();
     assert_eq!(Identity::has_valid_cdd(bob_id), true);
 
@@ -1177,6 +1303,7 @@ fn cdd_register_did_test_we() {
     assert_ok!(Identity::cdd_register_did(
         cdd1.clone(),
         charlie,
+        make_cdd_id(charlie),
         Some(10),
         signing_keys
     ));
@@ -1228,6 +1355,248 @@ fn cdd_register_did_test_we() {
     );
 }
 
+#[test]
+fn cdd_id_uniqueness_test() {
+    ExtBuilder::default()
+        .monied(true)
+        .cdd_providers(vec![
+            AccountKeyring::Eve.public(),
+            AccountKeyring::Ferdie.public(),
+        ])
+        .build()
+        .execute_with(|| cdd_id_uniqueness_test_we());
+}
+
+fn cdd_id_uniqueness_test_we() {
+    let cdd1 = Origin::signed(AccountKeyring::Eve.public());
+    let cdd2 = Origin::signed(AccountKeyring::Ferdie.public());
+    let alice_acc = AccountKeyring::Alice.public();
+    let bob_acc = AccountKeyring::Bob.public();
+    let alice_uid = InvestorUid::from(b"alice_unique_uid");
+    let alice_cdd_id = CddId::new(alice_uid);
+
+    // Empty commitments are rejected.
+    assert_err!(
+        Identity::cdd_register_did(cdd1.clone(), alice_acc, CddId::default(), None, vec![]),
+        Error::<TestStorage>::InvalidCddId
+    );
+
+    // CDD 1 registers Alice.
+    assert_ok!(Identity::cdd_register_did(
+        cdd1.clone(),
+        alice_acc,
+        alice_cdd_id,
+        None,
+        vec![]
+    ));
+    let alice_id = get_identity_id(AccountKeyring::Alice).unwrap();
+    assert_eq!(Identity::cdd_id_owner(alice_cdd_id), Some(alice_id));
+    assert_eq!(Identity::has_valid_cdd(alice_id), true);
+
+    // Another provider cannot use the same person for a new identity.
+    assert_err!(
+        Identity::cdd_register_did(cdd2.clone(), bob_acc, alice_cdd_id, None, vec![]),
+        Error::<TestStorage>::CddIdAlreadyInUse
+    );
+    assert_ok!(Identity::cdd_register_did(
+        cdd2.clone(),
+        bob_acc,
+        make_cdd_id(bob_acc),
+        None,
+        vec![]
+    ));
+    let bob_id = get_identity_id(AccountKeyring::Bob).unwrap();
+    assert_err!(
+        Identity::add_claim(
+            cdd2.clone(),
+            bob_id,
+            Claim::CustomerDueDiligence(alice_cdd_id),
+            None
+        ),
+        Error::<TestStorage>::CddIdAlreadyInUse
+    );
+
+    // Legacy CDD claims without a commitment cannot be added any more.
+    assert_err!(
+        Identity::add_claim(
+            cdd2.clone(),
+            bob_id,
+            Claim::LegacyCustomerDueDiligence,
+            None
+        ),
+        Error::<TestStorage>::InvalidCddId
+    );
+
+    // ... but it can renew the CDD claim of the same identity.
+    assert_ok!(Identity::add_claim(
+        cdd2,
+        alice_id,
+        Claim::CustomerDueDiligence(alice_cdd_id),
+        None
+    ));
+
+    // The opening proves the link between Alice's identity and her unique ID.
+    assert_eq!(Identity::is_cdd_id_opening_valid(alice_id, alice_uid), true);
+    assert_eq!(Identity::is_cdd_id_opening_valid(bob_id, alice_uid), false);
+    assert_eq!(
+        Identity::is_cdd_id_opening_valid(alice_id, InvestorUid::from(b"bob_unique_uid")),
+        false
+    );
+}
+
+#[test]
+fn close_identity_test() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(|| close_identity_test_we());
+}
+
+fn close_identity_test_we() {
+    let alice = Origin::signed(AccountKeyring::Alice.public());
+    let alice_did = register_keyring_account_with_balance(AccountKeyring::Alice, 10_000).unwrap();
+    let bob_did = register_keyring_account(AccountKeyring::Bob).unwrap();
+    let ticker = Ticker::try_from(&[0x50][..]).unwrap();
+    let auth_id = Identity::add_auth(
+        Signatory::from(alice_did),
+        Signatory::from(bob_did),
+        AuthorizationData::TransferTicker(ticker),
+        None,
+    );
+
+    // Alice cannot close her identity while it holds POLYX.
+    assert_ok!(Balances::top_up_identity_balance(
+        alice.clone(),
+        alice_did,
+        1_000
+    ));
+    assert_err!(
+        Identity::close_identity(alice.clone()),
+        Error::<TestStorage>::DidHasHoldings
+    );
+    assert_ok!(Balances::reclaim_identity_balance(
+        alice.clone(),
+        alice_did,
+        1_000
+    ));
+
+    // Tokens received before holdings were tracked also prevent closing once backfilled.
+    <asset::BalanceOf<TestStorage>>::insert(ticker, alice_did, 100);
+    <asset::AssetsHeld>::remove(alice_did);
+    <Asset as OnRuntimeUpgrade>::on_runtime_upgrade();
+    assert!(Asset::assets_held_backfilled());
+    assert_eq!(Asset::assets_held(alice_did), vec![ticker]);
+    assert!(Asset::assets_held(bob_did).is_empty());
+    assert_err!(
+        Identity::close_identity(alice.clone()),
+        Error::<TestStorage>::DidHasHoldings
+    );
+    <asset::BalanceOf<TestStorage>>::insert(ticker, alice_did, 0);
+
+    // Alice cannot close her identity while she owns a ticker.
+    let link_id = Identity::add_link(
+        Signatory::from(alice_did),
+        LinkData::TickerOwned(ticker),
+        None,
+    );
+    assert_err!(
+        Identity::close_identity(alice.clone()),
+        Error::<TestStorage>::DidOwnsAssets
+    );
+    Identity::remove_link(Signatory::from(alice_did), link_id);
+
+    // Only the master key can close the identity.
+    add_signing_item(
+        alice_did,
+        Signatory::from(AccountKey::from(AccountKeyring::Charlie.public().0)),
+    );
+    assert!(Identity::close_identity(Origin::signed(AccountKeyring::Charlie.public())).is_err());
+
+    assert_ok!(Identity::close_identity(alice.clone()));
+    assert_eq!(Identity::is_did_closed(alice_did), true);
+    assert_eq!(Identity::has_valid_cdd(alice_did), false);
+    assert!(!<identity::AuthorizationsGiven>::contains_key(
+        Signatory::from(alice_did),
+        auth_id
+    ));
+    assert!(!<identity::Authorizations<TestStorage>>::contains_key(
+        Signatory::from(bob_did),
+        auth_id
+    ));
+
+    // A closed identity cannot be closed again.
+    assert_err!(
+        Identity::close_identity(alice),
+        Error::<TestStorage>::DidIsClosed
+    );
+}
+
+#[test]
+fn merge_identities_test() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(|| merge_identities_test_we());
+}
+
+fn merge_identities_test_we() {
+    let root = Origin::system(frame_system::RawOrigin::Root);
+    let alice = Origin::signed(AccountKeyring::Alice.public());
+    let alice_did = register_keyring_account_with_balance(AccountKeyring::Alice, 10_000).unwrap();
+    let bob_did = register_keyring_account(AccountKeyring::Bob).unwrap();
+    let charlie_key = AccountKey::from(AccountKeyring::Charlie.public().0);
+    add_signing_item(alice_did, Signatory::from(charlie_key));
+    assert_ok!(Balances::top_up_identity_balance(alice, alice_did, 1_000));
+
+    // Only root can merge, and never into the same identity.
+    assert!(Identity::merge_identities(
+        Origin::signed(AccountKeyring::Alice.public()),
+        alice_did,
+        bob_did
+    )
+    .is_err());
+    assert_err!(
+        Identity::merge_identities(root.clone(), alice_did, alice_did),
+        Error::<TestStorage>::CannotMergeIntoItself
+    );
+
+    // A holding that cannot be moved stops the merge before anything is moved.
+    let ticker = Ticker::try_from(&[0x50][..]).unwrap();
+    <asset::BalanceOf<TestStorage>>::insert(ticker, alice_did, 1_000_000);
+    <asset::AssetsHeld>::insert(alice_did, vec![ticker]);
+    <asset::Frozen>::insert(ticker, true);
+    assert_err!(
+        Identity::merge_identities(root.clone(), alice_did, bob_did),
+        asset::Error::<TestStorage>::InvalidTransfer
+    );
+    assert_eq!(Balances::identity_balance(alice_did), 1_000);
+    assert_eq!(Asset::balance_of(ticker, alice_did), 1_000_000);
+    <asset::Frozen>::insert(ticker, false);
+
+    assert_ok!(Identity::merge_identities(root.clone(), alice_did, bob_did));
+
+    // Holdings, claims and signing items now belong to Bob.
+    assert_eq!(Balances::identity_balance(alice_did), 0);
+    assert_eq!(Balances::identity_balance(bob_did), 1_000);
+    assert_eq!(Asset::balance_of(ticker, alice_did), 0);
+    assert_eq!(Asset::balance_of(ticker, bob_did), 1_000_000);
+    assert_eq!(Identity::get_identity(&charlie_key), Some(bob_did));
+    assert!(Identity::did_records(bob_did)
+        .signing_items
+        .iter()
+        .any(|si| si.signer == Signatory::from(charlie_key)));
+    assert!(Identity::did_records(alice_did).signing_items.is_empty());
+    assert_eq!(Identity::has_valid_cdd(bob_did), true);
+
+    // Alice's identity is closed.
+    assert_eq!(Identity::is_did_closed(alice_did), true);
+    assert_eq!(Identity::has_valid_cdd(alice_did), false);
+    assert_err!(
+        Identity::merge_identities(root, alice_did, bob_did),
+        Error::<TestStorage>::DidIsClosed
+    );
+}
+
 #[test]
 fn add_identity_signers() {
     ExtBuilder::default().monied(true).build().execute_with(|| {
@@ -1370,6 +1739,7 @@ fn invalidate_cdd_claims_we() {
     assert_ok!(Identity::cdd_register_did(
         Origin::signed(cdd_1_acc),
         alice_acc,
+        make_cdd_id(alice_acc),
         Some(10),
         vec![]
     ));
@@ -1387,7 +1757,13 @@ fn invalidate_cdd_claims_we() {
     Timestamp::set_timestamp(8);
     assert_eq!(Identity::has_valid_cdd(alice_id), true);
     assert_err!(
-        Identity::cdd_register_did(Origin::signed(cdd_1_acc), bob_acc, Some(10), vec![]),
+        Identity::cdd_register_did(
+            Origin::signed(cdd_1_acc),
+            bob_acc,
+            make_cdd_id(bob_acc),
+            Some(10),
+            vec![]
+        ),
         Error::<TestStorage>::UnAuthorizedCddProvider
     );
 
@@ -1395,7 +1771,13 @@ fn invalidate_cdd_claims_we() {
     Timestamp::set_timestamp(11);
     assert_eq!(Identity::has_valid_cdd(alice_id), false);
     assert_err!(
-        Identity::cdd_register_did(Origin::signed(cdd_1_acc), bob_acc, Some(20), vec![]),
+        Identity::cdd_register_did(
+            Origin::signed(cdd_1_acc),
+            bob_acc,
+            make_cdd_id(bob_acc),
+            Some(20),
+            vec![]
+        ),
         Error::<TestStorage>::UnAuthorizedCddProvider
     );
 }
@@ -1442,6 +1824,7 @@ fn cdd_provider_with_systematic_cdd_claims_we() {
     assert_ok!(Identity::cdd_register_did(
         alice,
         charlie_acc.clone(),
+        make_cdd_id(charlie_acc),
         None,
         vec![]
     ));
@@ -1510,6 +1893,7 @@ fn gc_with_systematic_cdd_claims_we() {
     assert_ok!(Identity::cdd_register_did(
         alice,
         ferdie_acc.clone(),
+        make_cdd_id(ferdie_acc),
         None,
         vec![]
     ));
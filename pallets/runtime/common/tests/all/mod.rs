@@ -24,6 +24,7 @@ mod pips_test;
 mod protocol_fee;
 mod simple_token_test;
 mod statistics_test;
+mod sto_capped_test;
 mod treasury_test;
 mod utility_test;
 mod voting_test;
@@ -6,9 +6,9 @@ use super::{
 
 use pallet_balances as balances;
 use pallet_identity as identity;
-use pallet_multisig as multisig;
-use polymesh_common_utilities::Context;
-use polymesh_primitives::{AccountKey, IdentityId, Signatory};
+use pallet_multisig::{self as multisig, ProposalDetails, ProposalStatus};
+use polymesh_common_utilities::{protocol_fee::ProtocolOp, Context};
+use polymesh_primitives::{AccountKey, Document, IdentityId, Signatory};
 
 use codec::Encode;
 use frame_support::{assert_err, assert_ok, StorageDoubleMap};
@@ -18,6 +18,8 @@ use test_client::AccountKeyring;
 type Balances = balances::Module<TestStorage>;
 type Identity = identity::Module<TestStorage>;
 type MultiSig = multisig::Module<TestStorage>;
+type ProtocolFee = pallet_protocol_fee::Module<TestStorage>;
+type Timestamp = pallet_timestamp::Module<TestStorage>;
 type Origin = <TestStorage as frame_system::Trait>::Origin;
 type Error = multisig::Error<TestStorage>;
 
@@ -34,6 +36,7 @@ fn create_multisig() {
             alice.clone(),
             vec![Signatory::from(alice_did), Signatory::from(bob_did)],
             1,
+            None,
         ));
 
         assert_eq!(MultiSig::ms_signs_required(musig_address), 1);
@@ -44,7 +47,7 @@ fn create_multisig() {
         );
 
         assert_err!(
-            MultiSig::create_multisig(alice.clone(), vec![], 10,),
+            MultiSig::create_multisig(alice.clone(), vec![], 10, None),
             Error::NoSigners
         );
 
@@ -53,6 +56,7 @@ fn create_multisig() {
                 alice.clone(),
                 vec![Signatory::from(alice_did), Signatory::from(bob_did)],
                 0,
+                None,
             ),
             Error::RequiredSignaturesOutOfBounds
         );
@@ -62,12 +66,49 @@ fn create_multisig() {
                 alice.clone(),
                 vec![Signatory::from(alice_did), Signatory::from(bob_did)],
                 10,
+                None,
             ),
             Error::RequiredSignaturesOutOfBounds
         );
     });
 }
 
+#[test]
+fn create_multisig_charges_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_did = register_keyring_account(AccountKeyring::Alice).unwrap();
+        let alice = Origin::signed(AccountKeyring::Alice.public());
+        let fee = 100;
+        assert_ok!(ProtocolFee::change_base_fee(
+            Origin::system(frame_system::RawOrigin::Root),
+            ProtocolOp::MultiSigCreateMultiSig,
+            fee
+        ));
+        let balance = Balances::free_balance(&AccountKeyring::Alice.public());
+
+        assert_ok!(MultiSig::create_multisig(
+            alice.clone(),
+            vec![Signatory::from(alice_did)],
+            1,
+            None,
+        ));
+        assert_eq!(
+            Balances::free_balance(&AccountKeyring::Alice.public()),
+            balance - fee
+        );
+
+        // A rejected multisig is not charged.
+        assert_err!(
+            MultiSig::create_multisig(alice.clone(), vec![], 1, None),
+            Error::NoSigners
+        );
+        assert_eq!(
+            Balances::free_balance(&AccountKeyring::Alice.public()),
+            balance - fee
+        );
+    });
+}
+
 #[test]
 fn join_multisig() {
     ExtBuilder::default().build().execute_with(|| {
@@ -83,6 +124,7 @@ fn join_multisig() {
             alice.clone(),
             vec![Signatory::from(alice_did), bob_signer],
             1,
+            None,
         ));
 
         assert_eq!(
@@ -136,6 +178,7 @@ fn join_multisig() {
             alice.clone(),
             vec![Signatory::from(alice_did), bob_signer],
             1,
+            None,
         ));
 
         let bob_auth_id2 = <identity::Authorizations<TestStorage>>::iter_prefix(bob_signer)
@@ -165,6 +208,7 @@ fn change_multisig_sigs_required() {
             alice.clone(),
             vec![Signatory::from(alice_did), bob_signer],
             2,
+            None,
         ));
 
         let alice_auth_id =
@@ -205,7 +249,9 @@ fn change_multisig_sigs_required() {
         assert_ok!(MultiSig::create_proposal_as_key(
             bob.clone(),
             musig_address.clone(),
-            call
+            call,
+            None,
+            None
         ));
 
         assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 2);
@@ -234,6 +280,7 @@ fn create_or_approve_change_multisig_sigs_required() {
             alice.clone(),
             vec![Signatory::from(alice_did), bob_signer],
             2,
+            None,
         ));
         let alice_auth_id =
             <identity::Authorizations<TestStorage>>::iter_prefix(Signatory::from(alice_did))
@@ -266,13 +313,17 @@ fn create_or_approve_change_multisig_sigs_required() {
         assert_ok!(MultiSig::create_or_approve_proposal_as_key(
             bob.clone(),
             musig_address.clone(),
-            call.clone()
+            call.clone(),
+            None,
+            None
         ));
         assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 2);
         assert_ok!(MultiSig::create_or_approve_proposal_as_identity(
             alice.clone(),
             musig_address.clone(),
-            call
+            call,
+            None,
+            None
         ));
         assert_eq!(MultiSig::ms_signs_required(musig_address), 1);
     });
@@ -294,6 +345,7 @@ fn remove_multisig_signer() {
             alice.clone(),
             vec![alice_signer, bob_signer],
             1,
+            None,
         ));
 
         assert_eq!(MultiSig::number_of_signers(musig_address.clone()), 0);
@@ -347,7 +399,9 @@ fn remove_multisig_signer() {
         assert_ok!(MultiSig::create_proposal_as_identity(
             alice.clone(),
             musig_address.clone(),
-            call
+            call,
+            None,
+            None
         ));
 
         assert_eq!(MultiSig::number_of_signers(musig_address.clone()), 1);
@@ -378,7 +432,9 @@ fn remove_multisig_signer() {
         assert_ok!(MultiSig::create_proposal_as_identity(
             alice.clone(),
             musig_address.clone(),
-            remove_alice
+            remove_alice,
+            None,
+            None
         ));
 
         // Alice not removed since that would've broken the multi sig.
@@ -408,6 +464,7 @@ fn add_multisig_signer() {
             alice.clone(),
             vec![Signatory::from(alice_did)],
             1,
+            None,
         ));
 
         let alice_auth_id =
@@ -432,23 +489,28 @@ fn add_multisig_signer() {
         );
 
         let call = Box::new(Call::MultiSig(multisig::Call::add_multisig_signer(
-            bob_signer,
+            bob_signer, None,
         )));
 
         assert_ok!(MultiSig::create_proposal_as_identity(
             alice.clone(),
             musig_address.clone(),
-            call
+            call,
+            None,
+            None
         ));
 
         let call2 = Box::new(Call::MultiSig(multisig::Call::add_multisig_signer(
             charlie_signer,
+            None,
         )));
 
         assert_ok!(MultiSig::create_proposal_as_identity(
             alice.clone(),
             musig_address.clone(),
-            call2
+            call2,
+            None,
+            None
         ));
 
         assert_eq!(
@@ -533,6 +595,7 @@ fn should_change_all_signers_and_sigs_required() {
             alice.clone(),
             vec![Signatory::from(alice_did), Signatory::from(bob_did)],
             1,
+            None,
         ));
 
         let alice_auth_id =
@@ -576,7 +639,9 @@ fn should_change_all_signers_and_sigs_required() {
         assert_ok!(MultiSig::create_proposal_as_identity(
             alice.clone(),
             musig_address.clone(),
-            call
+            call,
+            None,
+            None
         ));
 
         assert_eq!(
@@ -645,6 +710,7 @@ fn make_multisig_master() {
             alice.clone(),
             vec![Signatory::from(alice_did)],
             1,
+            None,
         ));
 
         assert_eq!(
@@ -685,6 +751,7 @@ fn make_multisig_signer() {
             alice.clone(),
             vec![Signatory::from(alice_did)],
             1,
+            None,
         ));
 
         let signing_items = Identity::did_records(alice_did).signing_items;
@@ -726,6 +793,7 @@ fn remove_multisig_signers_via_creator() {
             alice.clone(),
             vec![alice_signer, bob_signer],
             1,
+            None,
         ));
 
         assert_eq!(MultiSig::number_of_signers(musig_address.clone()), 0);
@@ -823,6 +891,7 @@ fn add_multisig_signers_via_creator() {
             alice.clone(),
             vec![Signatory::from(alice_did)],
             1,
+            None,
         ));
 
         let alice_auth_id =
@@ -903,6 +972,7 @@ fn check_for_approval_closure() {
             alice.clone(),
             vec![Signatory::from(alice_did), Signatory::from(eve_did)],
             1,
+            None,
         ));
 
         let alice_auth_id =
@@ -945,13 +1015,15 @@ fn check_for_approval_closure() {
         );
 
         let call = Box::new(Call::MultiSig(multisig::Call::add_multisig_signer(
-            bob_signer,
+            bob_signer, None,
         )));
         Context::set_current_identity::<Identity>(Some(alice_did));
         assert_ok!(MultiSig::create_proposal_as_identity(
             alice.clone(),
             musig_address.clone(),
-            call.clone()
+            call.clone(),
+            None,
+            None
         ));
         let proposal_id = MultiSig::proposal_ids(musig_address.clone(), call).unwrap();
         let mut auth = <identity::Authorizations<TestStorage>>::iter_prefix(bob_signer);
@@ -979,3 +1051,431 @@ fn check_for_approval_closure() {
         );
     });
 }
+
+#[test]
+fn reject_expire_and_cancel_proposals() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_did = register_keyring_account(AccountKeyring::Alice).unwrap();
+        let bob_did = register_keyring_account(AccountKeyring::Bob).unwrap();
+        let charlie_did = register_keyring_account(AccountKeyring::Charlie).unwrap();
+        let alice = Origin::signed(AccountKeyring::Alice.public());
+        let bob = Origin::signed(AccountKeyring::Bob.public());
+        let charlie = Origin::signed(AccountKeyring::Charlie.public());
+
+        let musig_address = MultiSig::get_next_multisig_address(AccountKeyring::Alice.public());
+
+        assert_ok!(MultiSig::create_multisig(
+            alice.clone(),
+            vec![
+                Signatory::from(alice_did),
+                Signatory::from(bob_did),
+                Signatory::from(charlie_did)
+            ],
+            2,
+            None,
+        ));
+        for (origin, did) in [
+            (alice.clone(), alice_did),
+            (bob.clone(), bob_did),
+            (charlie.clone(), charlie_did),
+        ]
+        .iter()
+        {
+            let auth_id =
+                <identity::Authorizations<TestStorage>>::iter_prefix(Signatory::from(*did))
+                    .next()
+                    .unwrap()
+                    .auth_id;
+            Context::set_current_identity::<Identity>(Some(*did));
+            assert_ok!(MultiSig::accept_multisig_signer_as_identity(
+                origin.clone(),
+                auth_id
+            ));
+        }
+
+        let call = Box::new(Call::MultiSig(multisig::Call::change_sigs_required(1)));
+
+        // Rejection.
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            call.clone(),
+            None,
+            None
+        ));
+        let proposal_id = MultiSig::proposal_ids(musig_address.clone(), call.clone()).unwrap();
+        let multisig_proposal = (musig_address.clone(), proposal_id);
+
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_ok!(MultiSig::reject_as_identity(
+            bob.clone(),
+            musig_address.clone(),
+            proposal_id
+        ));
+        assert_err!(
+            MultiSig::reject_as_identity(bob.clone(), musig_address.clone(), proposal_id),
+            Error::AlreadyRejected
+        );
+        assert_err!(
+            MultiSig::approve_as_identity(bob.clone(), musig_address.clone(), proposal_id),
+            Error::AlreadyRejected
+        );
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_err!(
+            MultiSig::reject_as_identity(alice.clone(), musig_address.clone(), proposal_id),
+            Error::AlreadyApproved
+        );
+        assert_eq!(MultiSig::is_proposal_closed(&multisig_proposal), false);
+
+        // Two rejections out of three signers make two approvals impossible.
+        Context::set_current_identity::<Identity>(Some(charlie_did));
+        assert_ok!(MultiSig::reject_as_identity(
+            charlie.clone(),
+            musig_address.clone(),
+            proposal_id
+        ));
+        assert_eq!(MultiSig::tx_rejections(&multisig_proposal), 2);
+        assert_eq!(MultiSig::is_proposal_closed(&multisig_proposal), true);
+        assert_eq!(
+            MultiSig::proposal_status(&multisig_proposal),
+            multisig::ProposalStatus::Rejected
+        );
+        assert_eq!(
+            MultiSig::proposal_ids(musig_address.clone(), call.clone()),
+            None
+        );
+        assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 2);
+
+        // Cancellation.
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            call.clone(),
+            None,
+            None
+        ));
+        let proposal_id = MultiSig::proposal_ids(musig_address.clone(), call.clone()).unwrap();
+        let multisig_proposal = (musig_address.clone(), proposal_id);
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_err!(
+            MultiSig::cancel_proposal_as_identity(bob.clone(), musig_address.clone(), proposal_id),
+            Error::NotProposalCreator
+        );
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_ok!(MultiSig::cancel_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            proposal_id
+        ));
+        assert_eq!(
+            MultiSig::proposal_status(&multisig_proposal),
+            multisig::ProposalStatus::Cancelled
+        );
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_err!(
+            MultiSig::approve_as_identity(bob.clone(), musig_address.clone(), proposal_id),
+            Error::ProposalAlreadyClosed
+        );
+
+        // Expiry.
+        Timestamp::set_timestamp(100);
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_err!(
+            MultiSig::create_proposal_as_identity(
+                alice.clone(),
+                musig_address.clone(),
+                call.clone(),
+                Some(50),
+                None
+            ),
+            Error::InvalidExpiry
+        );
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            call.clone(),
+            Some(200),
+            None
+        ));
+        let proposal_id = MultiSig::proposal_ids(musig_address.clone(), call.clone()).unwrap();
+        let multisig_proposal = (musig_address.clone(), proposal_id);
+        Timestamp::set_timestamp(200);
+        Context::set_current_identity::<Identity>(Some(bob_did));
+
+        // A late approval closes the proposal instead, so the call can be proposed again.
+        assert_ok!(MultiSig::approve_as_identity(
+            bob.clone(),
+            musig_address.clone(),
+            proposal_id
+        ));
+        assert_eq!(
+            MultiSig::proposal_status(&multisig_proposal),
+            ProposalStatus::Expired
+        );
+        assert_eq!(MultiSig::is_proposal_closed(&multisig_proposal), true);
+        assert_eq!(
+            MultiSig::proposal_ids(musig_address.clone(), call.clone()),
+            None
+        );
+        assert_err!(
+            MultiSig::approve_as_identity(bob.clone(), musig_address.clone(), proposal_id),
+            Error::ProposalAlreadyClosed
+        );
+        assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 2);
+
+        // An expired proposal is replaced by a new one when the same call is proposed again.
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            call.clone(),
+            Some(300),
+            None
+        ));
+        let expiring_id = MultiSig::proposal_ids(musig_address.clone(), call.clone()).unwrap();
+        Timestamp::set_timestamp(300);
+        assert!(MultiSig::get_open_proposals(musig_address.clone(), 0, 10)
+            .iter()
+            .all(|open| open.id != expiring_id));
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_ok!(MultiSig::create_or_approve_proposal_as_identity(
+            bob,
+            musig_address.clone(),
+            call.clone(),
+            None,
+            None
+        ));
+        assert_eq!(
+            MultiSig::proposal_status((musig_address.clone(), expiring_id)),
+            ProposalStatus::Expired
+        );
+        let new_id = MultiSig::proposal_ids(musig_address.clone(), call).unwrap();
+        assert_eq!(new_id, expiring_id + 1);
+        assert_eq!(
+            MultiSig::proposal_status((musig_address, new_id)),
+            ProposalStatus::Active
+        );
+    });
+}
+
+#[test]
+fn weighted_signers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_did = register_keyring_account(AccountKeyring::Alice).unwrap();
+        let bob_did = register_keyring_account(AccountKeyring::Bob).unwrap();
+        let charlie_did = register_keyring_account(AccountKeyring::Charlie).unwrap();
+        let alice = Origin::signed(AccountKeyring::Alice.public());
+        let bob = Origin::signed(AccountKeyring::Bob.public());
+        let charlie = Origin::signed(AccountKeyring::Charlie.public());
+        let signers = vec![
+            Signatory::from(alice_did),
+            Signatory::from(bob_did),
+            Signatory::from(charlie_did),
+        ];
+
+        assert_err!(
+            MultiSig::create_multisig(alice.clone(), signers.clone(), 2, Some(vec![2, 1])),
+            Error::SignerWeightsMismatch
+        );
+        assert_err!(
+            MultiSig::create_multisig(alice.clone(), signers.clone(), 2, Some(vec![2, 1, 0])),
+            Error::InvalidSignerWeight
+        );
+        assert_err!(
+            MultiSig::create_multisig(alice.clone(), signers.clone(), 5, Some(vec![2, 1, 1])),
+            Error::RequiredSignaturesOutOfBounds
+        );
+
+        // Alice weighs as much as Bob and Charlie together.
+        let musig_address = MultiSig::get_next_multisig_address(AccountKeyring::Alice.public());
+        assert_ok!(MultiSig::create_multisig(
+            alice.clone(),
+            signers,
+            2,
+            Some(vec![2, 1, 1]),
+        ));
+        for (origin, did) in [
+            (alice.clone(), alice_did),
+            (bob.clone(), bob_did),
+            (charlie.clone(), charlie_did),
+        ]
+        .iter()
+        {
+            let auth_id =
+                <identity::Authorizations<TestStorage>>::iter_prefix(Signatory::from(*did))
+                    .next()
+                    .unwrap()
+                    .auth_id;
+            Context::set_current_identity::<Identity>(Some(*did));
+            assert_ok!(MultiSig::accept_multisig_signer_as_identity(
+                origin.clone(),
+                auth_id
+            ));
+        }
+        assert_eq!(
+            MultiSig::signer_weight(&musig_address, &Signatory::from(alice_did)),
+            2
+        );
+        assert_eq!(MultiSig::total_signer_weight(&musig_address), 4);
+
+        // Two clerks reach the threshold.
+        let call = Box::new(Call::MultiSig(multisig::Call::change_sigs_required(3)));
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            bob.clone(),
+            musig_address.clone(),
+            call,
+            None,
+            None
+        ));
+        assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 2);
+        Context::set_current_identity::<Identity>(Some(charlie_did));
+        assert_ok!(MultiSig::approve_as_identity(
+            charlie.clone(),
+            musig_address.clone(),
+            0
+        ));
+        assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 3);
+
+        // The threshold cannot exceed the total weight of the signers.
+        let call = Box::new(Call::MultiSig(multisig::Call::change_sigs_required(5)));
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            call,
+            None,
+            None
+        ));
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_ok!(MultiSig::approve_as_identity(
+            bob.clone(),
+            musig_address.clone(),
+            1
+        ));
+        assert_eq!(MultiSig::tx_approvals(&(musig_address.clone(), 1)), 3);
+        assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 3);
+
+        // The CFO and a clerk reach the new threshold, but removing the CFO would make it
+        // unreachable.
+        let call = Box::new(Call::MultiSig(multisig::Call::change_sigs_required(4)));
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            call,
+            None,
+            None
+        ));
+        Context::set_current_identity::<Identity>(Some(charlie_did));
+        assert_ok!(MultiSig::approve_as_identity(
+            charlie,
+            musig_address.clone(),
+            2
+        ));
+        assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 4);
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_err!(
+            MultiSig::remove_multisig_signers_via_creator(
+                alice,
+                musig_address,
+                vec![Signatory::from(alice_did)]
+            ),
+            Error::NotEnoughSigners
+        );
+    });
+}
+
+#[test]
+fn proposal_details_and_execution_result() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_did = register_keyring_account(AccountKeyring::Alice).unwrap();
+        let bob_did = register_keyring_account(AccountKeyring::Bob).unwrap();
+        let alice = Origin::signed(AccountKeyring::Alice.public());
+        let bob = Origin::signed(AccountKeyring::Bob.public());
+
+        let musig_address = MultiSig::get_next_multisig_address(AccountKeyring::Alice.public());
+
+        assert_ok!(MultiSig::create_multisig(
+            alice.clone(),
+            vec![Signatory::from(alice_did), Signatory::from(bob_did)],
+            2,
+            None,
+        ));
+        for (origin, did) in [(alice.clone(), alice_did), (bob.clone(), bob_did)].iter() {
+            let auth_id =
+                <identity::Authorizations<TestStorage>>::iter_prefix(Signatory::from(*did))
+                    .next()
+                    .unwrap()
+                    .auth_id;
+            Context::set_current_identity::<Identity>(Some(*did));
+            assert_ok!(MultiSig::accept_multisig_signer_as_identity(
+                origin.clone(),
+                auth_id
+            ));
+        }
+
+        let details = ProposalDetails {
+            description: b"Raise the threshold above the number of signers".to_vec(),
+            document: Some(Document {
+                name: b"memo".into(),
+                uri: b"www.example.com".into(),
+                content_hash: b"0x1".into(),
+            }),
+        };
+        let call = Box::new(Call::MultiSig(multisig::Call::change_sigs_required(3)));
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        let long_details = ProposalDetails {
+            description: vec![b'a'; multisig::MAX_DESCRIPTION_LENGTH + 1],
+            document: None,
+        };
+        assert_err!(
+            MultiSig::create_proposal_as_identity(
+                alice.clone(),
+                musig_address.clone(),
+                call.clone(),
+                None,
+                Some(long_details)
+            ),
+            Error::ProposalDescriptionTooLong
+        );
+        assert_ok!(MultiSig::create_proposal_as_identity(
+            alice.clone(),
+            musig_address.clone(),
+            call.clone(),
+            None,
+            Some(details.clone())
+        ));
+        let multisig_proposal = (musig_address.clone(), 0);
+        assert_eq!(
+            MultiSig::proposal_details(&multisig_proposal),
+            Some(details.clone())
+        );
+
+        let open_proposals = MultiSig::get_open_proposals(musig_address.clone(), 0, 10);
+        assert_eq!(open_proposals.len(), 1);
+        assert_eq!(open_proposals[0].id, 0);
+        assert_eq!(open_proposals[0].proposal, *call);
+        assert_eq!(open_proposals[0].details, Some(details));
+        assert_eq!(
+            open_proposals[0].approvers,
+            vec![Signatory::from(alice_did)]
+        );
+        assert_eq!(open_proposals[0].approvals, 1);
+        assert!(MultiSig::get_open_proposals(musig_address.clone(), 1, 10).is_empty());
+        assert!(MultiSig::get_open_proposals(musig_address.clone(), 0, 0).is_empty());
+
+        // The inner call fails because there are only two signers.
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_ok!(MultiSig::approve_as_identity(bob, musig_address.clone(), 0));
+        assert_eq!(
+            MultiSig::proposal_status(&multisig_proposal),
+            ProposalStatus::ExecutionFailed
+        );
+        assert!(MultiSig::proposal_execution_error(&multisig_proposal).is_some());
+        assert_eq!(MultiSig::ms_signs_required(musig_address.clone()), 2);
+        assert!(MultiSig::get_open_proposals(musig_address, 0, 10).is_empty());
+    });
+}
@@ -1,21 +1,22 @@
 use super::{
     storage::{
-        get_identity_id, make_account, make_account_with_balance,
+        add_signing_item, get_identity_id, make_account, make_account_with_balance,
         register_keyring_account_with_balance, Call, TestStorage,
     },
     ExtBuilder,
 };
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, traits::Currency};
 use frame_system;
 use pallet_balances as balances;
 use pallet_committee as committee;
 use pallet_group as group;
 use pallet_pips::{
-    self as pips, DepositInfo, Error, PipDescription, PipsMetadata, Referendum, ReferendumState,
-    ReferendumType, Url, VotingResult,
+    self as pips, CategoryConfig, Conviction, ConvictionLock, Delegation, DepositInfo, Error,
+    PipCategory, PipDescription, PipSnapshot, PipsMetadata, ProposalState, Referendum,
+    ReferendumState, ReferendumType, Url, VoteCount, VotingMode, VotingResult,
 };
 use pallet_treasury as treasury;
-use polymesh_primitives::Beneficiary;
+use polymesh_primitives::{AccountKey, Beneficiary, Signatory};
 use test_client::AccountKeyring;
 
 type System = frame_system::Module<TestStorage>;
@@ -31,6 +32,10 @@ fn make_proposal(value: u64) -> Call {
     Call::Pips(pips::Call::set_min_proposal_deposit(value.into()))
 }
 
+fn make_upgrade() -> Call {
+    Call::System(frame_system::Call::set_code(vec![]))
+}
+
 fn fast_forward_to(n: u64) {
     let block_number = System::block_number();
     (block_number..n).for_each(|block| {
@@ -63,7 +68,7 @@ fn starting_a_proposal_works_we() {
             40,
             Some(proposal_url.clone()),
             Some(proposal_desc.clone()),
-            None,
+            None
         ),
         Error::<TestStorage>::InsufficientDeposit
     );
@@ -75,7 +80,7 @@ fn starting_a_proposal_works_we() {
         60,
         Some(proposal_url),
         Some(proposal_desc),
-        None,
+        None
     ));
 
     assert_eq!(Balances::free_balance(&alice_acc), 158);
@@ -88,6 +93,8 @@ fn starting_a_proposal_works_we() {
             ayes_stake: 60,
             nays_count: 0,
             nays_stake: 0,
+            ayes_weight: 60,
+            nays_weight: 0,
         }
     );
 }
@@ -131,6 +138,8 @@ fn closing_a_proposal_works_we() {
             ayes_stake: 50,
             nays_count: 0,
             nays_stake: 0,
+            ayes_weight: 50,
+            nays_weight: 0,
         }
     );
 
@@ -180,6 +189,8 @@ fn creating_a_referendum_works_we() {
             ayes_stake: 100,
             nays_count: 0,
             nays_stake: 0,
+            ayes_weight: 100,
+            nays_weight: 0,
         }
     );
 
@@ -248,6 +259,8 @@ fn enacting_a_referendum_works_we() {
             ayes_stake: 100,
             nays_count: 0,
             nays_stake: 0,
+            ayes_weight: 100,
+            nays_weight: 0,
         }
     );
 
@@ -612,6 +625,8 @@ fn amend_pips_details_during_cool_off_period_we() {
             end: 111,
             url: Some(new_url),
             description: Some(new_desc),
+            category: PipCategory::Other,
+            quorum: 70,
         })
     );
 
@@ -709,6 +724,8 @@ fn cancel_pips_during_cool_off_period_we() {
             end: 111,
             url: None,
             description: None,
+            category: PipCategory::Other,
+            quorum: 70,
         })
     );
 }
@@ -877,7 +894,7 @@ fn proposal_with_beneficiares_we() {
         60,
         Some(proposal_url),
         Some(proposal_desc),
-        Some(beneficiaries),
+        Some(beneficiaries)
     ));
 
     // 2. Alice can fast track because she is a GC member
@@ -914,3 +931,386 @@ fn proposal_with_beneficiares_we() {
     assert_eq!(Balances::identity_balance(charlie_id), 200);
     assert_eq!(Balances::identity_balance(dave_id), 800);
 }
+
+#[test]
+fn conviction_and_quadratic_voting() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(conviction_and_quadratic_voting_we);
+}
+
+fn conviction_and_quadratic_voting_we() {
+    System::set_block_number(1);
+    let root = Origin::system(frame_system::RawOrigin::Root);
+
+    let alice_acc = AccountKeyring::Alice.public();
+    let (alice_signer, _) = make_account_with_balance(alice_acc, 300).unwrap();
+    let bob_acc = AccountKeyring::Bob.public();
+    let (bob_signer, bob_did) = make_account_with_balance(bob_acc, 200).unwrap();
+    let charlie_acc = AccountKeyring::Charlie.public();
+    let (charlie_signer, _) = make_account_with_balance(charlie_acc, 200).unwrap();
+
+    assert_ok!(Pips::set_default_voting_mode(
+        root.clone(),
+        VotingMode::Conviction
+    ));
+    assert_ok!(Pips::propose(
+        alice_signer.clone(),
+        Box::new(make_proposal(42)),
+        50,
+        None,
+        None,
+        None
+    ));
+    assert_eq!(Pips::proposal_voting_mode(0), VotingMode::Conviction);
+
+    fast_forward_to(101);
+    // Bob locks his stake for two periods after the end of the proposal to triple his vote.
+    assert_ok!(Pips::vote_with_conviction(
+        bob_signer.clone(),
+        0,
+        false,
+        40,
+        Conviction::Locked2x
+    ));
+    assert_ok!(Pips::vote_with_conviction(
+        charlie_signer.clone(),
+        0,
+        true,
+        30,
+        Conviction::None
+    ));
+    assert_eq!(
+        Pips::proposal_result(0),
+        VotingResult {
+            ayes_count: 2,
+            ayes_stake: 80,
+            nays_count: 1,
+            nays_stake: 40,
+            ayes_weight: 80,
+            nays_weight: 120,
+        }
+    );
+    assert_eq!(
+        Pips::get_votes(0),
+        VoteCount::ProposalFound {
+            ayes: 80,
+            nays: 40,
+            ayes_weight: 80,
+            nays_weight: 120,
+        }
+    );
+    assert_eq!(Pips::vote_conviction(0, &bob_acc), Conviction::Locked2x);
+    let lock = ConvictionLock {
+        pip: 0,
+        amount: 40,
+        until: 311,
+    };
+    assert_eq!(Pips::conviction_locks(&bob_acc), vec![lock.clone()]);
+    assert_eq!(Balances::locks(&bob_acc)[0].amount, 40);
+
+    // The quorum is met but the conviction of Bob outweighs the stake in favour.
+    fast_forward_to(112);
+    assert_eq!(Pips::proposals(0).unwrap().state, ProposalState::Rejected);
+
+    // The stake stays locked until the end of the conviction lock periods.
+    assert_ok!(Pips::unlock(bob_signer.clone()));
+    assert_eq!(Pips::conviction_locks(&bob_acc), vec![lock]);
+    System::set_block_number(311);
+    assert_ok!(Pips::unlock(bob_signer.clone()));
+    assert!(Pips::conviction_locks(&bob_acc).is_empty());
+    assert!(Balances::locks(&bob_acc).is_empty());
+
+    // Governance switches a proposal to quadratic voting during its cool-off period.
+    assert_ok!(Pips::propose(
+        alice_signer,
+        Box::new(make_proposal(43)),
+        64,
+        None,
+        None,
+        None
+    ));
+    assert_ok!(Pips::set_proposal_voting_mode(
+        root.clone(),
+        1,
+        VotingMode::Quadratic
+    ));
+    assert_eq!(Pips::proposal_result(1).ayes_weight, 8);
+
+    System::set_block_number(411);
+    assert_err!(
+        Pips::set_proposal_voting_mode(root, 1, VotingMode::Linear),
+        Error::<TestStorage>::ProposalIsImmutable
+    );
+    assert_err!(
+        Pips::vote_with_conviction(bob_signer.clone(), 1, false, 16, Conviction::Locked1x),
+        Error::<TestStorage>::ConvictionNotAllowed
+    );
+    assert_ok!(Pips::vote(bob_signer, 1, false, 16));
+    assert_eq!(Pips::proposal_result(1).nays_weight, 4);
+
+    // The stake of all the keys of an identity is weighted as a single quadratic vote.
+    let dave_acc = AccountKeyring::Dave.public();
+    Balances::make_free_balance_be(&dave_acc, 100);
+    add_signing_item(bob_did, Signatory::from(AccountKey::from(dave_acc.0)));
+    assert_ok!(Pips::vote(Origin::signed(dave_acc), 1, false, 20));
+    assert_eq!(Pips::identity_stake(1, (bob_did, false)), 36);
+    assert_eq!(Pips::proposal_result(1).nays_weight, 6);
+}
+
+#[test]
+fn delegated_voting() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(delegated_voting_we);
+}
+
+fn delegated_voting_we() {
+    System::set_block_number(1);
+
+    let alice_acc = AccountKeyring::Alice.public();
+    let (alice_signer, _) = make_account_with_balance(alice_acc, 300).unwrap();
+    let bob_acc = AccountKeyring::Bob.public();
+    let (bob_signer, bob_did) = make_account_with_balance(bob_acc, 200).unwrap();
+    let charlie_acc = AccountKeyring::Charlie.public();
+    let (charlie_signer, _) = make_account_with_balance(charlie_acc, 200).unwrap();
+    let dave_acc = AccountKeyring::Dave.public();
+    let (dave_signer, _) = make_account_with_balance(dave_acc, 200).unwrap();
+
+    // Charlie and Dave delegate their voting power to Bob.
+    assert_ok!(Pips::delegate(charlie_signer.clone(), bob_did, 30));
+    assert_ok!(Pips::delegate(dave_signer.clone(), bob_did, 60));
+    assert_err!(
+        Pips::delegate(dave_signer.clone(), bob_did, 10),
+        Error::<TestStorage>::AlreadyDelegating
+    );
+    assert_eq!(
+        Pips::delegation(&dave_acc),
+        Some(Delegation {
+            delegate: bob_did,
+            amount: 60
+        })
+    );
+    assert_eq!(
+        Pips::delegators_of(bob_did),
+        vec![(charlie_acc, 30), (dave_acc, 60)]
+    );
+    assert_eq!(Balances::locks(&dave_acc)[0].amount, 60);
+
+    assert_ok!(Pips::propose(
+        alice_signer.clone(),
+        Box::new(make_proposal(42)),
+        50,
+        None,
+        None,
+        None
+    ));
+    fast_forward_to(101);
+
+    // Bob's vote counts the delegated deposits.
+    assert_ok!(Pips::vote(bob_signer.clone(), 0, true, 40));
+    assert_eq!(
+        Pips::proposal_result(0),
+        VotingResult {
+            ayes_count: 2,
+            ayes_stake: 180,
+            nays_count: 0,
+            nays_stake: 0,
+            ayes_weight: 180,
+            nays_weight: 0,
+        }
+    );
+
+    // Charlie overrides the vote of Bob.
+    assert_ok!(Pips::vote(charlie_signer.clone(), 0, false, 20));
+    assert_eq!(Pips::proposal_result(0).ayes_stake, 150);
+    assert_eq!(Pips::proposal_result(0).nays_stake, 20);
+
+    // Dave withdraws his voting power.
+    assert_ok!(Pips::undelegate(dave_signer.clone()));
+    assert_eq!(Pips::proposal_result(0).ayes_stake, 90);
+    assert_eq!(Pips::delegation(&dave_acc), None);
+    assert_eq!(Pips::delegators_of(bob_did), vec![(charlie_acc, 30)]);
+    assert!(Balances::locks(&dave_acc).is_empty());
+    assert_err!(
+        Pips::undelegate(dave_signer),
+        Error::<TestStorage>::NotDelegating
+    );
+}
+
+#[test]
+fn categories_and_technical_review() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(categories_and_technical_review_we);
+}
+
+fn categories_and_technical_review_we() {
+    System::set_block_number(1);
+    let root = Origin::system(frame_system::RawOrigin::Root);
+
+    let alice_acc = AccountKeyring::Alice.public();
+    let (alice_signer, _) = make_account_with_balance(alice_acc, 300).unwrap();
+    let bob_acc = AccountKeyring::Bob.public();
+    let (bob_signer, _) = make_account_with_balance(bob_acc, 200).unwrap();
+
+    // Runtime upgrades need a larger quorum and a technical review.
+    let config = CategoryConfig {
+        quorum: 100,
+        technical_review: true,
+    };
+    assert_err!(
+        Pips::set_category_config(
+            bob_signer.clone(),
+            PipCategory::RuntimeUpgrade,
+            Some(config.clone())
+        ),
+        Error::<TestStorage>::BadOrigin
+    );
+    assert_ok!(Pips::set_category_config(
+        root.clone(),
+        PipCategory::RuntimeUpgrade,
+        Some(config.clone())
+    ));
+    assert_eq!(
+        Pips::category_config(PipCategory::RuntimeUpgrade),
+        Some(config)
+    );
+    assert_eq!(Pips::category_quorum(PipCategory::RuntimeUpgrade), 100);
+    assert_eq!(Pips::category_quorum(PipCategory::Other), 70);
+
+    // The category of a proposal is derived from its call.
+    let propose = |signer: &Origin, proposal: Call, deposit| {
+        Pips::propose(
+            signer.clone(),
+            Box::new(proposal),
+            deposit,
+            None,
+            None,
+            None,
+        )
+    };
+    assert_ok!(propose(&alice_signer, make_upgrade(), 80));
+    assert_ok!(propose(&alice_signer, make_upgrade(), 120));
+    assert_ok!(propose(&bob_signer, make_proposal(42), 80));
+    assert_eq!(
+        Pips::proposal_metadata(0).unwrap().category,
+        PipCategory::RuntimeUpgrade
+    );
+    assert_eq!(Pips::proposal_metadata(0).unwrap().quorum, 100);
+    assert_eq!(
+        Pips::proposal_metadata(2).unwrap().category,
+        PipCategory::ParameterChange
+    );
+    assert_eq!(Pips::proposal_metadata(2).unwrap().quorum, 70);
+
+    // The votes are snapshotted at the end of the community vote.
+    fast_forward_to(112);
+    assert_eq!(
+        Pips::proposal_snapshot(0),
+        Some(PipSnapshot {
+            taken_at: 111,
+            result: VotingResult {
+                ayes_count: 1,
+                ayes_stake: 80,
+                nays_count: 0,
+                nays_stake: 0,
+                ayes_weight: 80,
+                nays_weight: 0,
+            },
+            passed: false,
+        })
+    );
+    assert_eq!(Pips::proposals(0).unwrap().state, ProposalState::Rejected);
+    assert_eq!(
+        Pips::proposals(1).unwrap().state,
+        ProposalState::TechnicalReview
+    );
+    assert!(Pips::proposal_snapshot(1).unwrap().passed);
+    assert_eq!(Pips::proposals(2).unwrap().state, ProposalState::Referendum);
+
+    // The technical committee approves the reviewed proposal.
+    assert_err!(
+        Pips::approve_technical_review(alice_signer, 1),
+        Error::<TestStorage>::BadOrigin
+    );
+    assert_err!(
+        Pips::approve_technical_review(root.clone(), 2),
+        Error::<TestStorage>::IncorrectProposalState
+    );
+    assert_ok!(Pips::approve_technical_review(root.clone(), 1));
+    assert_eq!(Pips::proposals(1).unwrap().state, ProposalState::Referendum);
+    assert_eq!(
+        Pips::referendums(1).unwrap().referendum_type,
+        ReferendumType::Community
+    );
+    assert_err!(
+        Pips::reject_technical_review(root, 1),
+        Error::<TestStorage>::IncorrectProposalState
+    );
+}
+
+#[test]
+fn technical_review_expires() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(technical_review_expires_we);
+}
+
+fn technical_review_expires_we() {
+    System::set_block_number(1);
+    let root = Origin::system(frame_system::RawOrigin::Root);
+
+    let alice_acc = AccountKeyring::Alice.public();
+    let (alice_signer, _) = make_account_with_balance(alice_acc, 300).unwrap();
+
+    assert_ok!(Pips::set_category_config(
+        root.clone(),
+        PipCategory::RuntimeUpgrade,
+        Some(CategoryConfig {
+            quorum: 100,
+            technical_review: true,
+        })
+    ));
+    assert_err!(
+        Pips::set_technical_review_period(alice_signer.clone(), 10),
+        Error::<TestStorage>::BadOrigin
+    );
+    assert_ok!(Pips::set_technical_review_period(root.clone(), 10));
+    assert_eq!(Pips::technical_review_period(), 10);
+
+    assert_ok!(Pips::propose(
+        alice_signer,
+        Box::new(make_upgrade()),
+        120,
+        None,
+        None,
+        None
+    ));
+
+    // The review starts at the end of the community vote and lasts 10 blocks.
+    fast_forward_to(112);
+    assert_eq!(
+        Pips::proposals(0).unwrap().state,
+        ProposalState::TechnicalReview
+    );
+    assert_eq!(Pips::technical_reviews_ending_at(121), vec![0]);
+    fast_forward_to(121);
+    assert_eq!(
+        Pips::proposals(0).unwrap().state,
+        ProposalState::TechnicalReview
+    );
+
+    // The proposal is rejected once the review period is over.
+    fast_forward_to(122);
+    assert_eq!(Pips::proposals(0).unwrap().state, ProposalState::Rejected);
+    assert!(Pips::technical_reviews_ending_at(121).is_empty());
+    assert_err!(
+        Pips::approve_technical_review(root, 0),
+        Error::<TestStorage>::IncorrectProposalState
+    );
+}
@@ -1,17 +1,26 @@
 use super::{
     ext_builder::PROTOCOL_OP_BASE_FEE,
-    storage::{register_keyring_account_with_balance, TestStorage},
+    storage::{register_keyring_account, register_keyring_account_with_balance, TestStorage},
     ExtBuilder,
 };
 use codec::Encode;
+use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok};
-use polymesh_common_utilities::protocol_fee::ProtocolOp;
-use polymesh_primitives::{AccountKey, Signatory};
+use pallet_protocol_fee::FeeSplit;
+use polymesh_common_utilities::{constants::currency::ONE_UNIT, protocol_fee::ProtocolOp, Context};
+use polymesh_primitives::{AccountKey, Claim, ClaimRequirement, ClaimType, Signatory};
+use sp_runtime::Perbill;
 use std::convert::TryFrom;
 use test_client::AccountKeyring;
 
 type Error = pallet_protocol_fee::Error<TestStorage>;
 type ProtocolFee = pallet_protocol_fee::Module<TestStorage>;
+type PriceFeed = pallet_price_feed::Module<TestStorage>;
+type PriceFeedError = pallet_price_feed::Error<TestStorage>;
+type Timestamp = pallet_timestamp::Module<TestStorage>;
+type Identity = pallet_identity::Module<TestStorage>;
+type Balances = pallet_balances::Module<TestStorage>;
+type Origin = <TestStorage as frame_system::Trait>::Origin;
 
 #[test]
 fn can_compute_fee() {
@@ -42,3 +51,212 @@ fn can_charge_fee_batch() {
         );
     });
 }
+
+#[test]
+fn can_compute_fee_in_reference_unit() {
+    ExtBuilder::default().build().execute_with(|| {
+        let root = Origin::system(frame_system::RawOrigin::Root);
+        let now = 1_000_000;
+        let max_age = PriceFeed::max_price_age();
+        Timestamp::set_timestamp(now);
+
+        // Without a price the base fee is charged even if a reference fee is set.
+        assert_ok!(ProtocolFee::change_reference_fee(
+            root.clone(),
+            ProtocolOp::AssetIssue,
+            2 * ONE_UNIT
+        ));
+        assert_eq!(
+            ProtocolFee::compute_fee_details(ProtocolOp::AssetIssue),
+            (PROTOCOL_OP_BASE_FEE, None, false)
+        );
+
+        // Only the feeder origin submits valid prices.
+        assert_err!(
+            PriceFeed::submit_price(
+                Origin::signed(AccountKeyring::Alice.public()),
+                ONE_UNIT,
+                now
+            ),
+            PriceFeedError::NotAuthorised
+        );
+        assert_err!(
+            PriceFeed::submit_price(root.clone(), 0, now),
+            PriceFeedError::ZeroPrice
+        );
+        assert_err!(
+            PriceFeed::submit_price(root.clone(), ONE_UNIT, now + 1),
+            PriceFeedError::FuturePrice
+        );
+        assert_ok!(PriceFeed::submit_price(root.clone(), ONE_UNIT / 2, now));
+        assert_err!(
+            PriceFeed::submit_price(root.clone(), ONE_UNIT, now),
+            PriceFeedError::OutdatedPrice
+        );
+
+        // One POLYX is worth half a reference unit.
+        assert_eq!(
+            ProtocolFee::compute_fee_details(ProtocolOp::AssetIssue),
+            (4 * ONE_UNIT, Some(2 * ONE_UNIT), false)
+        );
+        assert_eq!(
+            ProtocolFee::compute_fee_details(ProtocolOp::AssetRegisterTicker),
+            (PROTOCOL_OP_BASE_FEE, Some(PROTOCOL_OP_BASE_FEE / 2), false)
+        );
+
+        // A stale price is rejected but the last known rate is still used.
+        Timestamp::set_timestamp(now + max_age + 1);
+        assert_err!(
+            PriceFeed::submit_price(root.clone(), ONE_UNIT, now + 1),
+            PriceFeedError::StalePrice
+        );
+        assert_eq!(
+            ProtocolFee::compute_fee_details(ProtocolOp::AssetIssue),
+            (4 * ONE_UNIT, Some(2 * ONE_UNIT), true)
+        );
+        assert_eq!(
+            ProtocolFee::compute_fee(ProtocolOp::AssetIssue),
+            4 * ONE_UNIT
+        );
+
+        // A fresh price updates the fee.
+        assert_ok!(PriceFeed::submit_price(root, ONE_UNIT, now + max_age + 1));
+        assert_eq!(
+            ProtocolFee::compute_fee_details(ProtocolOp::AssetIssue),
+            (2 * ONE_UNIT, Some(2 * ONE_UNIT), false)
+        );
+    });
+}
+
+#[test]
+fn can_discount_and_account_fees() {
+    ExtBuilder::default().build().execute_with(|| {
+        let root = Origin::system(frame_system::RawOrigin::Root);
+        let alice_did =
+            register_keyring_account_with_balance(AccountKeyring::Alice, PROTOCOL_OP_BASE_FEE * 10)
+                .unwrap();
+        let alice_signer =
+            Signatory::from(AccountKey::try_from(AccountKeyring::Alice.public().encode()).unwrap());
+        let bob_did = register_keyring_account(AccountKeyring::Bob).unwrap();
+        let bob = Origin::signed(AccountKeyring::Bob.public());
+        let balance = Balances::free_balance(AccountKeyring::Alice.public());
+        Context::set_current_identity::<Identity>(Some(alice_did));
+
+        assert_err!(
+            ProtocolFee::set_volume_discounts(
+                root.clone(),
+                vec![
+                    (100, Perbill::from_percent(50)),
+                    (50, Perbill::from_percent(10))
+                ]
+            ),
+            Error::InvalidDiscountTiers
+        );
+        assert_ok!(ProtocolFee::set_volume_discounts(
+            root.clone(),
+            vec![
+                (50, Perbill::from_percent(10)),
+                (100, Perbill::from_percent(50))
+            ]
+        ));
+
+        // The first tier is reached after the second full fee.
+        assert_ok!(ProtocolFee::charge_fee(
+            &alice_signer,
+            ProtocolOp::AssetIssue
+        ));
+        assert_ok!(ProtocolFee::charge_fee(
+            &alice_signer,
+            ProtocolOp::AssetIssue
+        ));
+        assert_eq!(
+            ProtocolFee::discount_of(alice_did),
+            Perbill::from_percent(10)
+        );
+        assert_ok!(ProtocolFee::charge_fee(
+            &alice_signer,
+            ProtocolOp::AssetIssue
+        ));
+        assert_eq!(
+            ProtocolFee::discount_of(alice_did),
+            Perbill::from_percent(50)
+        );
+        assert_eq!(
+            ProtocolFee::fees_paid(alice_did, ProtocolOp::AssetIssue),
+            PROTOCOL_OP_BASE_FEE * 2 + 37
+        );
+
+        // A claim discount applies when it is better than the volume tier.
+        assert_ok!(ProtocolFee::set_claim_discounts(
+            root.clone(),
+            vec![(
+                ClaimRequirement {
+                    claim_type: ClaimType::Accredited,
+                    issuer: bob_did,
+                    scope: Some(bob_did),
+                },
+                Perbill::from_percent(75),
+            )]
+        ));
+        assert_eq!(
+            ProtocolFee::discount_of(alice_did),
+            Perbill::from_percent(50)
+        );
+        let accredited = Claim::Accredited(bob_did);
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_ok!(Identity::add_claim(
+            bob.clone(),
+            alice_did,
+            accredited.clone(),
+            None
+        ));
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_eq!(
+            ProtocolFee::discount_of(alice_did),
+            Perbill::from_percent(75)
+        );
+        assert_ok!(ProtocolFee::charge_fee_batch(
+            &alice_signer,
+            ProtocolOp::AssetAddDocuments,
+            4
+        ));
+        assert_eq!(
+            ProtocolFee::fees_paid(alice_did, ProtocolOp::AssetAddDocuments),
+            PROTOCOL_OP_BASE_FEE
+        );
+        assert_eq!(
+            ProtocolFee::total_fees_paid(alice_did),
+            PROTOCOL_OP_BASE_FEE * 3 + 37
+        );
+        assert_eq!(
+            Balances::free_balance(AccountKeyring::Alice.public()),
+            balance - PROTOCOL_OP_BASE_FEE * 3 - 37
+        );
+
+        // Revoking the claim falls back to the volume tier.
+        Context::set_current_identity::<Identity>(Some(bob_did));
+        assert_ok!(Identity::revoke_claim(bob, alice_did, accredited));
+        Context::set_current_identity::<Identity>(Some(alice_did));
+        assert_eq!(
+            ProtocolFee::discount_of(alice_did),
+            Perbill::from_percent(50)
+        );
+
+        assert_err!(
+            ProtocolFee::change_fee_split(
+                root.clone(),
+                FeeSplit {
+                    treasury: Perbill::from_percent(60),
+                    validators: Perbill::from_percent(50),
+                }
+            ),
+            Error::InvalidFeeSplit
+        );
+        let split = FeeSplit {
+            treasury: Perbill::from_percent(60),
+            validators: Perbill::from_percent(30),
+        };
+        assert_ok!(ProtocolFee::change_fee_split(root, split));
+        assert_eq!(ProtocolFee::fee_split(), split);
+    });
+}
@@ -0,0 +1,75 @@
+use super::{
+    storage::{register_keyring_account, TestStorage},
+    ExtBuilder,
+};
+
+use pallet_asset::{self as asset, AssetType};
+use pallet_balances as balances;
+use polymesh_common_utilities::protocol_fee::ProtocolOp;
+use polymesh_primitives::Ticker;
+use polymesh_runtime_common::sto_capped::{self, Error};
+
+use frame_support::{assert_err, assert_ok};
+use std::convert::TryFrom;
+use test_client::AccountKeyring;
+
+type Asset = asset::Module<TestStorage>;
+type Balances = balances::Module<TestStorage>;
+type ProtocolFee = pallet_protocol_fee::Module<TestStorage>;
+type StoCapped = sto_capped::Module<TestStorage>;
+type Origin = <TestStorage as frame_system::Trait>::Origin;
+
+#[test]
+fn launch_sto_charges_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_did = register_keyring_account(AccountKeyring::Alice).unwrap();
+        let alice = Origin::signed(AccountKeyring::Alice.public());
+        let _ = register_keyring_account(AccountKeyring::Bob).unwrap();
+        let bob = Origin::signed(AccountKeyring::Bob.public());
+        let ticker = Ticker::try_from(&b"STO"[..]).unwrap();
+        assert_ok!(Asset::create_asset(
+            alice.clone(),
+            b"STO".to_vec().into(),
+            ticker,
+            1_000_000,
+            true,
+            AssetType::default(),
+            vec![],
+            None
+        ));
+        let fee = 100;
+        assert_ok!(ProtocolFee::change_base_fee(
+            Origin::system(frame_system::RawOrigin::Root),
+            ProtocolOp::StoCappedLaunchSto,
+            fee
+        ));
+        let launch = |origin| {
+            StoCapped::launch_sto(
+                origin,
+                ticker,
+                alice_did,
+                1_000,
+                1,
+                0,
+                10,
+                Ticker::default(),
+            )
+        };
+
+        // Only the owner of the asset can launch an STO and nobody else is charged.
+        let bob_balance = Balances::free_balance(&AccountKeyring::Bob.public());
+        assert_err!(launch(bob), Error::<TestStorage>::NotAnOwner);
+        assert_eq!(
+            Balances::free_balance(&AccountKeyring::Bob.public()),
+            bob_balance
+        );
+
+        let balance = Balances::free_balance(&AccountKeyring::Alice.public());
+        assert_ok!(launch(alice));
+        assert_eq!(StoCapped::sto_count(ticker), 1);
+        assert_eq!(
+            Balances::free_balance(&AccountKeyring::Alice.public()),
+            balance - fee
+        );
+    });
+}
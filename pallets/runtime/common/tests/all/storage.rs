@@ -6,23 +6,30 @@ use pallet_group as group;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
 use pallet_pips as pips;
+use pallet_price_feed as price_feed;
 use pallet_protocol_fee as protocol_fee;
 use pallet_statistics as statistics;
 use pallet_treasury as treasury;
 use pallet_utility as utility;
 
-use polymesh_common_utilities::traits::{
-    asset::AcceptTransfer,
-    balances::AccountData,
-    group::GroupTrait,
-    identity::Trait as IdentityTrait,
-    multisig::AddSignerMultiSig,
-    pip::{EnactProposalMaker, PipId},
-    CommonTrait,
+use polymesh_common_utilities::{
+    traits::{
+        asset::AcceptTransfer,
+        balances::AccountData,
+        group::GroupTrait,
+        identity::Trait as IdentityTrait,
+        multisig::AddSignerMultiSig,
+        pip::{EnactProposalMaker, PipId},
+        CommonTrait,
+    },
+    SystematicIssuers,
+};
+use polymesh_primitives::{
+    AccountKey, Authorization, AuthorizationData, Balance, CddId, IdentityId, InvestorUid,
+    Signatory,
 };
-use polymesh_primitives::{AccountKey, Authorization, AuthorizationData, IdentityId, Signatory};
 use polymesh_runtime_common::{
-    bridge, cdd_check::CddChecker, dividend, exemption, simple_token, voting,
+    bridge, cdd_check::CddChecker, dividend, exemption, simple_token, sto_capped, voting,
 };
 
 use codec::Encode;
@@ -65,6 +72,7 @@ impl_outer_origin! {
 
 impl_outer_dispatch! {
     pub enum Call for TestStorage where origin: Origin {
+        frame_system::System,
         identity::Identity,
         balances::Balances,
         pips::Pips,
@@ -95,8 +103,10 @@ impl_outer_event! {
         voting<T>,
         dividend<T>,
         simple_token<T>,
+        sto_capped<T>,
         frame_system<T>,
         protocol_fee<T>,
+        price_feed<T>,
         treasury<T>,
         utility,
     }
@@ -195,7 +205,11 @@ impl pallet_transaction_payment::ChargeTxFee for TestStorage {
 }
 
 impl pallet_transaction_payment::CddAndFeeDetails<Call> for TestStorage {
-    fn get_valid_payer(_: &Call, _: &Signatory) -> Result<Option<Signatory>, InvalidTransaction> {
+    fn get_valid_payer(
+        _: &Call,
+        _: &Signatory,
+        _: Balance,
+    ) -> Result<Option<Signatory>, InvalidTransaction> {
         Ok(None)
     }
     fn clear_context() {}
@@ -214,6 +228,12 @@ parameter_types! {
     pub const Five: AccountId = AccountId::from(AccountKeyring::Dave);
 }
 
+parameter_types! {
+    pub const BallotDeposit: u128 = 10;
+    pub const MaxElectionVoters: u32 = 3;
+    pub const MaxElectionCandidates: u32 = 3;
+}
+
 impl group::Trait<group::DefaultInstance> for TestStorage {
     type Event = Event;
     type AddOrigin = frame_system::EnsureRoot<AccountId>;
@@ -222,6 +242,10 @@ impl group::Trait<group::DefaultInstance> for TestStorage {
     type ResetOrigin = frame_system::EnsureRoot<AccountId>;
     type MembershipInitialized = committee::Module<TestStorage, committee::Instance1>;
     type MembershipChanged = committee::Module<TestStorage, committee::Instance1>;
+    type Currency = Balances;
+    type BallotDeposit = BallotDeposit;
+    type MaxVoters = MaxElectionVoters;
+    type MaxCandidates = MaxElectionCandidates;
 }
 
 /// PolymeshCommittee as an instance of group
@@ -233,6 +257,10 @@ impl group::Trait<group::Instance1> for TestStorage {
     type ResetOrigin = frame_system::EnsureRoot<AccountId>;
     type MembershipInitialized = committee::Module<TestStorage, committee::Instance1>;
     type MembershipChanged = committee::Module<TestStorage, committee::Instance1>;
+    type Currency = Balances;
+    type BallotDeposit = BallotDeposit;
+    type MaxVoters = MaxElectionVoters;
+    type MaxCandidates = MaxElectionCandidates;
 }
 
 impl group::Trait<group::Instance2> for TestStorage {
@@ -243,6 +271,10 @@ impl group::Trait<group::Instance2> for TestStorage {
     type ResetOrigin = frame_system::EnsureRoot<AccountId>;
     type MembershipInitialized = identity::Module<TestStorage>;
     type MembershipChanged = identity::Module<TestStorage>;
+    type Currency = Balances;
+    type BallotDeposit = BallotDeposit;
+    type MaxVoters = MaxElectionVoters;
+    type MaxCandidates = MaxElectionCandidates;
 }
 
 pub type CommitteeOrigin<T, I> = committee::RawOrigin<<T as system::Trait>::AccountId, I>;
@@ -256,6 +288,8 @@ impl<I> From<CommitteeOrigin<TestStorage, I>> for Origin {
 parameter_types! {
     pub const CommitteeRoot: AccountId = AccountId::from(AccountKeyring::Alice);
     pub const MotionDuration: BlockNumber = 0u64;
+    pub const GovernanceCommitteeIssuer: SystematicIssuers = SystematicIssuers::Committee;
+    pub const TechnicalCommitteeIssuer: SystematicIssuers = SystematicIssuers::TechnicalCommittee;
 }
 
 impl committee::Trait<committee::Instance1> for TestStorage {
@@ -265,6 +299,7 @@ impl committee::Trait<committee::Instance1> for TestStorage {
     type Event = Event;
     type MotionDuration = MotionDuration;
     type EnactProposalMaker = TestStorage;
+    type SystematicIssuer = GovernanceCommitteeIssuer;
 }
 
 impl committee::Trait<committee::DefaultInstance> for TestStorage {
@@ -274,6 +309,7 @@ impl committee::Trait<committee::DefaultInstance> for TestStorage {
     type Event = Event;
     type MotionDuration = MotionDuration;
     type EnactProposalMaker = TestStorage;
+    type SystematicIssuer = TechnicalCommitteeIssuer;
 }
 
 impl IdentityTrait for TestStorage {
@@ -287,6 +323,7 @@ impl IdentityTrait for TestStorage {
     type Public = AccountId;
     type OffChainSignature = OffChainSignature;
     type ProtocolFee = protocol_fee::Module<TestStorage>;
+    type Holdings = (balances::Module<TestStorage>, asset::Module<TestStorage>);
 }
 
 impl AddSignerMultiSig for TestStorage {
@@ -361,6 +398,13 @@ impl protocol_fee::Trait for TestStorage {
     type Event = Event;
     type Currency = Balances;
     type OnProtocolFeePayment = ();
+    type OnValidatorsFeeShare = ();
+    type PriceFeed = price_feed::Module<TestStorage>;
+}
+
+impl price_feed::Trait for TestStorage {
+    type Event = Event;
+    type FeederOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 impl asset::Trait for TestStorage {
@@ -385,14 +429,29 @@ impl exemption::Trait for TestStorage {
     type Asset = asset::Module<TestStorage>;
 }
 
+impl sto_capped::Trait for TestStorage {
+    type Event = Event;
+    type SimpleTokenTrait = simple_token::Module<TestStorage>;
+}
+
 impl voting::Trait for TestStorage {
     type Event = Event;
     type Asset = asset::Module<TestStorage>;
 }
 
+parameter_types! {
+    pub const GrantDeposit: u128 = 100;
+    pub const MaxGrantDescriptionLength: u32 = 32;
+    pub const MaxMilestones: u32 = 4;
+}
+
 impl treasury::Trait for TestStorage {
     type Event = Event;
     type Currency = Balances;
+    type CommitteeOrigin = frame_system::EnsureRoot<AccountId>;
+    type GrantDeposit = GrantDeposit;
+    type MaxGrantDescriptionLength = MaxGrantDescriptionLength;
+    type MaxMilestones = MaxMilestones;
 }
 
 thread_local! {
@@ -462,11 +521,23 @@ impl pips::Trait for TestStorage {
     type Currency = balances::Module<Self>;
     type CommitteeOrigin = frame_system::EnsureRoot<AccountId>;
     type VotingMajorityOrigin = frame_system::EnsureRoot<AccountId>;
+    type TechnicalCommitteeOrigin = frame_system::EnsureRoot<AccountId>;
     type GovernanceCommittee = Committee;
     type Treasury = treasury::Module<Self>;
+    type Categorizer = TestStorage;
     type Event = Event;
 }
 
+impl pips::PipCategorizer<Call> for TestStorage {
+    fn category(proposal: &Call) -> pips::PipCategory {
+        match proposal {
+            Call::System(frame_system::Call::set_code(..)) => pips::PipCategory::RuntimeUpgrade,
+            Call::Pips(..) => pips::PipCategory::ParameterChange,
+            _ => pips::PipCategory::Other,
+        }
+    }
+}
+
 impl utility::Trait for TestStorage {
     type Event = Event;
     type Call = Call;
@@ -487,6 +558,7 @@ impl EnactProposalMaker<Origin, Call> for TestStorage {
 }
 
 // Publish type alias for each module
+type System = frame_system::Module<TestStorage>;
 pub type Identity = identity::Module<TestStorage>;
 pub type Pips = pips::Module<TestStorage>;
 pub type Balances = balances::Module<TestStorage>;
@@ -518,7 +590,13 @@ pub fn make_account_with_balance(
     let cdd_providers = CddServiceProvider::get_members();
     let did_registration = if let Some(cdd_provider) = cdd_providers.into_iter().nth(0) {
         let cdd_acc = Public::from_raw(Identity::did_records(&cdd_provider).master_key.0);
-        Identity::cdd_register_did(Origin::signed(cdd_acc), id, Some(10), vec![])
+        Identity::cdd_register_did(
+            Origin::signed(cdd_acc),
+            id,
+            make_cdd_id(id),
+            Some(10),
+            vec![],
+        )
     } else {
         Identity::register_did(signed_id.clone(), vec![])
     };
@@ -528,6 +606,11 @@ pub fn make_account_with_balance(
     Ok((signed_id, did))
 }
 
+/// It builds the CDD commitment of a test investor, using `seed` as its unique ID.
+pub fn make_cdd_id<S: AsRef<[u8]>>(seed: S) -> CddId {
+    CddId::new(InvestorUid::from(seed))
+}
+
 pub fn make_account_without_cdd(
     id: AccountId,
 ) -> Result<(<TestStorage as frame_system::Trait>::Origin, IdentityId), &'static str> {
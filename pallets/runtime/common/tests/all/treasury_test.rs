@@ -6,7 +6,9 @@ use super::{
 use frame_support::{assert_err, assert_ok};
 use pallet_balances as balances;
 use pallet_identity as identity;
-use pallet_treasury::{self as treasury, TreasuryTrait};
+use pallet_treasury::{
+    self as treasury, Error, GrantStatus, Milestone, RecurringPayment, SpendReport, TreasuryTrait,
+};
 use polymesh_common_utilities::Context;
 use polymesh_primitives::{Beneficiary, IdentityId};
 use sp_runtime::DispatchError;
@@ -15,6 +17,7 @@ use test_client::AccountKeyring;
 pub type Balances = balances::Module<TestStorage>;
 pub type Treasury = treasury::Module<TestStorage>;
 type Identity = identity::Module<TestStorage>;
+type System = frame_system::Module<TestStorage>;
 type Origin = <TestStorage as frame_system::Trait>::Origin;
 
 #[test]
@@ -73,3 +76,274 @@ fn reimbursement_and_disbursement_we() {
     );
     assert_eq!(total_issuance, Balances::total_issuance());
 }
+
+#[test]
+fn grants_with_milestones() {
+    ExtBuilder::default()
+        .existential_deposit(10)
+        .build()
+        .execute_with(grants_with_milestones_we);
+}
+
+fn grants_with_milestones_we() {
+    let root = Origin::system(frame_system::RawOrigin::Root);
+    let alice = register_keyring_account(AccountKeyring::Alice).unwrap();
+    let alice_acc = Origin::signed(AccountKeyring::Alice.public());
+    let bob = register_keyring_account(AccountKeyring::Bob).unwrap();
+    let bob_acc = Origin::signed(AccountKeyring::Bob.public());
+    let _ = register_keyring_account(AccountKeyring::Charlie).unwrap();
+    let charlie_acc = Origin::signed(AccountKeyring::Charlie.public());
+    assert_ok!(Treasury::reimbursement(alice_acc.clone(), 1_000));
+
+    // Bob proposes a grant with two milestones.
+    let milestones = vec![
+        Milestone {
+            description: b"design".to_vec(),
+            amount: 300,
+        },
+        Milestone {
+            description: b"implementation".to_vec(),
+            amount: 200,
+        },
+    ];
+    assert_err!(
+        Treasury::propose_grant(bob_acc.clone(), b"grant".to_vec(), vec![]),
+        Error::<TestStorage>::NoMilestones
+    );
+    assert_err!(
+        Treasury::propose_grant(
+            bob_acc.clone(),
+            b"grant".to_vec(),
+            vec![milestones[0].clone(); 5]
+        ),
+        Error::<TestStorage>::TooManyMilestones
+    );
+    assert_err!(
+        Treasury::propose_grant(bob_acc.clone(), vec![b'x'; 33], milestones.clone()),
+        Error::<TestStorage>::DescriptionTooLong
+    );
+    assert_ok!(Treasury::propose_grant(
+        bob_acc.clone(),
+        b"grant".to_vec(),
+        milestones
+    ));
+    // The deposit is reserved until the grant is closed.
+    let bob_pub = AccountKeyring::Bob.public();
+    assert_eq!(Balances::reserved_balance(bob_pub), 100);
+    assert_eq!(Treasury::grant_deposits(0), Some((bob_pub, 100)));
+    let grant = Treasury::grants(0).unwrap();
+    assert_eq!(grant.beneficiary, bob);
+    assert_eq!(grant.status, GrantStatus::Proposed);
+    assert_eq!(grant.total(), 500);
+
+    // The committee approves it with Alice as reviewer, which commits its funds.
+    assert_err!(
+        Treasury::approve_grant(bob_acc.clone(), 0, Some(alice)),
+        DispatchError::BadOrigin
+    );
+    assert_ok!(Treasury::approve_grant(root.clone(), 0, Some(alice)));
+    assert_eq!(Treasury::committed_funds(), 500);
+    assert_eq!(Treasury::available_balance(), 500);
+    assert_err!(
+        Treasury::disbursement(
+            root.clone(),
+            vec![Beneficiary {
+                id: alice,
+                amount: 600
+            }]
+        ),
+        Error::<TestStorage>::InsufficientBalance
+    );
+
+    // Only the committee or the reviewer can release milestones.
+    assert_err!(
+        Treasury::release_milestone(charlie_acc, 0),
+        Error::<TestStorage>::NotAReviewer
+    );
+    assert_ok!(Treasury::release_milestone(alice_acc, 0));
+    assert_eq!(Balances::identity_balance(bob), 300);
+    assert_eq!(Treasury::committed_funds(), 200);
+    assert_eq!(Treasury::grants(0).unwrap().released, 1);
+
+    // The committee fails the grant and claws back its unreleased funds.
+    assert_ok!(Treasury::fail_grant(root.clone(), 0));
+    assert_eq!(Treasury::grants(0).unwrap().status, GrantStatus::Failed);
+    assert_eq!(Balances::reserved_balance(bob_pub), 0);
+    assert_eq!(Treasury::grant_deposits(0), None);
+    assert_eq!(Treasury::committed_funds(), 0);
+    assert_eq!(Treasury::available_balance(), 700);
+    assert_err!(
+        Treasury::release_milestone(root.clone(), 0),
+        Error::<TestStorage>::IncorrectGrantStatus
+    );
+
+    // A rejected grant commits no funds.
+    assert_ok!(Treasury::propose_grant(
+        bob_acc,
+        b"another grant".to_vec(),
+        vec![Milestone {
+            description: b"delivery".to_vec(),
+            amount: 100,
+        }]
+    ));
+    assert_eq!(Balances::reserved_balance(bob_pub), 100);
+    assert_ok!(Treasury::reject_grant(root, 1));
+    assert_eq!(Treasury::grants(1).unwrap().status, GrantStatus::Rejected);
+    assert_eq!(Treasury::committed_funds(), 0);
+    assert_eq!(Balances::reserved_balance(bob_pub), 0);
+}
+
+#[test]
+fn budget_periods_and_recurring_payments() {
+    ExtBuilder::default()
+        .existential_deposit(10)
+        .build()
+        .execute_with(budget_periods_and_recurring_payments_we);
+}
+
+fn budget_periods_and_recurring_payments_we() {
+    let root = Origin::system(frame_system::RawOrigin::Root);
+    let alice = register_keyring_account(AccountKeyring::Alice).unwrap();
+    let alice_acc = Origin::signed(AccountKeyring::Alice.public());
+    let bob = register_keyring_account(AccountKeyring::Bob).unwrap();
+    assert_ok!(Treasury::reimbursement(alice_acc, 1_000));
+
+    // Budget periods of 10 blocks capped to 300.
+    assert_ok!(Treasury::set_budget(root.clone(), 10, Some(300)));
+    System::set_block_number(1);
+    let to_bob = |amount| vec![Beneficiary { id: bob, amount }];
+    assert_err!(
+        Treasury::disbursement(root.clone(), to_bob(400)),
+        Error::<TestStorage>::BudgetExceeded
+    );
+    assert_ok!(Treasury::disbursement(root.clone(), to_bob(100)));
+    assert_eq!(Treasury::spendable_balance(), 200);
+
+    // Nothing is paid to an unknown identity.
+    let unknown = IdentityId::from(999);
+    assert_err!(
+        Treasury::disbursement(
+            root.clone(),
+            vec![
+                Beneficiary {
+                    id: bob,
+                    amount: 50
+                },
+                Beneficiary {
+                    id: unknown,
+                    amount: 50
+                }
+            ]
+        ),
+        Error::<TestStorage>::DidDoesNotExist
+    );
+    assert_eq!(Treasury::spendable_balance(), 200);
+    assert_eq!(Balances::identity_balance(bob), 100);
+
+    // Alice is paid 150 every 5 blocks from block 5 until block 15.
+    assert_err!(
+        Treasury::schedule_recurring_payment(root.clone(), alice, 150, 5, 0, 15),
+        Error::<TestStorage>::InvalidSchedule
+    );
+    assert_err!(
+        Treasury::schedule_recurring_payment(root.clone(), unknown, 150, 5, 5, 15),
+        Error::<TestStorage>::DidDoesNotExist
+    );
+    assert_ok!(Treasury::schedule_recurring_payment(
+        root.clone(),
+        alice,
+        150,
+        5,
+        5,
+        15
+    ));
+    for n in &[5, 10, 15] {
+        System::set_block_number(*n);
+        Treasury::make_recurring_payments(*n);
+    }
+    assert_eq!(Balances::identity_balance(alice), 450);
+    assert_eq!(Treasury::recurring_payments(0), None);
+
+    // A payment over the budget is skipped.
+    assert_ok!(Treasury::schedule_recurring_payment(
+        root.clone(),
+        alice,
+        400,
+        20,
+        10,
+        30
+    ));
+    System::set_block_number(20);
+    Treasury::make_recurring_payments(20);
+    assert_eq!(Balances::identity_balance(alice), 450);
+    assert_eq!(
+        Treasury::recurring_payments(1),
+        Some(RecurringPayment {
+            beneficiary: alice,
+            amount: 400,
+            start: 20,
+            interval: 10,
+            end: 30,
+            next: 30,
+        })
+    );
+    assert_ok!(Treasury::cancel_recurring_payment(root.clone(), 1));
+    assert_err!(
+        Treasury::cancel_recurring_payment(root.clone(), 1),
+        Error::<TestStorage>::NoSuchRecurringPayment
+    );
+
+    // The spending is reported per period and beneficiary.
+    assert_eq!(
+        Treasury::spend_report(0, 2),
+        vec![
+            SpendReport {
+                period: 0,
+                total: 250,
+                beneficiaries: vec![(bob, 100), (alice, 150)],
+            },
+            SpendReport {
+                period: 1,
+                total: 300,
+                beneficiaries: vec![(alice, 300)],
+            },
+        ]
+    );
+
+    // Changing the period mid-way restarts the current period without re-numbering past ones.
+    System::set_block_number(25);
+    assert_ok!(Treasury::disbursement(root.clone(), to_bob(100)));
+    assert_ok!(Treasury::set_budget(root.clone(), 5, Some(300)));
+    assert_eq!(Treasury::budget_period_start(), (25, 2));
+    System::set_block_number(29);
+    assert_eq!(Treasury::current_budget_period(), 2);
+    assert_eq!(Treasury::spendable_balance(), 200);
+    System::set_block_number(30);
+    assert_eq!(Treasury::current_budget_period(), 3);
+    assert_ok!(Treasury::disbursement(root, to_bob(300)));
+    assert_eq!(
+        Treasury::spend_report(0, 3),
+        vec![
+            SpendReport {
+                period: 0,
+                total: 250,
+                beneficiaries: vec![(bob, 100), (alice, 150)],
+            },
+            SpendReport {
+                period: 1,
+                total: 300,
+                beneficiaries: vec![(alice, 300)],
+            },
+            SpendReport {
+                period: 2,
+                total: 100,
+                beneficiaries: vec![(bob, 100)],
+            },
+            SpendReport {
+                period: 3,
+                total: 300,
+                beneficiaries: vec![(bob, 300)],
+            },
+        ]
+    );
+}
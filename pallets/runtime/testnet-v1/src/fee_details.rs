@@ -15,22 +15,24 @@
 
 use crate::{runtime, Runtime};
 
+use pallet_asset as asset;
 use pallet_balances as balances;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
 use pallet_transaction_payment::CddAndFeeDetails;
 use polymesh_common_utilities::Context;
 use polymesh_primitives::{
-    traits::IdentityCurrency, AccountId, AccountKey, AuthorizationData, IdentityId, Signatory,
-    TransactionError,
+    traits::IdentityCurrency, AccountId, AccountKey, AuthorizationData, Balance, IdentityId,
+    Signatory, Ticker, TransactionError,
 };
-use polymesh_runtime_common::bridge;
+use polymesh_runtime_common::{bridge, dividend};
 use sp_runtime::transaction_validity::InvalidTransaction;
 
 use codec::{Decode, Encode};
 use core::convert::TryFrom;
 use frame_support::{StorageDoubleMap, StorageMap};
 
+type Asset = asset::Module<Runtime>;
 type Identity = identity::Module<Runtime>;
 type Balances = balances::Module<Runtime>;
 type Bridge = bridge::Module<Runtime>;
@@ -54,9 +56,11 @@ impl CddAndFeeDetails<Call> for CddHandler {
     /// This also sets the identity in the context to the identity that was checked for CDD
     /// However, this does not set the payer context since that is meant to remain constant
     /// throughout the transaction. This function can also be used to simply check CDD and update identity context.
+    /// The `fee` is only used to check whether a fee sponsorship can cover the transaction.
     fn get_valid_payer(
         call: &Call,
         caller: &Signatory,
+        fee: Balance,
     ) -> Result<Option<Signatory>, InvalidTransaction> {
         // The CDD check and fee payer varies depending on the transaction.
         // This match covers all possible scenarios.
@@ -89,7 +93,9 @@ impl CddAndFeeDetails<Call> for CddHandler {
             // The multisig must have valid CDD and the caller must be a signer of the multisig.
             Call::MultiSig(multisig::Call::create_or_approve_proposal_as_key(multisig, ..))
             | Call::MultiSig(multisig::Call::create_proposal_as_key(multisig, ..))
-            | Call::MultiSig(multisig::Call::approve_as_key(multisig, ..)) => {
+            | Call::MultiSig(multisig::Call::approve_as_key(multisig, ..))
+            | Call::MultiSig(multisig::Call::reject_as_key(multisig, ..))
+            | Call::MultiSig(multisig::Call::cancel_proposal_as_key(multisig, ..)) => {
                 sp_runtime::print("multisig stuff");
                 if <multisig::MultiSigSigners<Runtime>>::contains_key(multisig, caller) {
                     if let Some(did) = Identity::get_identity(
@@ -141,36 +147,20 @@ impl CddAndFeeDetails<Call> for CddHandler {
                 // This will never happen during an external call.
                 Signatory::Identity(did) => check_cdd(did),
             },
-            // All other calls
-            _ => match caller {
-                // An external account was passed as the caller. This is the normal use case.
-                // If the account has enabled charging fee to identity then the identity should be charged
-                // otherwise, the account should be charged. In any case, the external account
-                // must directly be linked to an identity with valid CDD.
-                Signatory::AccountKey(key) => {
-                    if let Some(did) = Identity::get_identity(key) {
-                        if Identity::has_valid_cdd(did) {
-                            Context::set_current_identity::<Identity>(Some(did));
-                            if let Some(fee_did) = Balances::charge_fee_to_identity(&key) {
-                                sp_runtime::print("charging identity");
-                                return Ok(Some(Signatory::from(fee_did)));
-                            } else {
-                                sp_runtime::print("charging key");
-                                return Ok(Some(*caller));
-                            }
-                        }
-                        return Err(InvalidTransaction::Custom(
-                            TransactionError::CddRequired as u8,
-                        )
-                        .into());
-                    }
-                    // Return an error if any of the above checks fail
-                    Err(InvalidTransaction::Custom(TransactionError::MissingIdentity as u8).into())
+            // Call made by an investor on a ticker whose owner sponsors the fees.
+            // The caller must still have a valid CDD. The sponsor pays if the fee fits
+            // within the sponsorship budget and the investor cap.
+            Call::Asset(asset::Call::transfer(ticker, ..))
+            | Call::Dividend(dividend::Call::claim(ticker, ..)) => {
+                let payer = check_caller_cdd(caller)?;
+                if let Some(sponsor) = sponsored_payer(ticker, fee) {
+                    sp_runtime::print("charging sponsor");
+                    return Ok(Some(Signatory::from(sponsor)));
                 }
-                // A did was passed as the caller. The did should be charged the fee.
-                // This will never happen during an external call.
-                Signatory::Identity(did) => check_cdd(did),
-            },
+                Ok(payer)
+            }
+            // All other calls
+            _ => check_caller_cdd(caller),
         }
     }
 
@@ -195,6 +185,46 @@ impl CddAndFeeDetails<Call> for CddHandler {
     }
 }
 
+/// Returns signatory to charge fee for a call that has no special payer.
+fn check_caller_cdd(caller: &Signatory) -> Result<Option<Signatory>, InvalidTransaction> {
+    match caller {
+        // An external account was passed as the caller. This is the normal use case.
+        // If the account has enabled charging fee to identity then the identity should be charged
+        // otherwise, the account should be charged. In any case, the external account
+        // must directly be linked to an identity with valid CDD.
+        Signatory::AccountKey(key) => {
+            if let Some(did) = Identity::get_identity(key) {
+                if Identity::has_valid_cdd(did) {
+                    Context::set_current_identity::<Identity>(Some(did));
+                    if let Some(fee_did) = Balances::charge_fee_to_identity(&key) {
+                        sp_runtime::print("charging identity");
+                        return Ok(Some(Signatory::from(fee_did)));
+                    } else {
+                        sp_runtime::print("charging key");
+                        return Ok(Some(*caller));
+                    }
+                }
+                return Err(InvalidTransaction::Custom(TransactionError::CddRequired as u8).into());
+            }
+            // Return an error if any of the above checks fail
+            Err(InvalidTransaction::Custom(TransactionError::MissingIdentity as u8).into())
+        }
+        // A did was passed as the caller. The did should be charged the fee.
+        // This will never happen during an external call.
+        Signatory::Identity(did) => check_cdd(did),
+    }
+}
+
+/// Returns the sponsor of the fees on `ticker` if it can pay `fee` for the current identity.
+fn sponsored_payer(ticker: &Ticker, fee: Balance) -> Option<IdentityId> {
+    let investor = Context::current_identity::<Identity>()?;
+    let sponsorship = Asset::fee_sponsorship(ticker)?;
+    if Balances::identity_balance(sponsorship.sponsor) < fee {
+        return None;
+    }
+    Asset::charge_sponsored_fee(ticker, investor, fee)
+}
+
 /// Returns signatory to charge fee if auth is valid.
 fn is_auth_valid(
     singer: &Signatory,
@@ -19,7 +19,11 @@ use pallet_compliance_manager::{self as compliance_manager, AssetTransferRulesRe
 use pallet_group as group;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
-use pallet_pips::{HistoricalVotingByAddress, HistoricalVotingById, Vote, VoteCount};
+use pallet_pips::{
+    Delegation, HistoricalVotingByAddress, HistoricalVotingById, PipCategorizer, PipCategory, Vote,
+    VoteCount,
+};
+use pallet_price_feed as price_feed;
 use pallet_protocol_fee as protocol_fee;
 use pallet_statistics as statistics;
 use pallet_treasury as treasury;
@@ -33,11 +37,11 @@ use polymesh_common_utilities::{
         identity::Trait as IdentityTrait,
         pip::{EnactProposalMaker, PipId},
     },
-    CommonTrait,
+    CommonTrait, SystematicIssuers,
 };
 use polymesh_primitives::{
-    AccountId, AccountIndex, AccountKey, Balance, BlockNumber, Hash, IdentityId, Index, Link,
-    Moment, Signatory, Signature, SigningItem, Ticker,
+    AccountId, AccountIndex, AccountKey, Authorization, Balance, BlockNumber, Hash, IdentityId,
+    Index, Link, Moment, Signatory, Signature, SigningItem, Ticker,
 };
 
 use frame_support::{
@@ -59,9 +63,11 @@ use sp_version::RuntimeVersion;
 use frame_system::offchain::TransactionSubmitter;
 use pallet_contracts_rpc_runtime_api::ContractExecResult;
 use pallet_grandpa::{fg_primitives, AuthorityList as GrandpaAuthorityList};
-use pallet_identity_rpc_runtime_api::{AssetDidResult, CddStatus, DidRecords, DidStatus, LinkType};
+use pallet_identity_rpc_runtime_api::{
+    AssetDidResult, AuthorizationFilter, CddStatus, DidRecords, DidStatus, LinkType,
+};
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
-use pallet_protocol_fee_rpc_runtime_api::CappedFee;
+use pallet_protocol_fee_rpc_runtime_api::{CappedFee, FeeDetails};
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_core::OpaqueMetadata;
@@ -86,7 +92,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("polymesh"),
     impl_name: create_runtime_str!("polymath-polymesh"),
     authoring_version: 1,
-    spec_version: 1005,
+    spec_version: 1006,
     impl_version: 1005,
     apis: RUNTIME_API_VERSIONS,
 };
@@ -220,7 +226,14 @@ impl pallet_transaction_payment::Trait for Runtime {
 impl protocol_fee::Trait for Runtime {
     type Event = Event;
     type Currency = Balances;
-    type OnProtocolFeePayment = DealWithFees;
+    type OnProtocolFeePayment = Treasury;
+    type OnValidatorsFeeShare = Author<Runtime>;
+    type PriceFeed = PriceFeed;
+}
+
+impl price_feed::Trait for Runtime {
+    type Event = Event;
+    type FeederOrigin = committee::EnsureProportionAtLeast<_2, _3, AccountId, GovernanceCommittee>;
 }
 
 parameter_types! {
@@ -325,6 +338,8 @@ impl pallet_staking::Trait for Runtime {
 
 parameter_types! {
     pub const MotionDuration: BlockNumber = 0;
+    pub const GovernanceCommitteeIssuer: SystematicIssuers = SystematicIssuers::Committee;
+    pub const TechnicalCommitteeIssuer: SystematicIssuers = SystematicIssuers::TechnicalCommittee;
 }
 type GovernanceCommittee = committee::Instance1;
 impl committee::Trait<GovernanceCommittee> for Runtime {
@@ -334,6 +349,13 @@ impl committee::Trait<GovernanceCommittee> for Runtime {
     type Event = Event;
     type MotionDuration = MotionDuration;
     type EnactProposalMaker = Runtime;
+    type SystematicIssuer = GovernanceCommitteeIssuer;
+}
+
+parameter_types! {
+    pub const BallotDeposit: Balance = 100 * DOLLARS;
+    pub const MaxElectionVoters: u32 = 1_000;
+    pub const MaxElectionCandidates: u32 = 64;
 }
 
 /// PolymeshCommittee as an instance of group
@@ -345,6 +367,36 @@ impl group::Trait<group::Instance1> for Runtime {
     type ResetOrigin = frame_system::EnsureRoot<AccountId>;
     type MembershipInitialized = PolymeshCommittee;
     type MembershipChanged = PolymeshCommittee;
+    type Currency = Balances;
+    type BallotDeposit = BallotDeposit;
+    type MaxVoters = MaxElectionVoters;
+    type MaxCandidates = MaxElectionCandidates;
+}
+
+type TechnicalCommitteeInstance = committee::Instance2;
+impl committee::Trait<TechnicalCommitteeInstance> for Runtime {
+    type Origin = Origin;
+    type Proposal = Call;
+    type CommitteeOrigin = frame_system::EnsureRoot<AccountId>;
+    type Event = Event;
+    type MotionDuration = MotionDuration;
+    type EnactProposalMaker = Runtime;
+    type SystematicIssuer = TechnicalCommitteeIssuer;
+}
+
+/// TechnicalCommittee as an instance of group
+impl group::Trait<group::Instance3> for Runtime {
+    type Event = Event;
+    type AddOrigin = frame_system::EnsureRoot<AccountId>;
+    type RemoveOrigin = frame_system::EnsureRoot<AccountId>;
+    type SwapOrigin = frame_system::EnsureRoot<AccountId>;
+    type ResetOrigin = frame_system::EnsureRoot<AccountId>;
+    type MembershipInitialized = TechnicalCommittee;
+    type MembershipChanged = TechnicalCommittee;
+    type Currency = Balances;
+    type BallotDeposit = BallotDeposit;
+    type MaxVoters = MaxElectionVoters;
+    type MaxCandidates = MaxElectionCandidates;
 }
 
 impl pallet_pips::Trait for Runtime {
@@ -352,8 +404,11 @@ impl pallet_pips::Trait for Runtime {
     type CommitteeOrigin = frame_system::EnsureRoot<AccountId>;
     type VotingMajorityOrigin =
         committee::EnsureProportionAtLeast<_2, _3, AccountId, GovernanceCommittee>;
+    type TechnicalCommitteeOrigin =
+        committee::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCommitteeInstance>;
     type GovernanceCommittee = PolymeshCommittee;
     type Treasury = Treasury;
+    type Categorizer = Runtime;
     type Event = Event;
 }
 
@@ -394,9 +449,20 @@ impl pallet_contracts::Trait for Runtime {
     type BlockGasLimit = pallet_contracts::DefaultBlockGasLimit;
 }
 
+parameter_types! {
+    pub const GrantDeposit: Balance = 1_000 * DOLLARS;
+    pub const MaxGrantDescriptionLength: u32 = 4_096;
+    pub const MaxMilestones: u32 = 16;
+}
+
 impl treasury::Trait for Runtime {
     type Event = Event;
     type Currency = Balances;
+    type CommitteeOrigin =
+        committee::EnsureProportionAtLeast<_2, _3, AccountId, GovernanceCommittee>;
+    type GrantDeposit = GrantDeposit;
+    type MaxGrantDescriptionLength = MaxGrantDescriptionLength;
+    type MaxMilestones = MaxMilestones;
 }
 
 impl pallet_offences::Trait for Runtime {
@@ -498,6 +564,7 @@ impl IdentityTrait for Runtime {
     type Public = <MultiSignature as Verify>::Signer;
     type OffChainSignature = MultiSignature;
     type ProtocolFee = protocol_fee::Module<Runtime>;
+    type Holdings = (balances::Module<Runtime>, Asset);
 }
 
 impl contracts_wrapper::Trait for Runtime {}
@@ -520,6 +587,10 @@ impl group::Trait<group::Instance2> for Runtime {
     type ResetOrigin = frame_system::EnsureRoot<AccountId>;
     type MembershipInitialized = Identity;
     type MembershipChanged = Identity;
+    type Currency = Balances;
+    type BallotDeposit = BallotDeposit;
+    type MaxVoters = MaxElectionVoters;
+    type MaxCandidates = MaxElectionCandidates;
 }
 
 impl statistics::Trait for Runtime {}
@@ -543,6 +614,22 @@ impl EnactProposalMaker<Origin, Call> for Runtime {
     }
 }
 
+impl PipCategorizer<Call> for Runtime {
+    fn category(proposal: &Call) -> PipCategory {
+        match proposal {
+            Call::System(frame_system::Call::set_code(..))
+            | Call::System(frame_system::Call::set_code_without_checks(..)) => {
+                PipCategory::RuntimeUpgrade
+            }
+            Call::Treasury(..) => PipCategory::Treasury,
+            Call::Pips(..) | Call::ProtocolFee(..) | Call::Staking(..) => {
+                PipCategory::ParameterChange
+            }
+            _ => PipCategory::Other,
+        }
+    }
+}
+
 construct_runtime!(
     pub enum Runtime where
         Block = Block,
@@ -579,7 +666,7 @@ construct_runtime!(
         // ContractsWrapper: contracts_wrapper::{Module, Call, Storage},
 
         // Polymesh Governance Committees
-        Treasury: treasury::{Module, Call, Event<T>},
+        Treasury: treasury::{Module, Call, Storage, Event<T>},
         PolymeshCommittee: committee::<Instance1>::{Module, Call, Storage, Origin<T>, Event<T>, Config<T>},
         CommitteeMembership: group::<Instance1>::{Module, Call, Storage, Event<T>, Config<T>},
         Pips: pallet_pips::{Module, Call, Storage, Event<T>, Config<T>},
@@ -598,6 +685,9 @@ construct_runtime!(
         Statistic: statistics::{Module, Call, Storage},
         ProtocolFee: protocol_fee::{Module, Call, Storage, Event<T>, Config<T>},
         Utility: utility::{Module, Call, Storage, Event},
+        TechnicalCommittee: committee::<Instance2>::{Module, Call, Storage, Origin<T>, Event<T>, Config<T>},
+        TechnicalCommitteeMembership: group::<Instance3>::{Module, Call, Storage, Event<T>, Config<T>},
+        PriceFeed: price_feed::{Module, Call, Storage, Event<T>},
     }
 );
 
@@ -822,13 +912,34 @@ impl_runtime_apis! {
         fn voting_history_by_id(id: IdentityId) -> HistoricalVotingById<Vote<Balance>> {
             Pips::voting_history_by_id(id)
         }
+
+        fn delegation(address: AccountId) -> Option<Delegation<Balance>> {
+            Pips::delegation(address)
+        }
+
+        fn delegators(delegate: IdentityId) -> Vec<(AccountId, Balance)> {
+            Pips::delegators_of(delegate)
+        }
     }
 
     impl pallet_protocol_fee_rpc_runtime_api::ProtocolFeeApi<
         Block,
     > for Runtime {
-        fn compute_fee(op: ProtocolOp) -> CappedFee {
-            ProtocolFee::compute_fee(op).into()
+        fn compute_fee(op: ProtocolOp) -> FeeDetails {
+            let (fee, reference_fee, stale_price) = ProtocolFee::compute_fee_details(op);
+            FeeDetails {
+                fee: fee.into(),
+                reference_fee: reference_fee.map(Into::into),
+                stale_price,
+            }
+        }
+
+        fn fees_paid(did: IdentityId, op: ProtocolOp) -> CappedFee {
+            ProtocolFee::fees_paid(did, op).into()
+        }
+
+        fn total_fees_paid(did: IdentityId) -> CappedFee {
+            ProtocolFee::total_fees_paid(did).into()
         }
     }
 
@@ -871,6 +982,68 @@ impl_runtime_apis! {
         fn get_did_status(dids: Vec<IdentityId>) -> Vec<DidStatus> {
             Identity::get_did_status(dids)
         }
+
+        /// Retrieve a page of the pending authorizations of a given signatory
+        fn get_authorizations(
+            signatory: Signatory,
+            filter: AuthorizationFilter<Signatory, Moment>,
+        ) -> Vec<Authorization<Moment>> {
+            Identity::get_authorizations(signatory, filter)
+        }
+    }
+
+    impl node_rpc_runtime_api::multisig::MultiSigApi<Block, AccountId, Moment, Call> for Runtime {
+        #[inline]
+        fn get_open_proposals(
+            multisig: AccountId,
+            start: u64,
+            count: u32,
+        ) -> Vec<multisig::OpenProposal<Call, Moment>> {
+            MultiSig::get_open_proposals(multisig, start, count)
+        }
+    }
+
+    impl node_rpc_runtime_api::treasury::TreasuryApi<Block, Balance> for Runtime {
+        #[inline]
+        fn spend_report(
+            from: treasury::BudgetPeriodIndex,
+            to: treasury::BudgetPeriodIndex,
+        ) -> Vec<treasury::SpendReport<Balance>> {
+            Treasury::spend_report(from, to)
+        }
+    }
+
+    impl node_rpc_runtime_api::im_online::ImOnlineApi<Block, AccountId> for Runtime {
+        #[inline]
+        fn offline_reports(
+            validator: AccountId,
+        ) -> Vec<(sp_staking::SessionIndex, pallet_im_online::OfflineReport)> {
+            ImOnline::offline_reports(validator)
+        }
+    }
+
+    impl node_rpc_runtime_api::bridge::BridgeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        #[inline]
+        fn txs_by_recipient(
+            recipient: AccountId,
+        ) -> Vec<bridge::BridgeTxReport<AccountId, Balance, BlockNumber>> {
+            Bridge::txs_by_recipient(recipient)
+        }
+
+        #[inline]
+        fn txs_by_tx_hash(
+            tx_hash: sp_core::H256,
+        ) -> Vec<bridge::BridgeTxReport<AccountId, Balance, BlockNumber>> {
+            Bridge::txs_by_tx_hash(tx_hash)
+        }
+
+        #[inline]
+        fn pending_txs(
+            start: u32,
+            count: u32,
+        ) -> Vec<bridge::BridgeTxReport<AccountId, Balance, BlockNumber>> {
+            Bridge::pending_txs(start, count)
+        }
     }
 
     impl node_rpc_runtime_api::asset::AssetApi<Block, AccountId, Balance> for Runtime {
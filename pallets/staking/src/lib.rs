@@ -21,6 +21,8 @@
 // dispatchable gets modified.
 // - Introduce `validate_cdd_expiry_nominators()` to remove the nominators from the potential nominators list
 // when there CDD check get expired.
+// - Nominators are flagged for a grace period before removal and their nominations are restored
+// once they hold a valid CDD check again.
 // - Commission can be individual or global.
 // - Validators stash account should stake a minimum bonding amount to be a potential validator.
 
@@ -100,6 +102,20 @@
 //! [`add_permissioned_validator`](./enum.Call.html#variant.validate) call & there _stash_ accounts has valid CDD claim.
 //! Compliance status can only provided by the [`T::RequiredAddOrigin`].
 //!
+//! An operator identity can also apply to run a permissioned validator via the
+//! [`apply_as_validator`](./enum.Call.html#variant.apply_as_validator) call, signed by a bonded
+//! stash linked to the identity, with metadata about the legal entity, its jurisdiction and the
+//! hosting location of the validator. The operator must hold a valid CDD claim and the validator
+//! claim requirements set by the [`T::RequiredComplianceOrigin`]. Applications are approved by the
+//! [`T::RequiredAddOrigin`]. At the start of each era, permissioned validators whose CDD claim or
+//! required claims have lapsed are suspended until the claims are renewed.
+//!
+//! For decentralisation, the number of elected validators per operator identity can be capped with
+//! [`set_max_validators_per_identity`](./enum.Call.html#variant.set_max_validators_per_identity),
+//! and the number of elected validators per jurisdiction with
+//! [`set_jurisdiction_diversity`](./enum.Call.html#variant.set_jurisdiction_diversity). When
+//! candidates exceed a cap at election time, those with the highest approval stake are kept.
+//!
 //! #### Nomination
 //!
 //! A **nominator** does not take any _direct_ role in maintaining the network, instead, it votes on
@@ -221,6 +237,9 @@
 //! - Controller account, (obviously) not increasing the staked value.
 //! - Stash account, not increasing the staked value.
 //! - Stash account, also increasing the staked value.
+//! - Identity balance of the stash owner, not increasing the staked value.
+//! - Any account of the identity of the stash owner, not increasing the staked value.
+//! - Stash account, also increasing the staked value when the reward reaches a threshold.
 //!
 //! ### Additional Fund Management Operations
 //!
@@ -286,7 +305,10 @@ use frame_support::{
 use pallet_identity as identity;
 use pallet_session::historical::SessionManager;
 use polymesh_common_utilities::{identity::Trait as IdentityTrait, Context};
-use primitives::{traits::BlockRewardsReserveCurrency, AccountKey, IdentityId};
+use primitives::{
+    traits::{BlockRewardsReserveCurrency, IdentityCurrency},
+    AccountKey, Claim, ClaimRequirement, ClaimType, IdentityId, JurisdictionName,
+};
 
 use sp_phragmen::ExtendedBalance;
 use sp_runtime::{
@@ -295,7 +317,7 @@ use sp_runtime::{
         AtLeast32Bit, CheckedSub, Convert, EnsureOrigin, SaturatedConversion, Saturating,
         StaticLookup, Zero,
     },
-    PerThing, Perbill, RuntimeDebug,
+    DispatchError, PerThing, Perbill, RuntimeDebug,
 };
 use sp_staking::{
     offence::{Offence, OffenceDetails, OffenceError, OnOffenceHandler, ReportOffence},
@@ -362,16 +384,25 @@ pub enum StakerStatus<AccountId> {
 
 /// A destination account for payment.
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
-pub enum RewardDestination {
+pub enum RewardDestination<AccountId, Balance> {
     /// Pay into the stash account, increasing the amount at stake accordingly.
     Staked,
     /// Pay into the stash account, not increasing the amount at stake.
     Stash,
     /// Pay into the controller account.
     Controller,
+    /// Pay into the identity balance of the stash owner, or into the stash account if the stash
+    /// no longer has an identity.
+    IdentityBalance,
+    /// Pay into the given account, which must belong to the identity of the stash owner, or into
+    /// the stash account otherwise.
+    Account(AccountId),
+    /// Pay into the stash account, increasing the amount at stake when the reward is at least the
+    /// given threshold.
+    Compound(Balance),
 }
 
-impl Default for RewardDestination {
+impl<AccountId, Balance> Default for RewardDestination<AccountId, Balance> {
     fn default() -> Self {
         RewardDestination::Staked
     }
@@ -614,6 +645,9 @@ pub enum Compliance {
     Pending,
     /// CDD compliant. Eligible to participate in validation.
     Active,
+    /// Compliance requirements lapsed after being met. Not eligible to participate in validation
+    /// until they are met again.
+    Suspended,
 }
 
 impl Default for Compliance {
@@ -637,6 +671,39 @@ impl Default for PermissionedValidator {
     }
 }
 
+/// Metadata of the operator of a permissioned validator.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Default, RuntimeDebug)]
+pub struct ValidatorMetadata {
+    /// Name of the legal entity operating the validator.
+    pub legal_entity: Vec<u8>,
+    /// Jurisdiction of the legal entity.
+    pub jurisdiction: JurisdictionName,
+    /// Location where the validator is hosted.
+    pub hosting_location: Vec<u8>,
+}
+
+/// An application of an operator identity to run a permissioned validator.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Default, RuntimeDebug)]
+pub struct ValidatorApplication<Balance> {
+    /// The operator identity.
+    pub operator: IdentityId,
+    /// The active bond of the validator stash at the time of the application, or of the approval
+    /// once approved.
+    pub bond: Balance,
+    /// Metadata of the operator.
+    pub metadata: ValidatorMetadata,
+}
+
+/// A constraint on the number of elected validators per jurisdiction.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct JurisdictionDiversity {
+    /// The maximum number of elected validators whose operators share a jurisdiction.
+    pub max_validators: u32,
+    /// The trusted issuer of the `Jurisdiction` claims of validator operators. Candidates without
+    /// such a claim are not elected.
+    pub issuer: IdentityId,
+}
+
 /// Means for interacting with a specialized version of the `session` trait.
 ///
 /// This is needed because `Staking` sets the `ValidatorIdOf` of the `pallet_session::Trait`.
@@ -683,7 +750,8 @@ where
 pub trait Trait: frame_system::Trait + pallet_babe::Trait + IdentityTrait {
     /// The staking balance.
     type Currency: LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>
-        + BlockRewardsReserveCurrency<BalanceOf<Self>, NegativeImbalanceOf<Self>>;
+        + BlockRewardsReserveCurrency<BalanceOf<Self>, NegativeImbalanceOf<Self>>
+        + IdentityCurrency<Self::AccountId>;
 
     /// Time used for computing era duration.
     ///
@@ -805,7 +873,9 @@ decl_storage! {
             => Option<StakingLedger<T::AccountId, BalanceOf<T>>>;
 
         /// Where the reward payment should be made. Keyed by stash.
-        pub Payee get(fn payee): map hasher(twox_64_concat) T::AccountId => RewardDestination;
+        pub Payee get(fn payee):
+            map hasher(twox_64_concat) T::AccountId
+            => RewardDestination<T::AccountId, BalanceOf<T>>;
 
         /// The map from (wannabe) validator stash key to the preferences of that validator.
         pub Validators get(fn validators):
@@ -930,6 +1000,49 @@ decl_storage! {
         pub PermissionedValidators get(permissioned_validators):
             linked_map hasher(twox_64_concat) T::AccountId => Option<PermissionedValidator>;
 
+        /// Pending applications to run a permissioned validator, by stash account.
+        pub ValidatorApplications get(fn validator_applications):
+            linked_map hasher(twox_64_concat) T::AccountId => Option<ValidatorApplication<BalanceOf<T>>>;
+
+        /// Approved applications of permissioned validators, by stash account.
+        pub ValidatorOperators get(fn validator_operators):
+            map hasher(twox_64_concat) T::AccountId => Option<ValidatorApplication<BalanceOf<T>>>;
+
+        /// Claims that the operators of permissioned validators are required to hold in addition
+        /// to a valid CDD claim.
+        pub ValidatorClaimRequirements get(fn validator_claim_requirements):
+            Vec<ClaimRequirement>;
+
+        /// The maximum number of elected validators per operator identity. Zero means no limit.
+        pub MaxValidatorsPerIdentity get(fn max_validators_per_identity): u32;
+
+        /// The number of permissioned validators operated by each identity.
+        pub ValidatorsPerIdentity get(fn validators_per_identity):
+            map hasher(twox_64_concat) IdentityId => u32;
+
+        /// The identity counted in `ValidatorsPerIdentity` for each permissioned validator.
+        pub ValidatorIdentity get(fn validator_identity):
+            map hasher(twox_64_concat) T::AccountId => Option<IdentityId>;
+
+        /// The optional constraint on the number of elected validators per jurisdiction.
+        pub ValidatorJurisdictionDiversity get(fn jurisdiction_diversity):
+            Option<JurisdictionDiversity>;
+
+        /// The time during which nominators whose CDD claim is about to expire are flagged before
+        /// they are removed from the nominators. Zero removes them straight away.
+        pub NominatorCddGracePeriod get(fn nominator_cdd_grace_period): T::Moment;
+
+        /// Nominators flagged for CDD expiry, by stash account, with the time after which they
+        /// get removed.
+        pub FlaggedNominators get(fn flagged_nominators):
+            linked_map hasher(twox_64_concat) T::AccountId => Option<T::Moment>;
+
+        /// Nomination targets and unbonded balance of the nominators removed for CDD expiry, by
+        /// stash account. They are nominated again and the balance still unlocking is rebonded
+        /// once the nominator holds a valid CDD claim.
+        pub SuspendedNominations get(fn suspended_nominations):
+            linked_map hasher(twox_64_concat) T::AccountId => Option<(Vec<T::AccountId>, BalanceOf<T>)>;
+
         /// Commision rate to be used by all validators.
         pub ValidatorCommission get(fn validator_commission) config(): Commission;
 
@@ -979,7 +1092,11 @@ decl_storage! {
 }
 
 decl_event!(
-    pub enum Event<T> where Balance = BalanceOf<T>, <T as frame_system::Trait>::AccountId {
+    pub enum Event<T> where
+        Balance = BalanceOf<T>,
+        <T as frame_system::Trait>::AccountId,
+        Moment = <T as pallet_timestamp::Trait>::Moment,
+    {
         /// The staker has been rewarded by this amount. AccountId is controller account.
         Rewarded(Option<IdentityId>, AccountId, Balance),
         /// One validator (and its nominators) has been slashed by the given amount.
@@ -1009,6 +1126,26 @@ decl_event!(
         Unbonded(IdentityId, AccountId, Balance),
         /// User has updated their nominations
         Nominated(IdentityId, AccountId, Vec<AccountId>),
+        /// An operator identity has applied to run a permissioned validator with a stash account.
+        ValidatorApplied(IdentityId, AccountId),
+        /// An application to run a permissioned validator has been withdrawn.
+        ValidatorApplicationWithdrawn(IdentityId, AccountId),
+        /// An application to run a permissioned validator has been approved.
+        ValidatorApplicationApproved(IdentityId, AccountId),
+        /// An application to run a permissioned validator has been rejected.
+        ValidatorApplicationRejected(IdentityId, AccountId),
+        /// The claims required from validator operators have been changed.
+        ValidatorClaimRequirementsChanged(Vec<ClaimRequirement>),
+        /// A permissioned validator has been suspended because its CDD claim or required claims
+        /// lapsed. Parameters: validator identity, stash account.
+        ValidatorSuspended(Option<IdentityId>, AccountId),
+        /// A suspended permissioned validator has met the compliance requirements again.
+        ValidatorReinstated(Option<IdentityId>, AccountId),
+        /// The CDD claim of a nominator is about to expire. The nominator gets removed from the
+        /// nominators after the given time unless the claim is renewed.
+        NominatorFlagged(AccountId, Moment),
+        /// The nominations of a nominator removed for CDD expiry have been restored.
+        NominationsRestored(IdentityId, AccountId, Vec<AccountId>),
     }
 );
 
@@ -1055,6 +1192,14 @@ decl_error! {
         InvalidEraToReward,
         /// Invalid number of nominations.
         InvalidNumberOfNominations,
+        /// The account has no identity.
+        MissingIdentity,
+        /// There is no such validator application.
+        NoSuchApplication,
+        /// The operator identity has reached the maximum number of validators.
+        TooManyValidatorsPerIdentity,
+        /// The reward destination does not belong to the identity of the stash owner.
+        InvalidPayee,
     }
 }
 
@@ -1104,10 +1249,11 @@ decl_module! {
         pub fn bond(origin,
             controller: <T::Lookup as StaticLookup>::Source,
             #[compact] value: BalanceOf<T>,
-            payee: RewardDestination
+            payee: RewardDestination<T::AccountId, BalanceOf<T>>
         ) {
             let stash = ensure_signed(origin)?;
             ensure!(!<Bonded<T>>::contains_key(&stash), Error::<T>::AlreadyBonded);
+            Self::ensure_valid_payee(&stash, &payee)?;
 
             let controller = T::Lookup::lookup(controller)?;
             ensure!(!<Ledger<T>>::contains_key(&controller), Error::<T>::AlreadyPaired);
@@ -1279,6 +1425,7 @@ decl_module! {
                 ensure!(prefs.commission == commission, Error::<T>::InvalidCommission);
             }
 
+            Self::clear_cdd_expiry(stash);
             <Nominators<T>>::remove(stash);
             <Validators<T>>::insert(stash, prefs);
         }
@@ -1328,6 +1475,7 @@ decl_module! {
                         suppressed: false,
                     };
 
+                    Self::clear_cdd_expiry(stash);
                     <Validators<T>>::remove(stash);
                     <Nominators<T>>::insert(stash, &nominations);
                     Self::deposit_event(RawEvent::Nominated(nominate_identity, stash.clone(), targets));
@@ -1365,10 +1513,11 @@ decl_module! {
         /// - Writes are limited to the `origin` account key.
         /// # </weight>
         #[weight = SimpleDispatchInfo::FixedNormal(500_000)]
-        pub fn set_payee(origin, payee: RewardDestination) {
+        pub fn set_payee(origin, payee: RewardDestination<T::AccountId, BalanceOf<T>>) {
             let controller = ensure_signed(origin)?;
             let ledger = Self::ledger(&controller).ok_or(Error::<T>::NotController)?;
             let stash = &ledger.stash;
+            Self::ensure_valid_payee(stash, &payee)?;
             <Payee<T>>::insert(stash, payee);
         }
 
@@ -1409,6 +1558,28 @@ decl_module! {
             ValidatorCount::put(new);
         }
 
+        /// The maximum number of elected validators per operator identity. Zero means no limit.
+        #[weight = SimpleDispatchInfo::FixedOperational(20000)]
+        pub fn set_max_validators_per_identity(origin, #[compact] new: u32) {
+            ensure_root(origin)?;
+            MaxValidatorsPerIdentity::put(new);
+        }
+
+        /// Sets or clears the constraint on the number of elected validators per jurisdiction.
+        #[weight = SimpleDispatchInfo::FixedOperational(20000)]
+        pub fn set_jurisdiction_diversity(origin, diversity: Option<JurisdictionDiversity>) {
+            ensure_root(origin)?;
+            ValidatorJurisdictionDiversity::mutate(|old| *old = diversity);
+        }
+
+        /// The time during which nominators whose CDD claim is about to expire are flagged before
+        /// they are removed from the nominators.
+        #[weight = SimpleDispatchInfo::FixedOperational(20000)]
+        pub fn set_nominator_cdd_grace_period(origin, period: T::Moment) {
+            ensure_root(origin)?;
+            <NominatorCddGracePeriod<T>>::put(period);
+        }
+
         /// Governance committee on 2/3 rds majority can introduce a new potential validator
         /// to the pool of validators. Staking module uses `PermissionedValidators` to ensure
         /// validators have completed KYB compliance and considers them for validation.
@@ -1422,13 +1593,19 @@ decl_module! {
                 .map_err(|_| Error::<T>::NotAuthorised)?;
 
             ensure!(!<PermissionedValidators<T>>::contains_key(&validator), Error::<T>::AlreadyExists);
+            let validator_key = validator.encode().try_into()?;
+            let validator_id = <identity::Module<T>>::get_identity(&validator_key);
+            if let Some(did) = validator_id {
+                Self::ensure_below_validators_per_identity(did)?;
+            }
 
             <PermissionedValidators<T>>::insert(&validator, PermissionedValidator {
                 compliance: Compliance::Pending
             });
+            if let Some(did) = validator_id {
+                Self::count_validator(&validator, did);
+            }
 
-            let validator_key = validator.encode().try_into()?;
-            let validator_id = <identity::Module<T>>::get_identity(&validator_key);
             Self::deposit_event(RawEvent::PermissionedValidatorAdded(validator_id, validator));
         }
 
@@ -1448,15 +1625,120 @@ decl_module! {
             ensure!(<PermissionedValidators<T>>::contains_key(&validator), Error::<T>::NotExists);
 
             <PermissionedValidators<T>>::remove(&validator);
+            <ValidatorOperators<T>>::remove(&validator);
+            Self::uncount_validator(&validator);
 
             Self::deposit_event(RawEvent::PermissionedValidatorRemoved(caller_id, validator));
         }
 
+        /// Applies to run a permissioned validator with the bonded stash account signing the
+        /// extrinsic on behalf of the identity linked to it. The identity must hold a valid CDD
+        /// claim and the validator claim requirements.
+        ///
+        /// # Arguments
+        /// * origin Stash account (signer of the extrinsic).
+        /// * metadata Metadata of the operator.
+        #[weight = SimpleDispatchInfo::FixedNormal(250_000)]
+        pub fn apply_as_validator(origin, metadata: ValidatorMetadata) {
+            let stash = ensure_signed(origin)?;
+            let controller = Self::bonded(&stash).ok_or(Error::<T>::NotStash)?;
+            let ledger = Self::ledger(&controller).ok_or(Error::<T>::NotController)?;
+            ensure!(ledger.active >= <MinimumBondThreshold<T>>::get(), Error::<T>::InsufficientValue);
+            ensure!(
+                !<PermissionedValidators<T>>::contains_key(&stash)
+                    && !<ValidatorApplications<T>>::contains_key(&stash),
+                Error::<T>::AlreadyExists
+            );
+            let operator = <identity::Module<T>>::get_identity(&stash.encode().try_into()?)
+                .ok_or(Error::<T>::MissingIdentity)?;
+            ensure!(Self::is_validator_compliant(&stash), Error::<T>::NotCompliant);
+
+            <ValidatorApplications<T>>::insert(&stash, ValidatorApplication {
+                operator,
+                bond: ledger.active,
+                metadata,
+            });
+            Self::deposit_event(RawEvent::ValidatorApplied(operator, stash));
+        }
+
+        /// Withdraws the pending validator application of the stash account signing the extrinsic.
+        #[weight = SimpleDispatchInfo::FixedNormal(50_000)]
+        pub fn withdraw_validator_application(origin) {
+            let stash = ensure_signed(origin)?;
+            let application = <ValidatorApplications<T>>::take(&stash)
+                .ok_or(Error::<T>::NoSuchApplication)?;
+            Self::deposit_event(RawEvent::ValidatorApplicationWithdrawn(application.operator, stash));
+        }
+
+        /// Approves the pending validator application of `stash`, which adds it to the
+        /// permissioned validators. The active bond of the stash must still meet the minimum bond
+        /// threshold.
+        ///
+        /// # Arguments
+        /// * origin Required origin for adding a potential validator.
+        /// * stash Stash AccountId of the validator.
+        #[weight = SimpleDispatchInfo::FixedNormal(100_000)]
+        pub fn approve_validator_application(origin, stash: T::AccountId) {
+            T::RequiredAddOrigin::try_origin(origin)
+                .map_err(|_| Error::<T>::NotAuthorised)?;
+            let application = <ValidatorApplications<T>>::get(&stash)
+                .ok_or(Error::<T>::NoSuchApplication)?;
+            ensure!(!<PermissionedValidators<T>>::contains_key(&stash), Error::<T>::AlreadyExists);
+            let controller = Self::bonded(&stash).ok_or(Error::<T>::NotStash)?;
+            let ledger = Self::ledger(&controller).ok_or(Error::<T>::NotController)?;
+            ensure!(ledger.active >= <MinimumBondThreshold<T>>::get(), Error::<T>::InsufficientValue);
+            Self::ensure_below_validators_per_identity(application.operator)?;
+
+            <ValidatorApplications<T>>::remove(&stash);
+            <PermissionedValidators<T>>::insert(&stash, PermissionedValidator {
+                compliance: Compliance::Pending
+            });
+            let operator = application.operator;
+            Self::count_validator(&stash, operator);
+            <ValidatorOperators<T>>::insert(&stash, ValidatorApplication {
+                bond: ledger.active,
+                ..application
+            });
+            Self::deposit_event(RawEvent::ValidatorApplicationApproved(operator, stash.clone()));
+            Self::deposit_event(RawEvent::PermissionedValidatorAdded(Some(operator), stash));
+        }
+
+        /// Rejects the pending validator application of `stash`.
+        ///
+        /// # Arguments
+        /// * origin Required origin for removing a validator.
+        /// * stash Stash AccountId of the applicant.
+        #[weight = SimpleDispatchInfo::FixedNormal(50_000)]
+        pub fn reject_validator_application(origin, stash: T::AccountId) {
+            T::RequiredRemoveOrigin::try_origin(origin)
+                .map_err(|_| Error::<T>::NotAuthorised)?;
+            let application = <ValidatorApplications<T>>::take(&stash)
+                .ok_or(Error::<T>::NoSuchApplication)?;
+            Self::deposit_event(RawEvent::ValidatorApplicationRejected(application.operator, stash));
+        }
+
+        /// Replaces the claims that validator operators are required to hold in addition to a
+        /// valid CDD claim. Validators missing any of them are suspended at the start of the next
+        /// era.
+        ///
+        /// # Arguments
+        /// * origin Required origin for changing compliance status.
+        /// * requirements The new claim requirements.
+        #[weight = SimpleDispatchInfo::FixedOperational(100_000)]
+        pub fn set_validator_claim_requirements(origin, requirements: Vec<ClaimRequirement>) {
+            T::RequiredComplianceOrigin::try_origin(origin)
+                .map_err(|_| Error::<T>::NotAuthorised)?;
+            <ValidatorClaimRequirements>::put(requirements.clone());
+            Self::deposit_event(RawEvent::ValidatorClaimRequirementsChanged(requirements));
+        }
+
         /// Validate the nominators CDD expiry time.
         ///
         /// If an account from a given set of address is nominating then
         /// check the CDD expiry time of it and if it is expired
         /// then the account should be unbonded and removed from the nominating process.
+        /// While `NominatorCddGracePeriod` is non-zero, the account is only flagged at first and
+        /// gets removed once the grace period has elapsed.
         ///
         /// #<weight>
         /// - Depends on passed list of AccountId.
@@ -1482,21 +1764,8 @@ decl_module! {
                         // So we iterate all of them and if any one of the claim value doesn't expire then nominator posses
                         // valid CDD otherwise it will be removed from the pool of the nominators.
                         let is_cdded = <identity::Module<T>>::has_valid_cdd(nominate_identity);
-                        if !is_cdded {
-                            // Un-bonding the balance that bonded with the controller account of a Stash account
-                            // This unbonded amount only be accessible after completion of the BondingDuration
-                            // Controller account need to call the dispatchable function `withdraw_unbond` to withdraw fund.
-
-                            let controller = Self::bonded(target).ok_or("not a stash")?;
-                            let mut ledger = Self::ledger(&controller).ok_or("not a controller")?;
-                            let active_balance = ledger.active;
-                            if ledger.unlocking.len() < MAX_UNLOCKING_CHUNKS {
-                                Self::unbond_balance(controller, &mut ledger, active_balance);
-
-                                expired_nominators.push(target.clone());
-                                // Free the nominator from the valid nominator list
-                                <Nominators<T>>::remove(target);
-                            }
+                        if !is_cdded && Self::expire_cdd_nominator(target)? {
+                            expired_nominators.push(target.clone());
                         }
                     }
                 }
@@ -1786,28 +2055,100 @@ impl<T: Trait> Module<T> {
         return invalid_nominators;
     }
 
+    /// Returns the active permissioned validators whose CDD claim expires within `buffer` or who
+    /// miss any of the validator claim requirements. Like `fetch_invalid_cdd_nominators`, it is
+    /// suitable for off-chain use only.
+    pub fn fetch_invalid_validators(buffer: u64) -> Vec<T::AccountId> {
+        <PermissionedValidators<T>>::enumerate()
+            .filter(|(_, validator)| validator.compliance == Compliance::Active)
+            .map(|(stash, _)| stash)
+            .filter(|stash| {
+                AccountKey::try_from(stash.encode())
+                    .ok()
+                    .and_then(|key| <identity::Module<T>>::get_identity(&key))
+                    .map_or(true, |did| {
+                        <identity::Module<T>>::fetch_cdd(did, buffer.saturated_into::<T::Moment>())
+                            .is_none()
+                            || !Self::has_validator_claims(did)
+                    })
+            })
+            .collect()
+    }
+
+    /// Suspends those of the `targets` that are active permissioned validators no longer meeting
+    /// the compliance requirements. Unlike nominators, the targets are checked again on chain, so
+    /// a validator whose CDD claim only expires within the off-chain buffer stays active until
+    /// the claim actually lapses.
+    pub fn unsafe_suspend_invalid_validators(targets: Vec<T::AccountId>) -> DispatchResult {
+        for stash in targets.iter() {
+            if Self::is_validator_cdd_compliant(stash) && !Self::is_validator_compliant(stash) {
+                <PermissionedValidators<T>>::insert(
+                    stash,
+                    PermissionedValidator {
+                        compliance: Compliance::Suspended,
+                    },
+                );
+                let validator_id = AccountKey::try_from(stash.encode())
+                    .ok()
+                    .and_then(|key| <identity::Module<T>>::get_identity(&key));
+                Self::deposit_event(RawEvent::ValidatorSuspended(validator_id, stash.clone()));
+            }
+        }
+        Ok(())
+    }
+
     /// POLYMESH-NOTE: This is Polymesh specific change.
     /// Here we are assuming that passed targets are always be a those nominators whose cdd
     /// claim get expired or going to expire after the `buffer_time`.
     pub fn unsafe_validate_cdd_expiry_nominators(targets: Vec<T::AccountId>) -> DispatchResult {
         // Iterate provided list of accountIds (These accountIds should be stash type account).
         for target in targets.iter() {
-            // Un-bonding the balance that bonded with the controller account of a Stash account
-            // This unbonded amount only be accessible after completion of the BondingDuration
-            // Controller account need to call the dispatchable function `withdraw_unbond` to use fund.
-
-            let controller = Self::bonded(target).ok_or("not a stash")?;
-            let mut ledger = Self::ledger(&controller).ok_or("not a controller")?;
-            let active_balance = ledger.active;
-            if ledger.unlocking.len() < MAX_UNLOCKING_CHUNKS {
-                Self::unbond_balance(controller, &mut ledger, active_balance);
-                // Free the nominator from the valid nominator list
-                <Nominators<T>>::remove(target);
-            }
+            Self::expire_cdd_nominator(target)?;
         }
         Ok(())
     }
 
+    /// Flags the nominator `stash` for CDD expiry or, once its grace period has elapsed, unbonds
+    /// its active balance and removes it from the nominators. The nomination targets and the
+    /// unbonded balance are kept in `SuspendedNominations` so that they can be restored. Returns
+    /// whether the nominator was removed.
+    fn expire_cdd_nominator(stash: &T::AccountId) -> result::Result<bool, DispatchError> {
+        let nominations = match Self::nominators(stash) {
+            Some(nominations) => nominations,
+            None => return Ok(false),
+        };
+        let grace_period = Self::nominator_cdd_grace_period();
+        if !grace_period.is_zero() {
+            let now = <pallet_timestamp::Module<T>>::get();
+            match Self::flagged_nominators(stash) {
+                None => {
+                    let deadline = now.saturating_add(grace_period);
+                    <FlaggedNominators<T>>::insert(stash, deadline);
+                    Self::deposit_event(RawEvent::NominatorFlagged(stash.clone(), deadline));
+                    return Ok(false);
+                }
+                Some(deadline) if now < deadline => return Ok(false),
+                Some(_) => {}
+            }
+        }
+        // Un-bonding the balance that bonded with the controller account of a Stash account
+        // This unbonded amount only be accessible after completion of the BondingDuration
+        // Controller account need to call the dispatchable function `withdraw_unbond` to use fund.
+        let controller = Self::bonded(stash).ok_or(Error::<T>::NotStash)?;
+        let mut ledger = Self::ledger(&controller).ok_or(Error::<T>::NotController)?;
+        if ledger.unlocking.len() >= MAX_UNLOCKING_CHUNKS {
+            return Ok(false);
+        }
+        let active_balance = ledger.active;
+        Self::unbond_balance(controller, &mut ledger, active_balance);
+        let unbonded = active_balance - ledger.active;
+        // Free the nominator from the valid nominator list
+        <Nominators<T>>::remove(stash);
+        <FlaggedNominators<T>>::remove(stash);
+        <SuspendedNominations<T>>::insert(stash, (nominations.targets, unbonded));
+        Ok(true)
+    }
+
     /// The total balance that can be slashed from a stash account as of right now.
     pub fn slashable_balance_of(stash: &T::AccountId) -> BalanceOf<T> {
         Self::bonded(stash)
@@ -1955,10 +2296,17 @@ impl<T: Trait> Module<T> {
 
     /// Chill a stash account.
     fn chill_stash(stash: &T::AccountId) {
+        Self::clear_cdd_expiry(stash);
         <Validators<T>>::remove(stash);
         <Nominators<T>>::remove(stash);
     }
 
+    /// Forgets the CDD expiry flag and the suspended nominations of a stash account.
+    fn clear_cdd_expiry(stash: &T::AccountId) {
+        <FlaggedNominators<T>>::remove(stash);
+        <SuspendedNominations<T>>::remove(stash);
+    }
+
     /// Actually make a payment to a staker. This uses the currency's reward function
     /// to pay the right payee for the given staker account.
     fn make_payout(stash: &T::AccountId, amount: BalanceOf<T>) -> Option<PositiveImbalanceOf<T>> {
@@ -1970,16 +2318,74 @@ impl<T: Trait> Module<T> {
             RewardDestination::Stash => {
                 <T as Trait>::Currency::deposit_into_existing(stash, amount).ok()
             }
-            RewardDestination::Staked => Self::bonded(stash)
-                .and_then(|c| Self::ledger(&c).map(|l| (c, l)))
-                .and_then(|(controller, mut l)| {
-                    l.active += amount;
-                    l.total += amount;
-                    let r = <T as Trait>::Currency::deposit_into_existing(stash, amount).ok();
-                    Self::update_ledger(&controller, &l);
-                    r
-                }),
+            RewardDestination::Staked => Self::make_staked_payout(stash, amount),
+            // The identity of the stash may have changed since the payee was set, in which case
+            // the reward falls back to the stash.
+            RewardDestination::IdentityBalance => match Self::identity_of(stash) {
+                Some(did) => {
+                    <T as Trait>::Currency::deposit_into_existing_identity(&did, amount).ok()
+                }
+                None => <T as Trait>::Currency::deposit_into_existing(stash, amount).ok(),
+            },
+            RewardDestination::Account(account) => {
+                let payee = match Self::identity_of(stash) {
+                    Some(did) if Self::identity_of(&account) == Some(did) => &account,
+                    _ => stash,
+                };
+                <T as Trait>::Currency::deposit_into_existing(payee, amount).ok()
+            }
+            RewardDestination::Compound(threshold) if amount >= threshold => {
+                Self::make_staked_payout(stash, amount)
+            }
+            RewardDestination::Compound(_) => {
+                <T as Trait>::Currency::deposit_into_existing(stash, amount).ok()
+            }
+        }
+    }
+
+    /// Pays into the stash account and increases the amount at stake accordingly.
+    fn make_staked_payout(
+        stash: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Option<PositiveImbalanceOf<T>> {
+        Self::bonded(stash)
+            .and_then(|c| Self::ledger(&c).map(|l| (c, l)))
+            .and_then(|(controller, mut l)| {
+                l.active += amount;
+                l.total += amount;
+                let r = <T as Trait>::Currency::deposit_into_existing(stash, amount).ok();
+                Self::update_ledger(&controller, &l);
+                r
+            })
+    }
+
+    /// Ensures that the reward destination `payee` of `stash` is paid within the identity of the
+    /// stash owner.
+    fn ensure_valid_payee(
+        stash: &T::AccountId,
+        payee: &RewardDestination<T::AccountId, BalanceOf<T>>,
+    ) -> DispatchResult {
+        match payee {
+            RewardDestination::IdentityBalance => {
+                Self::identity_of(stash).ok_or(Error::<T>::MissingIdentity)?;
+            }
+            RewardDestination::Account(account) => {
+                let did = Self::identity_of(stash).ok_or(Error::<T>::MissingIdentity)?;
+                ensure!(
+                    Self::identity_of(account) == Some(did),
+                    Error::<T>::InvalidPayee
+                );
+            }
+            _ => {}
         }
+        Ok(())
+    }
+
+    /// Returns the identity of the account `who`, if any.
+    fn identity_of(who: &T::AccountId) -> Option<IdentityId> {
+        AccountKey::try_from(who.encode())
+            .ok()
+            .and_then(|key| <identity::Module<T>>::get_identity(&key))
     }
 
     /// Plan a new session potentially trigger a new era.
@@ -2166,6 +2572,7 @@ impl<T: Trait> Module<T> {
         let mut all_validators_and_prefs = BTreeMap::new();
         let mut all_validators = Vec::new();
         Self::refresh_compliance_statuses();
+        Self::refresh_nominator_statuses();
 
         // Select only valid validators who has bond minimum balance and has the cdd compliant
         for (validator, preference) in <Validators<T>>::enumerate() {
@@ -2199,6 +2606,13 @@ impl<T: Trait> Module<T> {
             });
         all_nominators.extend(nominator_votes);
 
+        // Enforce the caps per operator identity and per jurisdiction.
+        let diversified = Self::diversify_candidates(all_validators, &all_nominators);
+        all_nominators
+            .retain(|(voter, targets)| targets != &[voter.clone()] || diversified.contains(voter));
+        all_validators_and_prefs.retain(|validator, _| diversified.contains(validator));
+        let all_validators = diversified;
+
         let maybe_phragmen_result = sp_phragmen::elect::<_, _, _, T::CurrencyToVote, Perbill>(
             Self::validator_count() as usize,
             Self::minimum_validator_count().max(1) as usize,
@@ -2292,6 +2706,107 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Selects the validator candidates within the caps per operator identity and per
+    /// jurisdiction. Candidates are considered in decreasing order of approval stake, which is
+    /// the sum of the slashable balances of all the `voters` voting for them, and skipped if a cap
+    /// has been reached.
+    fn diversify_candidates(
+        candidates: Vec<T::AccountId>,
+        voters: &[(T::AccountId, Vec<T::AccountId>)],
+    ) -> Vec<T::AccountId> {
+        let max_per_identity = Self::max_validators_per_identity();
+        let diversity = Self::jurisdiction_diversity();
+        if max_per_identity == 0 && diversity.is_none() {
+            return candidates;
+        }
+
+        let mut approvals = candidates
+            .into_iter()
+            .map(|candidate| (candidate, BalanceOf::<T>::zero()))
+            .collect::<BTreeMap<_, _>>();
+        for (voter, targets) in voters {
+            let stake = Self::slashable_balance_of(voter);
+            for target in targets {
+                if let Some(approval) = approvals.get_mut(target) {
+                    *approval = approval.saturating_add(stake);
+                }
+            }
+        }
+        let mut candidates = approvals.into_iter().collect::<Vec<_>>();
+        // The sort is stable, so candidates with equal approval stay ordered by account.
+        candidates.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        let mut per_identity = BTreeMap::new();
+        let mut per_jurisdiction = BTreeMap::new();
+        candidates
+            .into_iter()
+            .map(|(candidate, _)| candidate)
+            .filter(|candidate| {
+                let did = match AccountKey::try_from(candidate.encode())
+                    .ok()
+                    .and_then(|key| <identity::Module<T>>::get_identity(&key))
+                {
+                    Some(did) => did,
+                    None => return false,
+                };
+                let jurisdiction = match &diversity {
+                    Some(diversity) => match Self::jurisdiction_of(did, diversity.issuer) {
+                        Some(jurisdiction) => Some((jurisdiction, diversity.max_validators)),
+                        None => return false,
+                    },
+                    None => None,
+                };
+                let identity_count = per_identity.entry(did).or_insert(0u32);
+                if max_per_identity != 0 && *identity_count >= max_per_identity {
+                    return false;
+                }
+                if let Some((jurisdiction, max_validators)) = jurisdiction {
+                    let jurisdiction_count = per_jurisdiction.entry(jurisdiction).or_insert(0u32);
+                    if *jurisdiction_count >= max_validators {
+                        return false;
+                    }
+                    *jurisdiction_count += 1;
+                }
+                *identity_count += 1;
+                true
+            })
+            .collect()
+    }
+
+    /// Returns the jurisdiction of `did` attested by a `Jurisdiction` claim of `issuer`.
+    fn jurisdiction_of(did: IdentityId, issuer: IdentityId) -> Option<JurisdictionName> {
+        <identity::Module<T>>::fetch_claims_with_issuer(did, ClaimType::Jurisdiction, issuer)
+            .into_iter()
+            .filter_map(|id_claim| match id_claim.claim {
+                Claim::Jurisdiction(jurisdiction, _) => Some(jurisdiction),
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Ensures that `did` operates fewer permissioned validators than the maximum per identity.
+    fn ensure_below_validators_per_identity(did: IdentityId) -> DispatchResult {
+        let max_per_identity = Self::max_validators_per_identity();
+        ensure!(
+            max_per_identity == 0 || Self::validators_per_identity(did) < max_per_identity,
+            Error::<T>::TooManyValidatorsPerIdentity
+        );
+        Ok(())
+    }
+
+    /// Counts the permissioned validator `stash` for the identity `did`.
+    fn count_validator(stash: &T::AccountId, did: IdentityId) {
+        <ValidatorsPerIdentity>::mutate(did, |count| *count += 1);
+        <ValidatorIdentity<T>>::insert(stash, did);
+    }
+
+    /// Stops counting the permissioned validator `stash` for its identity.
+    fn uncount_validator(stash: &T::AccountId) {
+        if let Some(did) = <ValidatorIdentity<T>>::take(stash) {
+            <ValidatorsPerIdentity>::mutate(did, |count| *count = count.saturating_sub(1));
+        }
+    }
+
     /// Remove all associated data of a stash account from the staking system.
     ///
     /// Assumes storage is upgraded before calling.
@@ -2306,6 +2821,7 @@ impl<T: Trait> Module<T> {
         <Payee<T>>::remove(stash);
         <Validators<T>>::remove(stash);
         <Nominators<T>>::remove(stash);
+        Self::clear_cdd_expiry(stash);
 
         slashing::clear_stash_metadata::<T>(stash);
 
@@ -2374,16 +2890,101 @@ impl<T: Trait> Module<T> {
         for account in accounts {
             <PermissionedValidators<T>>::mutate(account.clone(), |v| {
                 if let Some(validator) = v {
-                    validator.compliance = if Self::is_validator_or_nominator_compliant(&account) {
-                        Compliance::Active
-                    } else {
-                        Compliance::Pending
+                    let compliance = match (
+                        Self::is_validator_compliant(&account),
+                        &validator.compliance,
+                    ) {
+                        (true, _) => Compliance::Active,
+                        (false, Compliance::Pending) => Compliance::Pending,
+                        (false, _) => Compliance::Suspended,
                     };
+                    let validator_id = AccountKey::try_from(account.encode())
+                        .ok()
+                        .and_then(|key| <identity::Module<T>>::get_identity(&key));
+                    match (&validator.compliance, &compliance) {
+                        (Compliance::Active, Compliance::Suspended) => Self::deposit_event(
+                            RawEvent::ValidatorSuspended(validator_id, account.clone()),
+                        ),
+                        (Compliance::Suspended, Compliance::Active) => Self::deposit_event(
+                            RawEvent::ValidatorReinstated(validator_id, account.clone()),
+                        ),
+                        _ => {}
+                    }
+                    validator.compliance = compliance;
                 }
             });
         }
     }
 
+    /// Clears the flags of nominators whose CDD claim has been renewed beyond their grace period
+    /// and restores the nominations of removed nominators that hold a valid CDD claim again,
+    /// rebonding the part of the balance unbonded at removal that is still unlocking.
+    pub fn refresh_nominator_statuses() {
+        let now = <pallet_timestamp::Module<T>>::get();
+        let renewed = <FlaggedNominators<T>>::enumerate()
+            .filter(|(stash, deadline)| {
+                Self::identity_of(stash).map_or(false, |did| {
+                    <identity::Module<T>>::fetch_cdd(did, deadline.saturating_sub(now)).is_some()
+                })
+            })
+            .map(|(stash, _)| stash)
+            .collect::<Vec<_>>();
+        for stash in renewed {
+            <FlaggedNominators<T>>::remove(stash);
+        }
+
+        let restored = <SuspendedNominations<T>>::enumerate()
+            .filter_map(|(stash, suspended)| {
+                Self::identity_of(&stash)
+                    .filter(|did| <identity::Module<T>>::has_valid_cdd(*did))
+                    .map(|did| (did, stash, suspended))
+            })
+            .collect::<Vec<_>>();
+        for (did, stash, (targets, unbonded)) in restored {
+            <SuspendedNominations<T>>::remove(&stash);
+            if let Some(controller) = Self::bonded(&stash) {
+                if let Some(ledger) = Self::ledger(&controller) {
+                    let initial_bonded = ledger.active;
+                    let ledger = ledger.rebond(unbonded);
+                    if ledger.active > initial_bonded {
+                        Self::deposit_event(RawEvent::Bonded(
+                            did,
+                            stash.clone(),
+                            ledger.active - initial_bonded,
+                        ));
+                        Self::update_ledger(&controller, &ledger);
+                    }
+                }
+            }
+            let nominations = Nominations {
+                targets: targets.clone(),
+                submitted_in: Self::current_era().unwrap_or(0),
+                suppressed: false,
+            };
+            <Nominators<T>>::insert(&stash, nominations);
+            Self::deposit_event(RawEvent::NominationsRestored(did, stash, targets));
+        }
+    }
+
+    /// Does the identity of the stash account hold a valid CDD claim and the validator claim
+    /// requirements?
+    pub fn is_validator_compliant(stash: &T::AccountId) -> bool {
+        if let Some(account_key) = AccountKey::try_from(stash.encode()).ok() {
+            if let Some(validator_identity) = <identity::Module<T>>::get_identity(&(account_key)) {
+                return <identity::Module<T>>::has_valid_cdd(validator_identity)
+                    && Self::has_validator_claims(validator_identity);
+            }
+        }
+        false
+    }
+
+    /// Does `did` hold all the validator claim requirements?
+    fn has_validator_claims(did: IdentityId) -> bool {
+        Self::validator_claim_requirements()
+            .iter()
+            .all(|requirement| <identity::Module<T>>::holds(did, requirement))
+    }
+
     /// Is the stash account one of the permissioned validators?
     pub fn is_validator_or_nominator_compliant(stash: &T::AccountId) -> bool {
         if let Some(account_key) = AccountKey::try_from(stash.encode()).ok() {
@@ -44,7 +44,7 @@ use polymesh_common_utilities::traits::{
     CommonTrait,
 };
 use primitives::traits::BlockRewardsReserveCurrency;
-use primitives::{AccountKey, Claim, IdentityId, Signatory};
+use primitives::{AccountKey, CddId, Claim, IdentityId, InvestorUid, Signatory};
 use sp_core::{
     crypto::{key_types, Pair as PairTrait},
     sr25519::Pair,
@@ -237,12 +237,18 @@ impl group::Trait<group::Instance2> for Test {
     type ResetOrigin = EnsureSignedBy<Four, AccountId>;
     type MembershipInitialized = ();
     type MembershipChanged = ();
+    type Currency = balances::Module<Test>;
+    type BallotDeposit = ();
+    type MaxVoters = ();
+    type MaxCandidates = ();
 }
 
 impl protocol_fee::Trait for Test {
     type Event = ();
     type Currency = Balances;
     type OnProtocolFeePayment = ();
+    type OnValidatorsFeeShare = ();
+    type PriceFeed = ();
 }
 
 impl IdentityTrait for Test {
@@ -256,10 +262,15 @@ impl IdentityTrait for Test {
     type Public = AccountId;
     type OffChainSignature = OffChainSignature;
     type ProtocolFee = protocol_fee::Module<Test>;
+    type Holdings = ();
 }
 
 impl pallet_transaction_payment::CddAndFeeDetails<Call> for Test {
-    fn get_valid_payer(_: &Call, _: &Signatory) -> Result<Option<Signatory>, InvalidTransaction> {
+    fn get_valid_payer(
+        _: &Call,
+        _: &Signatory,
+        _: Balance,
+    ) -> Result<Option<Signatory>, InvalidTransaction> {
         Ok(None)
     }
     fn clear_context() {}
@@ -601,42 +612,48 @@ impl ExtBuilder {
 
         let _ = identity::GenesisConfig::<Test> {
             identities: vec![
-                /// (master_account_id, service provider did, target did, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
+                /// (master_account_id, service provider did, target did, investor uid, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
                 /// Provide Identity
                 (
                     account_key_ring.get(&1005).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(1),
+                    InvestorUid::from(b"uid1"),
                     None,
                 ),
                 (
                     account_key_ring.get(&11).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(11),
+                    InvestorUid::from(b"uid11"),
                     None,
                 ),
                 (
                     account_key_ring.get(&21).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(21),
+                    InvestorUid::from(b"uid21"),
                     None,
                 ),
                 (
                     account_key_ring.get(&31).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(31),
+                    InvestorUid::from(b"uid31"),
                     None,
                 ),
                 (
                     account_key_ring.get(&41).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(41),
+                    InvestorUid::from(b"uid41"),
                     None,
                 ),
                 (
                     account_key_ring.get(&101).unwrap().clone(),
                     IdentityId::from(1),
                     IdentityId::from(101),
+                    InvestorUid::from(b"uid101"),
                     None,
                 ),
             ],
@@ -874,7 +891,7 @@ pub fn add_nominator_claim(
     assert_ok!(Identity::add_claim(
         signed_claim_issuer_id,
         idendity_id,
-        Claim::CustomerDueDiligence,
+        Claim::CustomerDueDiligence(make_cdd_id(idendity_id.as_bytes())),
         Some((now.timestamp() as u64 + 10000_u64).into()),
     ));
 }
@@ -889,7 +906,7 @@ pub fn add_nominator_claim_with_expiry(
     assert_ok!(Identity::add_claim(
         signed_claim_issuer_id,
         idendity_id,
-        Claim::CustomerDueDiligence,
+        Claim::CustomerDueDiligence(make_cdd_id(idendity_id.as_bytes())),
         Some(expiry.into()),
     ));
 }
@@ -915,11 +932,17 @@ pub fn fix_nominator_genesis(cdd_sp: IdentityId, did: IdentityId, acc: u64) {
     );
 }
 
+/// It builds the CDD commitment of a test investor, using `seed` as its unique ID.
+pub fn make_cdd_id<S: AsRef<[u8]>>(seed: S) -> CddId {
+    CddId::new(InvestorUid::from(seed))
+}
+
 pub fn create_did_and_add_claim(stash: AccountId) {
     Balances::make_free_balance_be(&account_from(1005), 1_000_000);
     assert_ok!(Identity::cdd_register_did(
         Origin::signed(account_from(1005)),
         stash,
+        make_cdd_id(stash),
         None,
         vec![]
     ));
@@ -930,6 +953,7 @@ pub fn create_did_and_add_claim_with_expiry(stash: AccountId, expiry: u64) {
     assert_ok!(Identity::cdd_register_did(
         Origin::signed(account_from(1005)),
         stash,
+        make_cdd_id(stash),
         Some(expiry.into()),
         vec![]
     ));
@@ -4120,6 +4120,232 @@ fn should_remove_permissioned_validators() {
         });
 }
 
+#[test]
+fn should_onboard_validators_by_application() {
+    ExtBuilder::default().build().execute_with(|| {
+        let controller = account_from(50);
+        let stash = account_from(51);
+        let root = Origin::system(frame_system::RawOrigin::Root);
+        let cdd_provider = Origin::signed(account_from(1005));
+        let metadata = ValidatorMetadata {
+            legal_entity: b"Validator Operator Inc.".to_vec(),
+            jurisdiction: JurisdictionName::from("CA"),
+            hosting_location: b"Montreal".to_vec(),
+        };
+        let apply = || Staking::apply_as_validator(Origin::signed(stash), metadata.clone());
+
+        // Only bonded stashes linked to an identity can apply.
+        assert_noop!(apply(), Error::<Test>::NotStash);
+        let _ = Balances::make_free_balance_be(&stash, 1_000);
+        assert_ok!(Staking::bond(
+            Origin::signed(stash),
+            controller,
+            1_000,
+            RewardDestination::Controller
+        ));
+        assert_noop!(apply(), Error::<Test>::MissingIdentity);
+        create_did_and_add_claim(stash);
+        let operator =
+            Identity::get_identity(&AccountKey::try_from(stash.encode()).unwrap()).unwrap();
+
+        // The operator must hold the required claims.
+        assert_ok!(Staking::set_validator_claim_requirements(
+            Origin::signed(account_from(3000)),
+            vec![ClaimRequirement {
+                claim_type: ClaimType::KnowYourCustomer,
+                issuer: IdentityId::from(1),
+                scope: Some(IdentityId::from(1)),
+            }]
+        ));
+        assert_noop!(apply(), Error::<Test>::NotCompliant);
+        let kyc = primitives::Claim::KnowYourCustomer(IdentityId::from(1));
+        assert_ok!(Identity::add_claim(
+            cdd_provider.clone(),
+            operator,
+            kyc.clone(),
+            None
+        ));
+
+        // Rejected applicants can apply again.
+        assert_ok!(apply());
+        assert_noop!(apply(), Error::<Test>::AlreadyExists);
+        assert_ok!(Staking::reject_validator_application(
+            Origin::signed(account_from(2000)),
+            stash
+        ));
+        assert_eq!(Staking::validator_applications(&stash), None);
+        assert_ok!(apply());
+        assert_eq!(
+            Staking::validator_applications(&stash),
+            Some(ValidatorApplication {
+                operator,
+                bond: 1_000,
+                metadata: metadata.clone(),
+            })
+        );
+
+        // The committee approves the application if the bond still meets the threshold.
+        assert_noop!(
+            Staking::approve_validator_application(Origin::signed(stash), stash),
+            Error::<Test>::NotAuthorised
+        );
+        assert_ok!(Staking::set_min_bond_threshold(
+            Origin::signed(account_from(4000)),
+            500
+        ));
+        assert_ok!(Staking::unbond(Origin::signed(controller), 600));
+        assert_noop!(
+            Staking::approve_validator_application(root.clone(), stash),
+            Error::<Test>::InsufficientValue
+        );
+        assert_ok!(Staking::rebond(Origin::signed(controller), 600));
+        assert_ok!(Staking::approve_validator_application(root, stash));
+        assert_eq!(Staking::validator_applications(&stash), None);
+        assert_eq!(
+            Staking::validator_operators(&stash).unwrap().operator,
+            operator
+        );
+        assert_eq!(Staking::validators_per_identity(operator), 1);
+        assert_eq!(
+            Staking::permissioned_validators(&stash).unwrap().compliance,
+            Compliance::Pending
+        );
+        Staking::refresh_compliance_statuses();
+        assert!(Staking::is_validator_cdd_compliant(&stash));
+        assert!(!Staking::fetch_invalid_validators(0).contains(&stash));
+        assert_ok!(Staking::unsafe_suspend_invalid_validators(vec![stash]));
+        assert!(Staking::is_validator_cdd_compliant(&stash));
+
+        // Lapsed claims suspend the validator until they are renewed.
+        assert_ok!(Identity::revoke_claim(
+            cdd_provider.clone(),
+            operator,
+            kyc.clone()
+        ));
+        assert!(Staking::fetch_invalid_validators(0).contains(&stash));
+        assert_ok!(Staking::unsafe_suspend_invalid_validators(vec![stash]));
+        assert!(!Staking::fetch_invalid_validators(0).contains(&stash));
+        Staking::refresh_compliance_statuses();
+        assert_eq!(
+            Staking::permissioned_validators(&stash).unwrap().compliance,
+            Compliance::Suspended
+        );
+        assert!(!Staking::is_validator_cdd_compliant(&stash));
+        assert_ok!(Identity::add_claim(cdd_provider, operator, kyc, None));
+        Staking::refresh_compliance_statuses();
+        assert!(Staking::is_validator_cdd_compliant(&stash));
+
+        // Removed validators no longer count towards the cap of their operator.
+        assert_ok!(Staking::remove_permissioned_validator(
+            Origin::signed(account_from(2000)),
+            stash
+        ));
+        assert_eq!(Staking::validators_per_identity(operator), 0);
+    });
+}
+
+#[test]
+fn should_cap_validators_per_identity_and_jurisdiction() {
+    ExtBuilder::default()
+        .validator_count(3)
+        .build()
+        .execute_with(|| {
+            let root = || Origin::system(frame_system::RawOrigin::Root);
+            let cdd_provider = Origin::signed(account_from(1005));
+            let join = |did: u128, acc: AccountId| {
+                Identity::unsafe_join_identity(
+                    IdentityId::from(did),
+                    primitives::Signatory::from(AccountKey::try_from(acc.encode()).unwrap()),
+                )
+            };
+            let (acc_11, acc_21, acc_31, acc_51) = (
+                account_from(11),
+                account_from(21),
+                account_from(31),
+                account_from(51),
+            );
+
+            // Stash 51 is operated by the identity of stash 21.
+            let _ = Balances::make_free_balance_be(&acc_51, 1500);
+            assert_ok!(Staking::bond(
+                Origin::signed(acc_51),
+                account_from(50),
+                1500,
+                RewardDestination::Controller
+            ));
+            assert_ok!(join(21, acc_51));
+            assert_ok!(Staking::add_permissioned_validator(root(), acc_51));
+            assert_ok!(Staking::validate(
+                Origin::signed(account_from(50)),
+                ValidatorPrefs::default()
+            ));
+
+            let voters = vec![
+                (acc_11, vec![acc_11]),
+                (acc_21, vec![acc_21]),
+                (acc_31, vec![acc_31]),
+                (acc_51, vec![acc_51]),
+                (account_from(101), vec![acc_11, acc_21]),
+            ];
+            let diversify = || {
+                let mut elected =
+                    Staking::diversify_candidates(vec![acc_11, acc_21, acc_31, acc_51], &voters);
+                elected.sort();
+                elected
+            };
+            let sorted = |mut accounts: Vec<AccountId>| {
+                accounts.sort();
+                accounts
+            };
+            assert_eq!(diversify(), sorted(vec![acc_11, acc_21, acc_31, acc_51]));
+
+            // The candidate with the lower approval stake of the same operator is dropped.
+            assert_noop!(
+                Staking::set_max_validators_per_identity(Origin::signed(acc_11), 1),
+                BadOrigin
+            );
+            assert_ok!(Staking::set_max_validators_per_identity(root(), 1));
+            assert_eq!(diversify(), sorted(vec![acc_11, acc_21, acc_31]));
+            start_era(1);
+            assert!(!Session::validators().contains(&acc_51));
+            assert!(Session::validators().contains(&acc_21));
+
+            // Operators cannot add validators above the cap.
+            assert_eq!(Staking::validators_per_identity(IdentityId::from(21)), 2);
+            assert_ok!(join(11, account_from(61)));
+            assert_noop!(
+                Staking::add_permissioned_validator(root(), account_from(61)),
+                Error::<Test>::TooManyValidatorsPerIdentity
+            );
+
+            // Only one validator per jurisdiction, and only candidates with a jurisdiction.
+            for (did, jurisdiction) in &[(11, "CA"), (21, "CA"), (31, "US")] {
+                assert_ok!(Identity::add_claim(
+                    cdd_provider.clone(),
+                    IdentityId::from(*did),
+                    Claim::Jurisdiction(JurisdictionName::from(jurisdiction), IdentityId::from(1)),
+                    None
+                ));
+            }
+            assert_ok!(Staking::set_jurisdiction_diversity(
+                root(),
+                Some(JurisdictionDiversity {
+                    max_validators: 1,
+                    issuer: IdentityId::from(1),
+                })
+            ));
+            assert_eq!(diversify(), sorted(vec![acc_21, acc_31]));
+            assert_ok!(Staking::set_jurisdiction_diversity(
+                root(),
+                Some(JurisdictionDiversity {
+                    max_validators: 1,
+                    issuer: IdentityId::from(2),
+                })
+            ));
+            assert!(diversify().is_empty());
+        });
+}
+
 #[test]
 #[ignore]
 fn new_era_respects_block_rewards_reserve() {
@@ -4291,3 +4517,142 @@ fn check_whether_nominator_selected_or_not_when_its_cdd_claim_expired() {
             );
         });
 }
+
+#[test]
+fn should_flag_and_restore_cdd_expired_nominators() {
+    ExtBuilder::default().build().execute_with(|| {
+        let root = || Origin::system(frame_system::RawOrigin::Root);
+        let (stash, controller) = (account_from(501), account_from(500));
+        let targets = vec![account_from(11), account_from(21)];
+        Timestamp::set_timestamp(10);
+
+        let _ = Balances::make_free_balance_be(&stash, 2000);
+        assert_ok!(Staking::bond(
+            Origin::signed(stash),
+            controller,
+            1000,
+            RewardDestination::Controller
+        ));
+        create_did_and_add_claim_with_expiry(stash, 200);
+        let did = Identity::get_identity(&AccountKey::try_from(stash.encode()).unwrap()).unwrap();
+        assert_ok!(Staking::nominate(
+            Origin::signed(controller),
+            targets.clone()
+        ));
+
+        assert_noop!(
+            Staking::set_nominator_cdd_grace_period(Origin::signed(stash), 50),
+            BadOrigin
+        );
+        assert_ok!(Staking::set_nominator_cdd_grace_period(root(), 50));
+
+        // The nominator is flagged first and stays a nominator during the grace period.
+        assert_ok!(Staking::unsafe_validate_cdd_expiry_nominators(vec![stash]));
+        assert_eq!(Staking::flagged_nominators(stash), Some(60));
+        assert!(Staking::nominators(stash).is_some());
+        Timestamp::set_timestamp(59);
+        assert_ok!(Staking::unsafe_validate_cdd_expiry_nominators(vec![stash]));
+        assert!(Staking::nominators(stash).is_some());
+
+        // The nominator is removed once the grace period has elapsed.
+        Timestamp::set_timestamp(60);
+        assert_ok!(Staking::unsafe_validate_cdd_expiry_nominators(vec![stash]));
+        assert!(Staking::nominators(stash).is_none());
+        assert_eq!(Staking::flagged_nominators(stash), None);
+        assert_eq!(
+            Staking::suspended_nominations(stash),
+            Some((targets.clone(), 1000))
+        );
+        assert_eq!(Staking::ledger(controller).unwrap().active, 0);
+
+        // Nominations are not restored while the CDD claim is expired.
+        Timestamp::set_timestamp(300);
+        Staking::refresh_nominator_statuses();
+        assert!(Staking::nominators(stash).is_none());
+
+        // Nominations are restored once the CDD claim is renewed.
+        assert_ok!(Identity::add_claim(
+            Origin::signed(account_from(1005)),
+            did,
+            Claim::CustomerDueDiligence(make_cdd_id(stash)),
+            Some(1000),
+        ));
+        Staking::refresh_nominator_statuses();
+        assert_eq!(Staking::nominators(stash).unwrap().targets, targets);
+        assert_eq!(Staking::suspended_nominations(stash), None);
+
+        // The balance unbonded at removal is bonded again.
+        let ledger = Staking::ledger(controller).unwrap();
+        assert_eq!(ledger.active, 1000);
+        assert!(ledger.unlocking.is_empty());
+    });
+}
+
+#[test]
+fn should_pay_rewards_within_identity() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (stash, controller, key) = (account_from(501), account_from(500), account_from(503));
+        let _ = Balances::make_free_balance_be(&stash, 2000);
+        let _ = Balances::make_free_balance_be(&key, 1);
+
+        // Rewards cannot be paid to the identity balance of a stash without an identity.
+        assert_noop!(
+            Staking::bond(
+                Origin::signed(stash),
+                controller,
+                1000,
+                RewardDestination::IdentityBalance
+            ),
+            Error::<Test>::MissingIdentity
+        );
+        assert_ok!(Staking::bond(
+            Origin::signed(stash),
+            controller,
+            1000,
+            RewardDestination::Stash
+        ));
+        create_did_and_add_claim(stash);
+        let did = Staking::identity_of(&stash).unwrap();
+
+        assert_ok!(Staking::set_payee(
+            Origin::signed(controller),
+            RewardDestination::IdentityBalance
+        ));
+        let _ = Staking::make_payout(&stash, 100);
+        assert_eq!(Balances::identity_balance(did), 100);
+
+        // Rewards can only be paid to accounts of the same identity.
+        assert_noop!(
+            Staking::set_payee(Origin::signed(controller), RewardDestination::Account(key)),
+            Error::<Test>::InvalidPayee
+        );
+        assert_ok!(Identity::unsafe_join_identity(
+            did,
+            primitives::Signatory::from(AccountKey::try_from(key.encode()).unwrap())
+        ));
+        assert_ok!(Staking::set_payee(
+            Origin::signed(controller),
+            RewardDestination::Account(key)
+        ));
+        let _ = Staking::make_payout(&stash, 100);
+        assert_eq!(Balances::free_balance(&key), 101);
+
+        // Rewards fall back to the stash once the account leaves the identity.
+        assert_ok!(Identity::leave_identity_as_key(Origin::signed(key)));
+        let _ = Staking::make_payout(&stash, 100);
+        assert_eq!(Balances::free_balance(&key), 101);
+        assert_eq!(Balances::free_balance(&stash), 2100);
+
+        // Rewards are bonded once they reach the threshold.
+        assert_ok!(Staking::set_payee(
+            Origin::signed(controller),
+            RewardDestination::Compound(50)
+        ));
+        let _ = Staking::make_payout(&stash, 40);
+        assert_eq!(Staking::ledger(&controller).unwrap().active, 1000);
+        assert_eq!(Balances::free_balance(&stash), 2140);
+        let _ = Staking::make_payout(&stash, 60);
+        assert_eq!(Staking::ledger(&controller).unwrap().active, 1060);
+        assert_eq!(Balances::free_balance(&stash), 2200);
+    });
+}
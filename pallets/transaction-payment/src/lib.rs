@@ -42,7 +42,9 @@ use frame_support::{
     weights::{DispatchInfo, GetDispatchInfo, Weight},
 };
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
-use primitives::{traits::IdentityCurrency, AccountKey, IdentityId, Signatory, TransactionError};
+use primitives::{
+    traits::IdentityCurrency, AccountKey, Balance, IdentityId, Signatory, TransactionError,
+};
 use sp_runtime::{
     traits::{Convert, SaturatedConversion, Saturating, SignedExtension, Zero},
     transaction_validity::{
@@ -244,9 +246,11 @@ where
         let encoded_transactor =
             AccountKey::try_from(who.encode()).map_err(|_| InvalidTransaction::BadProof)?;
         let fee = Self::compute_fee(len as u32, info, 0u32.into());
-        if let Some(payer) =
-            T::CddHandler::get_valid_payer(call, &Signatory::from(encoded_transactor))?
-        {
+        if let Some(payer) = T::CddHandler::get_valid_payer(
+            call,
+            &Signatory::from(encoded_transactor),
+            fee.saturated_into::<Balance>(),
+        )? {
             let imbalance;
             match payer {
                 Signatory::AccountKey(key) => {
@@ -286,6 +290,7 @@ pub trait CddAndFeeDetails<Call> {
     fn get_valid_payer(
         call: &Call,
         caller: &Signatory,
+        fee: Balance,
     ) -> Result<Option<Signatory>, InvalidTransaction>;
     fn clear_context();
     fn set_payer_context(payer: Option<Signatory>);
@@ -470,6 +475,7 @@ mod tests {
         fn get_valid_payer(
             _: &Call,
             caller: &Signatory,
+            _: Balance,
         ) -> Result<Option<Signatory>, InvalidTransaction> {
             Ok(Some(*caller))
         }
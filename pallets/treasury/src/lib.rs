@@ -25,10 +25,44 @@
 //!
 //! The disbursement mechanism is designed to incentivize Polymesh Improvement Proposals.
 //!
+//! ### Grants
+//!
+//! An identity can propose a grant that is paid out in milestones. The proposer reserves a
+//! `GrantDeposit`, which is returned once the grant is rejected, completed or failed. The length of
+//! descriptions and the number of milestones are capped. Once the governance committee
+//! approves a grant, its total amount is committed and it cannot be disbursed for other purposes.
+//! Milestones are paid out in order, each one after the committee or the reviewer of the grant
+//! confirms its delivery. If the committee fails a grant, the amount of its unreleased milestones
+//! is clawed back into the available treasury balance.
+//!
+//! ### Budget periods
+//!
+//! Time is split in budget periods of `BudgetPeriod` blocks. The governance committee can cap the
+//! amount disbursed per period, and the cap applies to every disbursement, including PIP
+//! beneficiaries, grant milestones and recurring payments. The spending of each period is
+//! recorded per beneficiary. Changing the period length does not re-number past periods: the
+//! current period restarts at the block of the change and later periods follow with the new
+//! length.
+//!
+//! ### Recurring payments
+//!
+//! The governance committee can schedule a payment to an identity that repeats every `interval`
+//! blocks from `start` until `end`. A payment that exceeds the budget or the available balance,
+//! or that cannot be deposited to the beneficiary, is skipped and the next one stays scheduled.
+//!
 //! ## Dispatchable Functions
 //!
 //! - [disbursement](Module::disbursement) - Transfers from the treasury to the given benericiaries.
 //! - [reimbursement](Module::reimbursement) - Transfers to the treasury.
+//! - [propose_grant](Module::propose_grant) - Proposes a grant paid out in milestones.
+//! - [approve_grant](Module::approve_grant) - Approves a grant and commits its funds.
+//! - [reject_grant](Module::reject_grant) - Rejects a proposed grant.
+//! - [release_milestone](Module::release_milestone) - Pays out the next milestone of a grant.
+//! - [fail_grant](Module::fail_grant) - Fails a grant and claws back its unreleased funds.
+//! - [set_budget](Module::set_budget) - Sets the budget period and its spending cap.
+//! - [schedule_recurring_payment](Module::schedule_recurring_payment) - Schedules a recurring
+//! payment.
+//! - [cancel_recurring_payment](Module::cancel_recurring_payment) - Cancels a recurring payment.
 //!
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
@@ -41,19 +75,131 @@ use polymesh_common_utilities::{
 };
 use polymesh_primitives::{traits::IdentityCurrency, AccountKey, Beneficiary, IdentityId};
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::{
-    decl_error, decl_event, decl_module,
+    decl_error, decl_event, decl_module, decl_storage,
     dispatch::DispatchResult,
     ensure,
-    traits::{Currency, ExistenceRequirement, Imbalance, OnUnbalanced, WithdrawReason},
+    traits::{
+        Currency, EnsureOrigin, ExistenceRequirement, Get, Imbalance, OnUnbalanced,
+        ReservableCurrency, WithdrawReason,
+    },
 };
 use frame_system::{self as system, ensure_root, ensure_signed};
-use sp_runtime::traits::{AccountIdConversion, Saturating};
+use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Saturating, Zero};
 use sp_std::{convert::TryFrom, prelude::*};
 
+#[cfg(feature = "std")]
+use serde_derive::{Deserialize, Serialize};
+
 pub type ProposalIndex = u32;
 
+/// The identifier of a grant.
+pub type GrantId = u32;
+
+/// The index of a budget period.
+pub type BudgetPeriodIndex = u32;
+
+/// The identifier of a recurring payment.
+pub type RecurringPaymentId = u32;
+
+/// The spending of a budget period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SpendReport<Balance> {
+    /// The budget period.
+    pub period: BudgetPeriodIndex,
+    /// The total amount disbursed during the period.
+    pub total: Balance,
+    /// The amount disbursed to each beneficiary during the period.
+    pub beneficiaries: Vec<(IdentityId, Balance)>,
+}
+
+/// A payment to an identity that repeats every `interval` blocks from `start` until `end`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RecurringPayment<Balance, BlockNumber> {
+    /// The identity that receives the payments.
+    pub beneficiary: IdentityId,
+    /// The amount of each payment.
+    pub amount: Balance,
+    /// The block of the first payment.
+    pub start: BlockNumber,
+    /// The number of blocks between payments.
+    pub interval: BlockNumber,
+    /// No payment is made after this block.
+    pub end: BlockNumber,
+    /// The block of the next payment.
+    pub next: BlockNumber,
+}
+
+/// A deliverable of a grant and the amount paid out on its delivery.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct Milestone<Balance> {
+    /// The description of the deliverable.
+    pub description: Vec<u8>,
+    /// The amount paid out when the milestone is released.
+    pub amount: Balance,
+}
+
+/// The status of a grant.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum GrantStatus {
+    /// Waiting for the approval of the governance committee.
+    Proposed,
+    /// Approved, its milestones are being released.
+    Approved,
+    /// Rejected by the governance committee.
+    Rejected,
+    /// All its milestones were released.
+    Completed,
+    /// Failed by the governance committee, its unreleased funds were clawed back.
+    Failed,
+}
+
+impl Default for GrantStatus {
+    fn default() -> Self {
+        GrantStatus::Proposed
+    }
+}
+
+/// A treasury grant paid out in milestones.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct Grant<Balance> {
+    /// The identity that proposed the grant and receives its payouts.
+    pub beneficiary: IdentityId,
+    /// The description of the grant.
+    pub description: Vec<u8>,
+    /// The milestones of the grant, released in order.
+    pub milestones: Vec<Milestone<Balance>>,
+    /// The number of released milestones.
+    pub released: u32,
+    /// The identity that can confirm the delivery of milestones, besides the committee.
+    pub reviewer: Option<IdentityId>,
+    /// The status of the grant.
+    pub status: GrantStatus,
+}
+
+impl<Balance: Copy + Saturating + Zero> Grant<Balance> {
+    /// The total amount of the grant.
+    pub fn total(&self) -> Balance {
+        self.milestones
+            .iter()
+            .fold(Zero::zero(), |acc: Balance, m| acc.saturating_add(m.amount))
+    }
+
+    /// The amount of the milestones that have not been released.
+    pub fn unreleased(&self) -> Balance {
+        self.milestones
+            .iter()
+            .skip(self.released as usize)
+            .fold(Zero::zero(), |acc: Balance, m| acc.saturating_add(m.amount))
+    }
+}
+
 type Identity<T> = identity::Module<T>;
 type BalanceOf<T> =
     <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
@@ -64,18 +210,60 @@ pub trait Trait: frame_system::Trait + CommonTrait + BalancesTrait + IdentityTra
     // The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
     /// The native currency.
-    type Currency: Currency<Self::AccountId> + IdentityCurrency<Self::AccountId>;
+    type Currency: ReservableCurrency<Self::AccountId> + IdentityCurrency<Self::AccountId>;
+    /// Origin that approves, rejects and fails grants.
+    type CommitteeOrigin: EnsureOrigin<Self::Origin>;
+    /// The amount reserved from the proposer of a grant until the grant is closed.
+    type GrantDeposit: Get<BalanceOf<Self>>;
+    /// The maximum length in bytes of the description of a grant or of a milestone.
+    type MaxGrantDescriptionLength: Get<u32>;
+    /// The maximum number of milestones of a grant.
+    type MaxMilestones: Get<u32>;
 }
 
 pub trait TreasuryTrait<Balance> {
-    fn disbursement(target: IdentityId, amount: Balance);
+    fn disbursement(target: IdentityId, amount: Balance) -> DispatchResult;
     fn balance() -> Balance;
 }
 
+decl_storage! {
+    trait Store for Module<T: Trait> as Treasury {
+        /// The number of grants proposed so far.
+        pub GrantCount get(fn grant_count): GrantId;
+        /// The grants.
+        /// grant id -> grant
+        pub Grants get(fn grants): map hasher(twox_64_concat) GrantId => Option<Grant<BalanceOf<T>>>;
+        /// The deposits of grants that have not been closed yet.
+        /// grant id -> (proposer, deposit)
+        pub GrantDeposits get(fn grant_deposits): map hasher(twox_64_concat) GrantId => Option<(T::AccountId, BalanceOf<T>)>;
+        /// The funds of approved grants that have not been released yet.
+        pub CommittedFunds get(fn committed_funds): BalanceOf<T>;
+        /// The length in blocks of a budget period. Zero means a single period.
+        pub BudgetPeriod get(fn budget_period): T::BlockNumber;
+        /// The block from which the current budget period length applies and the index of the
+        /// budget period that starts at that block.
+        pub BudgetPeriodStart get(fn budget_period_start): (T::BlockNumber, BudgetPeriodIndex);
+        /// The maximum amount disbursed per budget period, if any.
+        pub BudgetCap get(fn budget_cap): Option<BalanceOf<T>>;
+        /// The spending of each budget period.
+        /// budget period -> spend report
+        pub Spending get(fn spending): map hasher(twox_64_concat) BudgetPeriodIndex => SpendReport<BalanceOf<T>>;
+        /// The number of recurring payments scheduled so far.
+        pub RecurringPaymentCount get(fn recurring_payment_count): RecurringPaymentId;
+        /// The active recurring payments.
+        /// recurring payment id -> recurring payment
+        pub RecurringPayments get(fn recurring_payments): map hasher(twox_64_concat) RecurringPaymentId => Option<RecurringPayment<BalanceOf<T>, T::BlockNumber>>;
+        /// The recurring payments due at a block.
+        /// block number -> recurring payment ids
+        pub RecurringPaymentsDueAt get(fn recurring_payments_due_at): map hasher(twox_64_concat) T::BlockNumber => Vec<RecurringPaymentId>;
+    }
+}
+
 decl_event!(
     pub enum Event<T>
     where
         Balance = BalanceOf<T>,
+        BlockNumber = <T as frame_system::Trait>::BlockNumber,
     {
         /// Disbursement to a target Identity.
         /// (target identity, amount)
@@ -83,6 +271,43 @@ decl_event!(
 
         /// Treasury reimbursement.
         TreasuryReimbursement(IdentityId, Balance),
+
+        /// A grant was proposed.
+        /// (caller DID, grant id, total amount)
+        GrantProposed(IdentityId, GrantId, Balance),
+
+        /// A grant was approved and its funds committed.
+        /// (caller DID, grant id, reviewer)
+        GrantApproved(IdentityId, GrantId, Option<IdentityId>),
+
+        /// A grant was rejected.
+        /// (caller DID, grant id)
+        GrantRejected(IdentityId, GrantId),
+
+        /// A milestone of a grant was released.
+        /// (caller DID, grant id, milestone index, amount)
+        MilestoneReleased(IdentityId, GrantId, u32, Balance),
+
+        /// A grant failed and its unreleased funds were clawed back.
+        /// (caller DID, grant id, clawed back amount)
+        GrantFailed(IdentityId, GrantId, Balance),
+
+        /// The budget period or its cap changed.
+        /// (caller DID, period length, cap)
+        BudgetChanged(IdentityId, BlockNumber, Option<Balance>),
+
+        /// A recurring payment was scheduled.
+        /// (caller DID, recurring payment id, beneficiary, amount)
+        RecurringPaymentScheduled(IdentityId, RecurringPaymentId, IdentityId, Balance),
+
+        /// A recurring payment was cancelled.
+        /// (caller DID, recurring payment id)
+        RecurringPaymentCancelled(IdentityId, RecurringPaymentId),
+
+        /// A due recurring payment was skipped because it exceeds the budget or the available
+        /// balance, or because it could not be deposited to the beneficiary.
+        /// (caller DID, recurring payment id, amount)
+        RecurringPaymentSkipped(IdentityId, RecurringPaymentId, Balance),
     }
 );
 
@@ -91,6 +316,26 @@ decl_error! {
     pub enum Error for Module<T: Trait> {
         /// Proposer's balance is too low.
         InsufficientBalance,
+        /// The grant does not exist.
+        NoSuchGrant,
+        /// The grant is not in the required status.
+        IncorrectGrantStatus,
+        /// A grant needs at least one milestone.
+        NoMilestones,
+        /// A grant has more milestones than `MaxMilestones`.
+        TooManyMilestones,
+        /// A description is longer than `MaxGrantDescriptionLength`.
+        DescriptionTooLong,
+        /// Only the committee or the reviewer of the grant can release its milestones.
+        NotAReviewer,
+        /// The disbursement exceeds the budget of the current period.
+        BudgetExceeded,
+        /// The schedule of a recurring payment is invalid.
+        InvalidSchedule,
+        /// The recurring payment does not exist.
+        NoSuchRecurringPayment,
+        /// The beneficiary identity does not exist.
+        DidDoesNotExist,
     }
 }
 
@@ -100,12 +345,22 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        /// The amount reserved from the proposer of a grant until the grant is closed.
+        const GrantDeposit: BalanceOf<T> = T::GrantDeposit::get();
+
+        /// The maximum length in bytes of the description of a grant or of a milestone.
+        const MaxGrantDescriptionLength: u32 = T::MaxGrantDescriptionLength::get();
+
+        /// The maximum number of milestones of a grant.
+        const MaxMilestones: u32 = T::MaxMilestones::get();
+
         /// It transfers balances from treasury to each of beneficiaries and the specific amount
         /// for each of them.
         ///
         /// # Error
         /// * `BadOrigin`: Only root can execute transaction.
         /// * `InsufficientBalance`: If treasury balances is not enough to cover all beneficiaries.
+        /// * `DidDoesNotExist`: A beneficiary identity does not exist.
         pub fn disbursement(origin, beneficiaries: Vec<Beneficiary<BalanceOf<T>>>) -> DispatchResult
         {
             ensure_root(origin)?;
@@ -113,12 +368,17 @@ decl_module! {
             // Ensure treasury has enough balance.
             let total_amount = beneficiaries.iter().fold( 0.into(), |acc,b| b.amount.saturating_add(acc));
             ensure!(
-                Self::balance() >= total_amount,
+                Self::available_balance() >= total_amount,
                 Error::<T>::InsufficientBalance
             );
-            beneficiaries.into_iter().for_each( |b| {
-                Self::unsafe_disbursement(b.id, b.amount);
-            });
+            Self::ensure_within_budget(total_amount)?;
+            ensure!(
+                beneficiaries.iter().all(|b| <Identity<T>>::is_identity_exists(&b.id)),
+                Error::<T>::DidDoesNotExist
+            );
+            for b in beneficiaries {
+                Self::unsafe_disbursement(b.id, b.amount)?;
+            }
             Ok(())
         }
 
@@ -140,6 +400,214 @@ decl_module! {
             Self::deposit_event(RawEvent::TreasuryReimbursement(did, amount));
             Ok(())
         }
+
+        /// Proposes a grant to the identity of the caller, paid out in `milestones`. The
+        /// `GrantDeposit` is reserved from the caller until the grant is closed.
+        ///
+        /// # Error
+        /// * `NoMilestones`: A grant needs at least one milestone.
+        /// * `TooManyMilestones`: A grant has at most `MaxMilestones` milestones.
+        /// * `DescriptionTooLong`: A description is longer than `MaxGrantDescriptionLength`.
+        /// * `InsufficientBalance`: The caller cannot reserve the deposit.
+        pub fn propose_grant(
+            origin,
+            description: Vec<u8>,
+            milestones: Vec<Milestone<BalanceOf<T>>>
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let sender_key = AccountKey::try_from(sender.encode())?;
+            let did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+            ensure!(!milestones.is_empty(), Error::<T>::NoMilestones);
+            ensure!(
+                milestones.len() <= T::MaxMilestones::get() as usize,
+                Error::<T>::TooManyMilestones
+            );
+            let max_len = T::MaxGrantDescriptionLength::get() as usize;
+            ensure!(
+                description.len() <= max_len && milestones.iter().all(|m| m.description.len() <= max_len),
+                Error::<T>::DescriptionTooLong
+            );
+            let deposit = T::GrantDeposit::get();
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            let id = Self::grant_count();
+            <GrantCount>::put(id + 1);
+            let grant = Grant {
+                beneficiary: did,
+                description,
+                milestones,
+                released: 0,
+                reviewer: None,
+                status: GrantStatus::Proposed,
+            };
+            let total = grant.total();
+            <Grants<T>>::insert(id, grant);
+            <GrantDeposits<T>>::insert(id, (sender, deposit));
+            Self::deposit_event(RawEvent::GrantProposed(did, id, total));
+            Ok(())
+        }
+
+        /// Approves a proposed grant and commits its total amount. The delivery of its
+        /// milestones can be confirmed by the committee or by `reviewer`.
+        ///
+        /// # Error
+        /// * `IncorrectGrantStatus`: The grant is not waiting for approval.
+        /// * `InsufficientBalance`: The available treasury balance cannot cover the grant.
+        pub fn approve_grant(origin, id: GrantId, reviewer: Option<IdentityId>) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            let mut grant = Self::ensure_grant_status(id, GrantStatus::Proposed)?;
+            let total = grant.total();
+            ensure!(Self::available_balance() >= total, Error::<T>::InsufficientBalance);
+
+            <CommittedFunds<T>>::mutate(|committed| *committed = committed.saturating_add(total));
+            grant.reviewer = reviewer;
+            grant.status = GrantStatus::Approved;
+            <Grants<T>>::insert(id, grant);
+            Self::deposit_event(RawEvent::GrantApproved(Self::committee_did(), id, reviewer));
+            Ok(())
+        }
+
+        /// Rejects a proposed grant and returns its deposit.
+        ///
+        /// # Error
+        /// * `IncorrectGrantStatus`: The grant is not waiting for approval.
+        pub fn reject_grant(origin, id: GrantId) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            let mut grant = Self::ensure_grant_status(id, GrantStatus::Proposed)?;
+            grant.status = GrantStatus::Rejected;
+            <Grants<T>>::insert(id, grant);
+            Self::refund_grant_deposit(id);
+            Self::deposit_event(RawEvent::GrantRejected(Self::committee_did(), id));
+            Ok(())
+        }
+
+        /// Confirms the delivery of the next milestone of an approved grant and pays it out to
+        /// the beneficiary. It can be called by the committee or by the reviewer of the grant.
+        /// The deposit of the grant is returned once its last milestone is released.
+        ///
+        /// # Error
+        /// * `IncorrectGrantStatus`: The grant is not approved.
+        /// * `NotAReviewer`: The caller is neither the committee nor the reviewer of the grant.
+        pub fn release_milestone(origin, id: GrantId) -> DispatchResult {
+            let mut grant = Self::ensure_grant_status(id, GrantStatus::Approved)?;
+            let caller_did = match T::CommitteeOrigin::try_origin(origin) {
+                Ok(_) => Self::committee_did(),
+                Err(origin) => {
+                    let sender = ensure_signed(origin)?;
+                    let sender_key = AccountKey::try_from(sender.encode())?;
+                    let did = Context::current_identity_or::<Identity<T>>(&sender_key)?;
+                    ensure!(grant.reviewer == Some(did), Error::<T>::NotAReviewer);
+                    did
+                }
+            };
+
+            let index = grant.released;
+            let amount = grant.milestones[index as usize].amount;
+            Self::ensure_within_budget(amount)?;
+            Self::unsafe_disbursement(grant.beneficiary, amount)?;
+            <CommittedFunds<T>>::mutate(|committed| *committed = committed.saturating_sub(amount));
+            grant.released += 1;
+            if grant.released as usize == grant.milestones.len() {
+                grant.status = GrantStatus::Completed;
+                Self::refund_grant_deposit(id);
+            }
+            <Grants<T>>::insert(id, grant);
+            Self::deposit_event(RawEvent::MilestoneReleased(caller_did, id, index, amount));
+            Ok(())
+        }
+
+        /// Fails an approved grant. The amount of its unreleased milestones is clawed back into
+        /// the available treasury balance and its deposit is returned.
+        ///
+        /// # Error
+        /// * `IncorrectGrantStatus`: The grant is not approved.
+        pub fn fail_grant(origin, id: GrantId) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            let mut grant = Self::ensure_grant_status(id, GrantStatus::Approved)?;
+            let unreleased = grant.unreleased();
+            <CommittedFunds<T>>::mutate(|committed| *committed = committed.saturating_sub(unreleased));
+            grant.status = GrantStatus::Failed;
+            <Grants<T>>::insert(id, grant);
+            Self::refund_grant_deposit(id);
+            Self::deposit_event(RawEvent::GrantFailed(Self::committee_did(), id, unreleased));
+            Ok(())
+        }
+
+        /// Sets the length of budget periods and the maximum amount disbursed per period.
+        ///
+        /// A new length applies from the current block, which starts a new window of the current
+        /// budget period. The spending recorded for the current and past periods is kept.
+        ///
+        /// # Arguments
+        /// * `period` the length in blocks of a budget period, zero for a single period
+        /// * `cap` the maximum amount disbursed per period, `None` for no cap
+        pub fn set_budget(origin, period: T::BlockNumber, cap: Option<BalanceOf<T>>) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            if period != Self::budget_period() {
+                let current = Self::current_budget_period();
+                <BudgetPeriodStart<T>>::put((<system::Module<T>>::block_number(), current));
+                <BudgetPeriod<T>>::put(period);
+            }
+            <BudgetCap<T>>::mutate(|old| *old = cap);
+            Self::deposit_event(RawEvent::BudgetChanged(Self::committee_did(), period, cap));
+            Ok(())
+        }
+
+        /// Schedules a payment of `amount` to `beneficiary` every `interval` blocks from `start`
+        /// until `end`.
+        ///
+        /// # Error
+        /// * `InvalidSchedule`: `start` is not in the future, `interval` is zero or `end` is
+        /// before `start`.
+        /// * `DidDoesNotExist`: The beneficiary identity does not exist.
+        pub fn schedule_recurring_payment(
+            origin,
+            beneficiary: IdentityId,
+            amount: BalanceOf<T>,
+            start: T::BlockNumber,
+            interval: T::BlockNumber,
+            end: T::BlockNumber
+        ) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            ensure!(
+                start > <system::Module<T>>::block_number() && !interval.is_zero() && end >= start,
+                Error::<T>::InvalidSchedule
+            );
+            ensure!(<Identity<T>>::is_identity_exists(&beneficiary), Error::<T>::DidDoesNotExist);
+
+            let id = Self::recurring_payment_count();
+            <RecurringPaymentCount>::put(id + 1);
+            let payment = RecurringPayment {
+                beneficiary,
+                amount,
+                start,
+                interval,
+                end,
+                next: start,
+            };
+            <RecurringPayments<T>>::insert(id, payment);
+            <RecurringPaymentsDueAt<T>>::mutate(start, |ids| ids.push(id));
+            Self::deposit_event(RawEvent::RecurringPaymentScheduled(Self::committee_did(), id, beneficiary, amount));
+            Ok(())
+        }
+
+        /// Cancels a recurring payment. No further payment is made.
+        ///
+        /// # Error
+        /// * `NoSuchRecurringPayment`: The recurring payment does not exist or it has ended.
+        pub fn cancel_recurring_payment(origin, id: RecurringPaymentId) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            let payment = Self::recurring_payments(id).ok_or(Error::<T>::NoSuchRecurringPayment)?;
+            <RecurringPaymentsDueAt<T>>::mutate(payment.next, |ids| ids.retain(|due| *due != id));
+            <RecurringPayments<T>>::remove(id);
+            Self::deposit_event(RawEvent::RecurringPaymentCancelled(Self::committee_did(), id));
+            Ok(())
+        }
+
+        /// Makes the recurring payments due at block `n`.
+        fn on_initialize(n: T::BlockNumber) {
+            Self::make_recurring_payments(n);
+        }
     }
 }
 
@@ -152,33 +620,158 @@ impl<T: Trait> Module<T> {
         TREASURY_MODULE_ID.into_account()
     }
 
-    pub fn unsafe_disbursement(target: IdentityId, amount: BalanceOf<T>) {
-        let _ = T::Currency::withdraw(
-            &Self::account_id(),
+    /// Transfers `amount` from the treasury to the identity balance of `target`. If the deposit
+    /// fails, the withdrawn amount goes back to the treasury.
+    pub fn unsafe_disbursement(target: IdentityId, amount: BalanceOf<T>) -> DispatchResult {
+        let treasury = Self::account_id();
+        let withdrawn = T::Currency::withdraw(
+            &treasury,
             amount,
             WithdrawReason::Transfer.into(),
             ExistenceRequirement::AllowDeath,
-        );
-        let _ = T::Currency::deposit_into_existing_identity(&target, amount);
+        )?;
+        if let Err(e) = T::Currency::deposit_into_existing_identity(&target, amount) {
+            let _ = T::Currency::resolve_creating(&treasury, withdrawn);
+            return Err(e);
+        }
+        Self::record_spending(target, amount);
         let current_did = Context::current_identity::<Identity<T>>()
             .unwrap_or(SystematicIssuers::Committee.as_id());
         Self::deposit_event(RawEvent::TreasuryDisbursement(current_did, target, amount));
+        Ok(())
     }
 
     fn balance() -> BalanceOf<T> {
         T::Currency::free_balance(&Self::account_id())
     }
+
+    /// The treasury balance that is not committed to approved grants.
+    pub fn available_balance() -> BalanceOf<T> {
+        Self::balance().saturating_sub(Self::committed_funds())
+    }
+
+    /// The index of the current budget period.
+    pub fn current_budget_period() -> BudgetPeriodIndex {
+        let period = Self::budget_period();
+        let (start, first) = Self::budget_period_start();
+        if period.is_zero() {
+            first
+        } else {
+            let elapsed = <system::Module<T>>::block_number().saturating_sub(start) / period;
+            first.saturating_add(elapsed.saturated_into())
+        }
+    }
+
+    /// The amount that can be disbursed: the available balance, capped by the budget left in the
+    /// current period.
+    pub fn spendable_balance() -> BalanceOf<T> {
+        let available = Self::available_balance();
+        match Self::budget_cap() {
+            Some(cap) => {
+                let spent = Self::spending(Self::current_budget_period()).total;
+                available.min(cap.saturating_sub(spent))
+            }
+            None => available,
+        }
+    }
+
+    /// Retrieves the spending of the budget periods from `from` to `to`, both included. Periods
+    /// without spending are skipped.
+    pub fn spend_report(
+        from: BudgetPeriodIndex,
+        to: BudgetPeriodIndex,
+    ) -> Vec<SpendReport<BalanceOf<T>>> {
+        let mut reports = <Spending<T>>::iter()
+            .filter(|report| report.period >= from && report.period <= to)
+            .collect::<Vec<_>>();
+        reports.sort_by_key(|report| report.period);
+        reports
+    }
+
+    fn ensure_within_budget(amount: BalanceOf<T>) -> DispatchResult {
+        if let Some(cap) = Self::budget_cap() {
+            let spent = Self::spending(Self::current_budget_period()).total;
+            ensure!(
+                spent.saturating_add(amount) <= cap,
+                Error::<T>::BudgetExceeded
+            );
+        }
+        Ok(())
+    }
+
+    fn record_spending(target: IdentityId, amount: BalanceOf<T>) {
+        let period = Self::current_budget_period();
+        <Spending<T>>::mutate(period, |report| {
+            report.period = period;
+            report.total = report.total.saturating_add(amount);
+            match report
+                .beneficiaries
+                .iter_mut()
+                .find(|(id, _)| *id == target)
+            {
+                Some((_, spent)) => *spent = spent.saturating_add(amount),
+                None => report.beneficiaries.push((target, amount)),
+            }
+        });
+    }
+
+    /// Makes the recurring payments due at block `n` and schedules their next payments.
+    pub fn make_recurring_payments(n: T::BlockNumber) {
+        for id in <RecurringPaymentsDueAt<T>>::take(n) {
+            if let Some(mut payment) = Self::recurring_payments(id) {
+                let paid = Self::available_balance() >= payment.amount
+                    && Self::ensure_within_budget(payment.amount).is_ok()
+                    && Self::unsafe_disbursement(payment.beneficiary, payment.amount).is_ok();
+                if !paid {
+                    Self::deposit_event(RawEvent::RecurringPaymentSkipped(
+                        Self::committee_did(),
+                        id,
+                        payment.amount,
+                    ));
+                }
+
+                let next = n.saturating_add(payment.interval);
+                if next <= payment.end {
+                    payment.next = next;
+                    <RecurringPayments<T>>::insert(id, payment);
+                    <RecurringPaymentsDueAt<T>>::mutate(next, |ids| ids.push(id));
+                } else {
+                    <RecurringPayments<T>>::remove(id);
+                }
+            }
+        }
+    }
+
+    fn committee_did() -> IdentityId {
+        Context::current_identity::<Identity<T>>().unwrap_or(SystematicIssuers::Committee.as_id())
+    }
+
+    /// Returns the deposit of a closed grant to its proposer.
+    fn refund_grant_deposit(id: GrantId) {
+        if let Some((proposer, deposit)) = <GrantDeposits<T>>::take(id) {
+            let _ = T::Currency::unreserve(&proposer, deposit);
+        }
+    }
+
+    fn ensure_grant_status(
+        id: GrantId,
+        status: GrantStatus,
+    ) -> Result<Grant<BalanceOf<T>>, Error<T>> {
+        let grant = Self::grants(id).ok_or(Error::<T>::NoSuchGrant)?;
+        ensure!(grant.status == status, Error::<T>::IncorrectGrantStatus);
+        Ok(grant)
+    }
 }
 
 impl<T: Trait> TreasuryTrait<BalanceOf<T>> for Module<T> {
     #[inline]
-    fn disbursement(target: IdentityId, amount: BalanceOf<T>) {
-        Self::unsafe_disbursement(target, amount);
+    fn disbursement(target: IdentityId, amount: BalanceOf<T>) -> DispatchResult {
+        Self::unsafe_disbursement(target, amount)
     }
 
     #[inline]
     fn balance() -> BalanceOf<T> {
-        Self::balance()
+        Self::spendable_balance()
     }
 }
 
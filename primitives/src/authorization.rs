@@ -16,9 +16,12 @@
 use crate::{identity_id::IdentityId, signing_item::Signatory, Ticker};
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchError;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 /// Authorization data for two step prcoesses.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum AuthorizationData {
     /// CDD provider's attestation to change master key
     AttestMasterKeyRotation(IdentityId),
@@ -70,6 +73,7 @@ impl From<AuthorizationError> for DispatchError {
 
 /// Authorization struct
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Authorization<U> {
     /// Enum that contains authorization type and data
     pub authorization_data: AuthorizationData,
@@ -14,9 +14,10 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{identity_id::IdentityId, Moment};
-use polymesh_primitives_derive::VecU8StrongTyped;
+use polymesh_primitives_derive::{SliceU8StrongTyped, VecU8StrongTyped};
 
 use codec::{Decode, Encode};
+use sp_io::hashing::blake2_256;
 #[cfg(feature = "std")]
 use sp_runtime::{Deserialize, Serialize};
 use sp_std::prelude::*;
@@ -24,6 +25,71 @@ use sp_std::prelude::*;
 /// Scope: Almost all claim needs a valid scope identity.
 pub type Scope = IdentityId;
 
+/// Domain separator used to build a `CddId` from an `InvestorUid`.
+const CDD_ID_DOMAIN: &[u8] = b"POLYMESH_CDD_ID";
+
+/// Unique identifier of an investor, assigned off-chain by its CDD provider.
+/// It never goes on-chain in plain form, only through its `CddId` commitment.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Debug,
+    PartialOrd,
+    Ord,
+    Hash,
+    SliceU8StrongTyped,
+)]
+pub struct InvestorUid([u8; 16]);
+
+/// Hashed commitment to the `InvestorUid` of the person behind a CDD claim.
+///
+/// Every CDD provider derives the same `CddId` for the same investor, so the chain can ensure
+/// that one person is not attached to several identities. The `InvestorUid` is the opening of
+/// the commitment and it can be used later (i.e. by a regulator) to prove the link between an
+/// identity and a person.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Debug,
+    PartialOrd,
+    Ord,
+    Hash,
+    SliceU8StrongTyped,
+)]
+pub struct CddId([u8; 32]);
+
+impl CddId {
+    /// It creates the commitment of `investor_uid`.
+    pub fn new(investor_uid: InvestorUid) -> Self {
+        let mut data = CDD_ID_DOMAIN.to_vec();
+        data.extend_from_slice(investor_uid.as_slice());
+        CddId(blake2_256(&data))
+    }
+
+    /// It checks if `investor_uid` is the opening of this commitment.
+    pub fn is_opened_by(&self, investor_uid: InvestorUid) -> bool {
+        *self == Self::new(investor_uid)
+    }
+
+    /// Empty commitments are only used by systematic CDD claims.
+    #[inline]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// All possible claims in polymesh
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, PartialOrd, Ord)]
@@ -36,8 +102,9 @@ pub enum Claim {
     BuyLockup(Scope),
     /// User has an active SellLockup (date defined in claim expiry)
     SellLockup(Scope),
-    /// User has passed CDD
-    CustomerDueDiligence,
+    /// User has passed CDD. Claims issued before investor unique IDs keep this encoding and
+    /// carry no commitment. New claims use `CustomerDueDiligence`.
+    LegacyCustomerDueDiligence,
     /// User is KYC'd
     KnowYourCustomer(Scope),
     /// This claim contains a string that represents the jurisdiction of the user
@@ -48,6 +115,8 @@ pub enum Claim {
     BlackListed(Scope),
     /// Empty claim
     NoData,
+    /// User has passed CDD. It carries the commitment to the investor unique ID.
+    CustomerDueDiligence(CddId),
 }
 
 impl Default for Claim {
@@ -64,7 +133,9 @@ impl Claim {
             Claim::Affiliate(..) => ClaimType::Affiliate,
             Claim::BuyLockup(..) => ClaimType::BuyLockup,
             Claim::SellLockup(..) => ClaimType::SellLockup,
-            Claim::CustomerDueDiligence => ClaimType::CustomerDueDiligence,
+            Claim::LegacyCustomerDueDiligence | Claim::CustomerDueDiligence(..) => {
+                ClaimType::CustomerDueDiligence
+            }
             Claim::KnowYourCustomer(..) => ClaimType::KnowYourCustomer,
             Claim::Jurisdiction(..) => ClaimType::Jurisdiction,
             Claim::Whitelisted(..) => ClaimType::Whitelisted,
@@ -80,7 +151,7 @@ impl Claim {
             Claim::Affiliate(ref scope) => Some(scope),
             Claim::BuyLockup(ref scope) => Some(scope),
             Claim::SellLockup(ref scope) => Some(scope),
-            Claim::CustomerDueDiligence => None,
+            Claim::LegacyCustomerDueDiligence | Claim::CustomerDueDiligence(..) => None,
             Claim::KnowYourCustomer(ref scope) => Some(scope),
             Claim::Jurisdiction(.., ref scope) => Some(scope),
             Claim::Whitelisted(ref scope) => Some(scope),
@@ -88,6 +159,14 @@ impl Claim {
             Claim::NoData => None,
         }
     }
+
+    /// The CDD commitment of this claim, if it is a CDD claim. Legacy CDD claims have none.
+    pub fn as_cdd_id(&self) -> Option<&CddId> {
+        match self {
+            Claim::CustomerDueDiligence(ref cdd_id) => Some(cdd_id),
+            _ => None,
+        }
+    }
 }
 
 /// Claim type represent the claim without its data.
@@ -151,3 +230,49 @@ impl From<Claim> for IdentityClaim {
         }
     }
 }
+
+/// A claim that an identity must hold to meet a requirement: a claim of `claim_type` issued by
+/// `issuer` in `scope`.
+#[derive(Encode, Decode, Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct ClaimRequirement {
+    /// The type of the claim.
+    pub claim_type: ClaimType,
+    /// The issuer of the claim.
+    pub issuer: IdentityId,
+    /// The scope of the claim.
+    pub scope: Option<Scope>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CddId, Claim, ClaimType, InvestorUid};
+    use codec::{Decode, Encode};
+
+    #[test]
+    fn cdd_id_commitment_test() {
+        let uid = InvestorUid::from(b"investor_uid_001");
+        let other_uid = InvestorUid::from(b"investor_uid_002");
+        let cdd_id = CddId::new(uid);
+
+        assert_eq!(cdd_id, CddId::new(uid));
+        assert_ne!(cdd_id, CddId::new(other_uid));
+        assert_eq!(cdd_id.is_opened_by(uid), true);
+        assert_eq!(cdd_id.is_opened_by(other_uid), false);
+        assert_eq!(cdd_id.is_default(), false);
+        assert_eq!(CddId::default().is_default(), true);
+    }
+
+    #[test]
+    fn legacy_cdd_claim_encoding_test() {
+        // CDD claims stored before investor unique IDs were encoded as a bare variant index.
+        let legacy = Claim::decode(&mut &[4u8][..]).unwrap();
+        assert_eq!(legacy, Claim::LegacyCustomerDueDiligence);
+        assert_eq!(legacy.claim_type(), ClaimType::CustomerDueDiligence);
+        assert_eq!(legacy.as_cdd_id(), None);
+
+        let cdd_id = CddId::new(InvestorUid::from(b"investor_uid_001"));
+        let claim = Claim::CustomerDueDiligence(cdd_id);
+        assert_eq!(Claim::decode(&mut &claim.encode()[..]).unwrap(), claim);
+        assert_eq!(claim.claim_type(), ClaimType::CustomerDueDiligence);
+    }
+}
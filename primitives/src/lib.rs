@@ -107,7 +107,9 @@ pub use identity::Identity;
 /// Claim information.
 /// Each claim is associated with this kind of record.
 pub mod identity_claim;
-pub use identity_claim::{Claim, ClaimType, IdentityClaim, JurisdictionName, Scope};
+pub use identity_claim::{
+    CddId, Claim, ClaimRequirement, ClaimType, IdentityClaim, InvestorUid, JurisdictionName, Scope,
+};
 
 /// Key is strong type which stores bytes representing the key.
 pub mod account_key;
@@ -259,18 +259,21 @@ impl<'a> Predicate for AnyPredicate<'a> {
 mod tests {
     use crate::{
         predicate::{self, Context, Predicate},
-        Claim, Rule, RuleType, Scope,
+        CddId, Claim, Rule, RuleType, Scope,
     };
     use std::convert::From;
 
     #[test]
     fn existential_operators_test() {
         let scope = Scope::from(0);
-        let context = Context::from(vec![Claim::CustomerDueDiligence, Claim::Affiliate(scope)]);
+        let context = Context::from(vec![
+            Claim::CustomerDueDiligence(CddId::default()),
+            Claim::Affiliate(scope),
+        ]);
 
         // Affiliate && CustommerDueDiligenge
         let affiliate_claim = Claim::Affiliate(scope);
-        let cdd_claim = Claim::CustomerDueDiligence;
+        let cdd_claim = Claim::CustomerDueDiligence(CddId::default());
         let affiliate_and_cdd_pred =
             predicate::exists(&affiliate_claim).and(predicate::exists(&cdd_claim));
 
@@ -0,0 +1,101 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for bridge module.
+use codec::Codec;
+use polymesh_runtime_common::bridge::BridgeTxReport;
+use sp_core::H256;
+
+use sp_std::vec::Vec;
+
+/// This module contains some types which require transformations to avoid serde issues with
+/// `u128` type.
+pub mod capped {
+    use polymesh_runtime_common::bridge::{
+        BridgeTxReport as CoreBridgeTxReport, BridgeTxStatus, HandledTxStatus,
+    };
+
+    use codec::{Decode, Encode};
+    use sp_core::H256;
+    use sp_runtime::traits::{SaturatedConversion, UniqueSaturatedInto};
+
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
+
+    /// The full status of a bridge transaction.
+    #[derive(Eq, PartialEq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct BridgeTxReport<AccountId, BlockNumber> {
+        /// The bridge transaction nonce.
+        pub nonce: u32,
+        /// The recipient account of POLYX on Polymesh.
+        pub recipient: AccountId,
+        /// Amount of POLYX tokens to credit.
+        pub amount: u64,
+        /// Ethereum token lock transaction hash.
+        pub tx_hash: H256,
+        /// Status of the bridge transaction.
+        pub status: BridgeTxStatus,
+        /// Identifier of the proposal of the bridge controller multisig, if proposed.
+        pub proposal_id: Option<u64>,
+        /// Number of approvals of the proposal.
+        pub approvals: u64,
+        /// Block number at which the transaction was executed or is planned to be executed.
+        pub execution_block: BlockNumber,
+        /// Result of the last attempt to handle the transaction, if any.
+        pub handled: Option<HandledTxStatus>,
+    }
+
+    impl<AccountId, Balance, BlockNumber> From<CoreBridgeTxReport<AccountId, Balance, BlockNumber>>
+        for BridgeTxReport<AccountId, BlockNumber>
+    where
+        Balance: UniqueSaturatedInto<u64>,
+    {
+        fn from(report: CoreBridgeTxReport<AccountId, Balance, BlockNumber>) -> Self {
+            BridgeTxReport {
+                nonce: report.bridge_tx.nonce,
+                recipient: report.bridge_tx.recipient,
+                amount: report.bridge_tx.amount.saturated_into(),
+                tx_hash: report.bridge_tx.tx_hash,
+                status: report.status,
+                proposal_id: report.proposal_id,
+                approvals: report.approvals,
+                execution_block: report.execution_block,
+                handled: report.handled,
+            }
+        }
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// The API to query the status of bridge transactions.
+    pub trait BridgeApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Retrieves the status of the bridge transactions of `recipient`.
+        fn txs_by_recipient(recipient: AccountId) -> Vec<BridgeTxReport<AccountId, Balance, BlockNumber>>;
+
+        /// Retrieves the status of the bridge transactions of the Ethereum transaction `tx_hash`.
+        fn txs_by_tx_hash(tx_hash: H256) -> Vec<BridgeTxReport<AccountId, Balance, BlockNumber>>;
+
+        /// Retrieves up to `count` timelocked and pending bridge transactions, skipping the first
+        /// `start` of them, in execution order.
+        fn pending_txs(start: u32, count: u32) -> Vec<BridgeTxReport<AccountId, Balance, BlockNumber>>;
+    }
+}
@@ -0,0 +1,33 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for im-online module.
+use codec::Codec;
+use pallet_im_online::OfflineReport;
+use sp_staking::SessionIndex;
+
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// The API to query the liveness of validators.
+    pub trait ImOnlineApi<ValidatorId>
+    where
+        ValidatorId: Codec,
+    {
+        /// Retrieves the liveness of `validator` in each of the recent sessions in which it was a
+        /// validator.
+        fn offline_reports(validator: ValidatorId) -> Vec<(SessionIndex, OfflineReport)>;
+    }
+}
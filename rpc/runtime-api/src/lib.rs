@@ -17,4 +17,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod asset;
+pub mod bridge;
+pub mod im_online;
+pub mod multisig;
 pub mod pips;
+pub mod treasury;
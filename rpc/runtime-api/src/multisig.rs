@@ -0,0 +1,34 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for multisig module.
+use codec::Codec;
+use pallet_multisig::OpenProposal;
+
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with multisigs.
+    pub trait MultiSigApi<AccountId, Moment, Proposal>
+    where
+        AccountId: Codec,
+        Moment: Codec,
+        Proposal: Codec
+    {
+        /// Retrieves up to `count` open proposals of `multisig` from the proposal ID `start`,
+        /// with their calls and the signers that approved them.
+        fn get_open_proposals(multisig: AccountId, start: u64, count: u32) -> Vec<OpenProposal<Proposal, Moment>>;
+    }
+}
@@ -15,7 +15,7 @@
 
 //! Runtime API definition for pips module.
 use codec::Codec;
-use pallet_pips::{HistoricalVotingByAddress, HistoricalVotingById, Vote, VoteCount};
+use pallet_pips::{Delegation, HistoricalVotingByAddress, HistoricalVotingById, Vote, VoteCount};
 use polymesh_primitives::IdentityId;
 
 use sp_std::{prelude::*, vec::Vec};
@@ -24,7 +24,8 @@ use sp_std::{prelude::*, vec::Vec};
 /// `u128` type.
 /// For instance, `Balance` is capped (or expanded) to `u64` in `VoteCount`.
 pub mod capped {
-    use pallet_pips::{Vote as CoreVote, VoteCount as CoreVoteCount};
+    use pallet_pips::{Delegation as CoreDelegation, Vote as CoreVote, VoteCount as CoreVoteCount};
+    use polymesh_primitives::IdentityId;
 
     use codec::{Decode, Encode};
     use sp_runtime::traits::{SaturatedConversion, UniqueSaturatedInto};
@@ -42,6 +43,10 @@ pub mod capped {
             ayes: u64,
             /// Stake against
             nays: u64,
+            /// Voting weight for
+            ayes_weight: u64,
+            /// Voting weight against
+            nays_weight: u64,
         },
         /// Proposal was not for given index.
         ProposalNotFound,
@@ -53,9 +58,16 @@ pub mod capped {
     {
         fn from(vote_count: CoreVoteCount<Balance>) -> Self {
             match vote_count {
-                CoreVoteCount::ProposalFound { ayes, nays } => VoteCount::ProposalFound {
+                CoreVoteCount::ProposalFound {
+                    ayes,
+                    nays,
+                    ayes_weight,
+                    nays_weight,
+                } => VoteCount::ProposalFound {
                     ayes: ayes.saturated_into(),
                     nays: nays.saturated_into(),
+                    ayes_weight: ayes_weight.saturated_into(),
+                    nays_weight: nays_weight.saturated_into(),
                 },
                 CoreVoteCount::ProposalNotFound => VoteCount::ProposalNotFound,
             }
@@ -83,6 +95,50 @@ pub mod capped {
             }
         }
     }
+
+    #[derive(Eq, PartialEq)]
+    #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct Delegation {
+        /// The identity that votes on behalf of the delegator.
+        pub delegate: IdentityId,
+        /// The delegated amount.
+        pub amount: u64,
+    }
+
+    impl<Balance> From<CoreDelegation<Balance>> for Delegation
+    where
+        Balance: UniqueSaturatedInto<u64>,
+    {
+        fn from(delegation: CoreDelegation<Balance>) -> Self {
+            Delegation {
+                delegate: delegation.delegate,
+                amount: delegation.amount.saturated_into(),
+            }
+        }
+    }
+
+    #[derive(Eq, PartialEq)]
+    #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct Delegator<AccountId> {
+        /// The account that delegated its voting power.
+        pub account: AccountId,
+        /// The delegated amount.
+        pub amount: u64,
+    }
+
+    impl<AccountId, Balance> From<(AccountId, Balance)> for Delegator<AccountId>
+    where
+        Balance: UniqueSaturatedInto<u64>,
+    {
+        fn from((account, amount): (AccountId, Balance)) -> Self {
+            Delegator {
+                account,
+                amount: amount.saturated_into(),
+            }
+        }
+    }
 }
 
 sp_api::decl_runtime_apis! {
@@ -106,6 +162,13 @@ sp_api::decl_runtime_apis! {
 
         /// Retrieve referendums voted on information by `id` identity (and its signing items).
         fn voting_history_by_id(id: IdentityId) -> HistoricalVotingById<Vote<Balance>>;
+
+        /// Retrieve the voting power delegated by `address`.
+        fn delegation(address: AccountId) -> Option<Delegation<Balance>>;
+
+        /// Retrieve the accounts that delegated their voting power to `delegate` and the
+        /// delegated amounts.
+        fn delegators(delegate: IdentityId) -> Vec<(AccountId, Balance)>;
     }
 }
 
@@ -118,11 +181,13 @@ mod tests {
         let votes = VoteCount::ProposalFound {
             ayes: 3141u64,
             nays: 5926u64,
+            ayes_weight: 56u64,
+            nays_weight: 76u64,
         };
 
         assert_eq!(
             serde_json::to_string(&votes).unwrap(),
-            r#"{"ProposalFound":{"ayes":3141,"nays":5926}}"#,
+            r#"{"ProposalFound":{"ayes":3141,"nays":5926,"ayes_weight":56,"nays_weight":76}}"#,
         );
 
         // should not panic
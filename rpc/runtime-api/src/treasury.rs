@@ -0,0 +1,89 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for treasury module.
+use codec::Codec;
+use pallet_treasury::{BudgetPeriodIndex, SpendReport};
+
+use sp_std::vec::Vec;
+
+/// This module contains some types which require transformations to avoid serde issues with
+/// `u128` type.
+pub mod capped {
+    use pallet_treasury::{BudgetPeriodIndex, SpendReport as CoreSpendReport};
+    use polymesh_primitives::IdentityId;
+
+    use codec::{Decode, Encode};
+    use sp_runtime::traits::{SaturatedConversion, UniqueSaturatedInto};
+    use sp_std::vec::Vec;
+
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
+
+    /// The amount disbursed to a beneficiary.
+    #[derive(Eq, PartialEq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct BeneficiarySpending {
+        /// The beneficiary identity.
+        pub id: IdentityId,
+        /// The disbursed amount.
+        pub amount: u64,
+    }
+
+    /// The spending of a budget period.
+    #[derive(Eq, PartialEq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct SpendReport {
+        /// The budget period.
+        pub period: BudgetPeriodIndex,
+        /// The total amount disbursed during the period.
+        pub total: u64,
+        /// The amount disbursed to each beneficiary during the period.
+        pub beneficiaries: Vec<BeneficiarySpending>,
+    }
+
+    impl<Balance> From<CoreSpendReport<Balance>> for SpendReport
+    where
+        Balance: UniqueSaturatedInto<u64>,
+    {
+        fn from(report: CoreSpendReport<Balance>) -> Self {
+            SpendReport {
+                period: report.period,
+                total: report.total.saturated_into(),
+                beneficiaries: report
+                    .beneficiaries
+                    .into_iter()
+                    .map(|(id, amount)| BeneficiarySpending {
+                        id,
+                        amount: amount.saturated_into(),
+                    })
+                    .collect(),
+            }
+        }
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with the treasury.
+    pub trait TreasuryApi<Balance>
+    where
+        Balance: Codec
+    {
+        /// Retrieves the spending of the budget periods from `from` to `to`, both included.
+        fn spend_report(from: BudgetPeriodIndex, to: BudgetPeriodIndex) -> Vec<SpendReport<Balance>>;
+    }
+}
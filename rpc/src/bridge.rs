@@ -0,0 +1,137 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+pub use node_rpc_runtime_api::bridge::{
+    self as runtime_api, capped::BridgeTxReport, BridgeApi as BridgeRuntimeApi,
+};
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+
+use codec::Codec;
+use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, UniqueSaturatedInto},
+};
+
+use std::sync::Arc;
+
+/// Bridge RPC methods.
+#[rpc]
+pub trait BridgeApi<BlockHash, AccountId, Balance, BlockNumber> {
+    /// Retrieves the full status of the bridge transactions of `recipient`.
+    #[rpc(name = "bridge_txsByRecipient")]
+    fn txs_by_recipient(
+        &self,
+        recipient: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<BridgeTxReport<AccountId, BlockNumber>>>;
+
+    /// Retrieves the full status of the bridge transactions of the Ethereum transaction
+    /// `tx_hash`.
+    #[rpc(name = "bridge_txsByTxHash")]
+    fn txs_by_tx_hash(
+        &self,
+        tx_hash: H256,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<BridgeTxReport<AccountId, BlockNumber>>>;
+
+    /// Retrieves up to `count` timelocked and pending bridge transactions, skipping the first
+    /// `start` of them, in execution order.
+    #[rpc(name = "bridge_pendingTxs")]
+    fn pending_txs(
+        &self,
+        start: u32,
+        count: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<BridgeTxReport<AccountId, BlockNumber>>>;
+}
+
+/// An implementation of bridge specific RPC methods.
+pub struct Bridge<T, U> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> Bridge<T, U> {
+    /// Create new `Bridge` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+    BridgeApi<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber> for Bridge<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec,
+    Balance: Codec + UniqueSaturatedInto<u64>,
+    BlockNumber: Codec,
+{
+    fn txs_by_recipient(
+        &self,
+        recipient: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<BridgeTxReport<AccountId, BlockNumber>>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .txs_by_recipient(at, recipient),
+            "Unable to query `txs_by_recipient`."
+        )
+        .map(|reports| reports.into_iter().map(BridgeTxReport::from).collect())
+    }
+
+    fn txs_by_tx_hash(
+        &self,
+        tx_hash: H256,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<BridgeTxReport<AccountId, BlockNumber>>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.txs_by_tx_hash(at, tx_hash),
+            "Unable to query `txs_by_tx_hash`."
+        )
+        .map(|reports| reports.into_iter().map(BridgeTxReport::from).collect())
+    }
+
+    fn pending_txs(
+        &self,
+        start: u32,
+        count: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<BridgeTxReport<AccountId, BlockNumber>>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .pending_txs(at, start, count),
+            "Unable to query `pending_txs`."
+        )
+        .map(|reports| reports.into_iter().map(BridgeTxReport::from).collect())
+    }
+}
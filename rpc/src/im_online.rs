@@ -0,0 +1,81 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+pub use node_rpc_runtime_api::im_online::ImOnlineApi as ImOnlineRuntimeApi;
+use pallet_im_online::OfflineReport;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+
+use codec::Codec;
+use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_staking::SessionIndex;
+
+use std::sync::Arc;
+
+/// ImOnline RPC methods.
+#[rpc]
+pub trait ImOnlineApi<BlockHash, ValidatorId> {
+    /// Retrieves the heartbeats received and blocks authored by `validator` in each of the recent
+    /// sessions in which it was a validator.
+    #[rpc(name = "imOnline_offlineReports")]
+    fn offline_reports(
+        &self,
+        validator: ValidatorId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(SessionIndex, OfflineReport)>>;
+}
+
+/// An implementation of im-online specific RPC methods.
+pub struct ImOnline<T, U> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> ImOnline<T, U> {
+    /// Create new `ImOnline` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, ValidatorId> ImOnlineApi<<Block as BlockT>::Hash, ValidatorId> for ImOnline<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: ImOnlineRuntimeApi<Block, ValidatorId>,
+    ValidatorId: Codec,
+{
+    fn offline_reports(
+        &self,
+        validator: ValidatorId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(SessionIndex, OfflineReport)>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .offline_reports(at, validator),
+            "Unable to query `offline_reports`."
+        )
+    }
+}
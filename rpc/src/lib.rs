@@ -39,4 +39,8 @@ macro_rules! rpc_forward_call {
 }
 
 pub mod asset;
+pub mod bridge;
+pub mod im_online;
+pub mod multisig;
 pub mod pips;
+pub mod treasury;
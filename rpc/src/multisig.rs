@@ -0,0 +1,101 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+pub use node_rpc_runtime_api::multisig::MultiSigApi as MultiSigRuntimeApi;
+use pallet_multisig::OpenProposal;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+
+use codec::{Codec, Encode};
+use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use std::sync::Arc;
+
+/// MultiSig RPC methods.
+#[rpc]
+pub trait MultiSigApi<BlockHash, AccountId, Moment> {
+    /// Retrieves up to `count` open proposals of `multisig` from the proposal ID `start`. The
+    /// call of each proposal is returned SCALE encoded.
+    #[rpc(name = "multiSig_getOpenProposals")]
+    fn get_open_proposals(
+        &self,
+        multisig: AccountId,
+        start: u64,
+        count: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<OpenProposal<Bytes, Moment>>>;
+}
+
+/// An implementation of multisig specific RPC methods.
+pub struct MultiSig<T, U, P> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<(U, P)>,
+}
+
+impl<T, U, P> MultiSig<T, U, P> {
+    /// Create new `MultiSig` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Moment, Proposal> MultiSigApi<<Block as BlockT>::Hash, AccountId, Moment>
+    for MultiSig<C, Block, Proposal>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: MultiSigRuntimeApi<Block, AccountId, Moment, Proposal>,
+    AccountId: Codec,
+    Moment: Codec,
+    Proposal: Codec + Send + Sync + 'static,
+{
+    fn get_open_proposals(
+        &self,
+        multisig: AccountId,
+        start: u64,
+        count: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<OpenProposal<Bytes, Moment>>> {
+        let open_proposals: Result<Vec<OpenProposal<Proposal, Moment>>> = rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .get_open_proposals(at, multisig, start, count),
+            "Unable to query `get_open_proposals`."
+        );
+        Ok(open_proposals?
+            .into_iter()
+            .map(|open| OpenProposal {
+                id: open.id,
+                proposal: open.proposal.encode().into(),
+                details: open.details,
+                creator: open.creator,
+                approvers: open.approvers,
+                approvals: open.approvals,
+                rejections: open.rejections,
+                expiry: open.expiry,
+            })
+            .collect())
+    }
+}
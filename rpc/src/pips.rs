@@ -15,7 +15,7 @@
 
 pub use node_rpc_runtime_api::pips::{
     self as runtime_api,
-    capped::{Vote, VoteCount},
+    capped::{Delegation, Delegator, Vote, VoteCount},
     PipsApi as PipsRuntimeApi,
 };
 use pallet_pips::{HistoricalVotingByAddress, HistoricalVotingById, VoteByPip};
@@ -65,6 +65,18 @@ pub trait PipsApi<BlockHash, AccountId, Balance> {
         id: IdentityId,
         at: Option<BlockHash>,
     ) -> Result<HistoricalVotingById<Vote>>;
+
+    /// Retrieve the voting power delegated by `address`.
+    #[rpc(name = "pips_delegation")]
+    fn delegation(&self, address: AccountId, at: Option<BlockHash>) -> Result<Option<Delegation>>;
+
+    /// Retrieve the accounts that delegated their voting power to `delegate`.
+    #[rpc(name = "pips_delegators")]
+    fn delegators(
+        &self,
+        delegate: IdentityId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Delegator<AccountId>>>;
 }
 
 /// An implementation of pips specific RPC methods.
@@ -184,4 +196,32 @@ where
 
         Ok(history)
     }
+
+    fn delegation(
+        &self,
+        address: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<Delegation>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.delegation(at, address),
+            "Unable to query `delegation`."
+        )
+        .map(|delegation| delegation.map(Delegation::from))
+    }
+
+    fn delegators(
+        &self,
+        delegate: IdentityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Delegator<AccountId>>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.delegators(at, delegate),
+            "Unable to query `delegators`."
+        )
+        .map(|delegators| delegators.into_iter().map(Delegator::from).collect())
+    }
 }
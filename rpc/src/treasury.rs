@@ -0,0 +1,87 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymathNetwork/Polymesh).
+// Copyright (c) 2020 Polymath
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+pub use node_rpc_runtime_api::treasury::{
+    self as runtime_api, capped::SpendReport, TreasuryApi as TreasuryRuntimeApi,
+};
+use pallet_treasury::BudgetPeriodIndex;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+
+use codec::Codec;
+use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, UniqueSaturatedInto},
+};
+
+use std::sync::Arc;
+
+/// Treasury RPC methods.
+#[rpc]
+pub trait TreasuryApi<BlockHash, Balance> {
+    /// Summarises the spending per beneficiary of the budget periods from `from` to `to`, both
+    /// included. Periods without spending are skipped.
+    #[rpc(name = "treasury_spendReport")]
+    fn spend_report(
+        &self,
+        from: BudgetPeriodIndex,
+        to: BudgetPeriodIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<SpendReport>>;
+}
+
+/// An implementation of treasury specific RPC methods.
+pub struct Treasury<T, U> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> Treasury<T, U> {
+    /// Create new `Treasury` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, Balance> TreasuryApi<<Block as BlockT>::Hash, Balance> for Treasury<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: TreasuryRuntimeApi<Block, Balance>,
+    Balance: Codec + UniqueSaturatedInto<u64>,
+{
+    fn spend_report(
+        &self,
+        from: BudgetPeriodIndex,
+        to: BudgetPeriodIndex,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<SpendReport>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.spend_report(at, from, to),
+            "Unable to query `spend_report`."
+        )
+        .map(|reports| reports.into_iter().map(SpendReport::from).collect())
+    }
+}
@@ -322,12 +322,12 @@ for (let i = 0; i < accounts.length; i++) {
     let expiry = expiries.length == 0 ? null : expiries[i];
     if(fast) {
     await api.tx.identity
-      .cddRegisterDid(accounts[i].address, expiry, [])
+      .cddRegisterDid(accounts[i].address, reqImports.cddIdOf(accounts[i]), expiry, [])
       .signAndSend(alice, { nonce: reqImports.nonces.get(alice.address) });
     }
     else {
       let nonceObj = {nonce: reqImports.nonces.get(alice.address)};
-        const transaction = api.tx.identity.cddRegisterDid(accounts[i].address, null, []);
+        const transaction = api.tx.identity.cddRegisterDid(accounts[i].address, reqImports.cddIdOf(accounts[i]), null, []);
         const result = await reqImports.sendTransaction(transaction, alice, nonceObj);  
         const passed = result.findRecord('system', 'ExtrinsicSuccess');
         if (!passed) {
@@ -35,7 +35,7 @@ async function createIdentities(api, accounts, alice) {
     let dids = [];
       for (let i = 0; i < accounts.length; i++) {
         let nonceObj = {nonce: reqImports.nonces.get(alice.address)};
-        const transaction = api.tx.identity.cddRegisterDid(accounts[i].address, null, []);
+        const transaction = api.tx.identity.cddRegisterDid(accounts[i].address, reqImports.cddIdOf(accounts[i]), null, []);
         const result = await reqImports.sendTransaction(transaction, alice, nonceObj);  
         const passed = result.findRecord('system', 'ExtrinsicSuccess');
         if (passed) reqImports.fail_count--;
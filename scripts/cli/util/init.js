@@ -142,6 +142,11 @@ const blockTillPoolEmpty = async function (api) {
   }
 };
 
+// Builds the CDD commitment of `account`, using its public key as investor unique ID.
+const cddIdOf = function(account) {
+  return blake2AsHex(u8aConcat(stringToU8a("POLYMESH_CDD_ID"), u8aFixLength(account.publicKey, 128)));
+};
+
 // Create a new DID for each of accounts[]
 // precondition - accounts all have enough POLY
 const createIdentities = async function(api, accounts, alice) {
@@ -153,12 +158,12 @@ const createIdentitiesWithExpiry = async function(api, accounts, alice, expiries
 
   for (let i = 0; i < accounts.length; i++) {
     // let nonceObj = {nonce: nonces.get(alice.address)};
-    // const transaction = api.tx.identity.cddRegisterDid(accounts[i].address, null, []);
+    // const transaction = api.tx.identity.cddRegisterDid(accounts[i].address, cddIdOf(accounts[i]), null, []);
     // await sendTransaction(transaction, alice, nonceObj);
 
       let expiry = expiries.length == 0 ? null : expiries[i];
       await api.tx.identity
-        .cddRegisterDid(accounts[i].address, expiry, [])
+        .cddRegisterDid(accounts[i].address, cddIdOf(accounts[i]), expiry, [])
         .signAndSend(alice, { nonce: nonces.get(alice.address) });
 
     nonces.set(alice.address, nonces.get(alice.address).addn(1));
@@ -416,6 +421,7 @@ let reqImports = {
   ticker,
   createApi,
   createIdentities,
+  cddIdOf,
   initMain,
   blockTillPoolEmpty,
   generateKeys,
@@ -5,7 +5,9 @@ use polymesh_common_utilities::{
     constants::currency::{MILLICENTS, POLY},
     protocol_fee::ProtocolOp,
 };
-use polymesh_primitives::{AccountId, AccountKey, IdentityId, PosRatio, Signatory, Signature};
+use polymesh_primitives::{
+    AccountId, AccountKey, IdentityId, InvestorUid, PosRatio, Signatory, Signature,
+};
 use std::convert::TryFrom;
 
 use polymesh_runtime_develop::{self as general, constants::time as GeneralTime};
@@ -18,7 +20,7 @@ use sc_service::Properties;
 use serde_json::json;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_babe::AuthorityId as BabeId;
-use sp_core::{sr25519, Pair, Public};
+use sp_core::{hashing::blake2_128, sr25519, Pair, Public};
 use sp_runtime::{
     traits::{IdentifyAccount, Verify},
     PerThing,
@@ -88,6 +90,12 @@ where
     AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
+/// Helper function to generate an investor unique ID from seed.
+/// Development seeds are public, so these IDs must not be used on a production chain.
+pub fn get_investor_uid_from_seed(seed: &str) -> InvestorUid {
+    InvestorUid::from(blake2_128(format!("//{}", seed).as_bytes()))
+}
+
 /// Helper function to generate stash, controller and session key from seed
 pub fn get_authority_keys_from_seed(
     seed: &str,
@@ -157,18 +165,20 @@ fn general_testnet_genesis(
         }),
         identity: {
             let initial_identities = vec![
-                // (master_account_id, service provider did, target did, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
+                // (master_account_id, service provider did, target did, investor uid, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
                 // Service providers
                 (
                     get_account_id_from_seed::<sr25519::Public>("cdd_provider_1"),
                     IdentityId::from(1),
                     IdentityId::from(1),
+                    get_investor_uid_from_seed("cdd_provider_1"),
                     None,
                 ),
                 (
                     get_account_id_from_seed::<sr25519::Public>("cdd_provider_2"),
                     IdentityId::from(2),
                     IdentityId::from(2),
+                    get_investor_uid_from_seed("cdd_provider_2"),
                     None,
                 ),
                 // Governance committee members
@@ -176,18 +186,21 @@ fn general_testnet_genesis(
                     get_account_id_from_seed::<sr25519::Public>("governance_committee_1"),
                     IdentityId::from(1),
                     IdentityId::from(3),
+                    get_investor_uid_from_seed("governance_committee_1"),
                     None,
                 ),
                 (
                     get_account_id_from_seed::<sr25519::Public>("governance_committee_2"),
                     IdentityId::from(1),
                     IdentityId::from(4),
+                    get_investor_uid_from_seed("governance_committee_2"),
                     None,
                 ),
                 (
                     get_account_id_from_seed::<sr25519::Public>("governance_committee_3"),
                     IdentityId::from(1),
                     IdentityId::from(5),
+                    get_investor_uid_from_seed("governance_committee_3"),
                     None,
                 ),
             ];
@@ -200,6 +213,7 @@ fn general_testnet_genesis(
                         x.1.clone(),
                         IdentityId::from(1),
                         IdentityId::from(identity_counter),
+                        get_investor_uid_from_seed(&format!("authority_{}", identity_counter)),
                         None,
                     )
                 })
@@ -287,6 +301,8 @@ fn general_testnet_genesis(
             proposal_duration: GeneralTime::MINUTES * 1,
             proposal_cool_off_period: GeneralTime::MINUTES * 1,
             default_enactment_period: GeneralTime::MINUTES * 1,
+            conviction_lock_period: GeneralTime::MINUTES * 1,
+            technical_review_period: GeneralTime::MINUTES * 1,
         }),
         pallet_im_online: Some(V1Config::ImOnlineConfig {
             slashing_params: general::OfflineSlashingParams {
@@ -320,6 +336,15 @@ fn general_testnet_genesis(
             members: vec![],
             phantom: Default::default(),
         }),
+        group_Instance3: Some(v1::runtime::TechnicalCommitteeMembershipConfig {
+            active_members: vec![IdentityId::from(3), IdentityId::from(4)],
+            phantom: Default::default(),
+        }),
+        committee_Instance2: Some(v1::runtime::TechnicalCommitteeConfig {
+            vote_threshold: (1, 2),
+            members: vec![],
+            phantom: Default::default(),
+        }),
         group_Instance2: Some(v1::runtime::CddServiceProvidersConfig {
             // sp1, sp2, first authority
             active_members: vec![
@@ -333,6 +358,14 @@ fn general_testnet_genesis(
             base_fees: vec![
                 (ProtocolOp::AssetCreateAsset, 10_000 * 1_000_000),
                 (ProtocolOp::AssetRegisterTicker, 2_500 * 1_000_000),
+                (ProtocolOp::AssetAddExtension, 250 * 1_000_000),
+                (ProtocolOp::StoCappedLaunchSto, 1_000 * 1_000_000),
+                (ProtocolOp::BridgeProposeBridgeTx, 1_000_000),
+                (ProtocolOp::MultiSigCreateMultiSig, 500 * 1_000_000),
+                (
+                    ProtocolOp::ComplianceManagerAddDefaultTrustedClaimIssuer,
+                    50 * 1_000_000,
+                ),
             ],
             coefficient: PosRatio(1, 1),
         }),
@@ -562,24 +595,27 @@ fn v1_testnet_genesis(
         }),
         identity: {
             let initial_identities = vec![
-                // (master_account_id, service provider did, target did, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
+                // (master_account_id, service provider did, target did, investor uid, expiry time of CustomerDueDiligence claim i.e 10 days is ms)
                 // Service providers
                 (
                     get_account_id_from_seed::<sr25519::Public>("cdd_provider_1"),
                     IdentityId::from(1),
                     IdentityId::from(1),
+                    get_investor_uid_from_seed("cdd_provider_1"),
                     None,
                 ),
                 (
                     get_account_id_from_seed::<sr25519::Public>("cdd_provider_2"),
                     IdentityId::from(2),
                     IdentityId::from(2),
+                    get_investor_uid_from_seed("cdd_provider_2"),
                     None,
                 ),
                 (
                     get_account_id_from_seed::<sr25519::Public>("cdd_provider_3"),
                     IdentityId::from(3),
                     IdentityId::from(3),
+                    get_investor_uid_from_seed("cdd_provider_3"),
                     None,
                 ),
                 // Governance committee members
@@ -587,18 +623,21 @@ fn v1_testnet_genesis(
                     get_account_id_from_seed::<sr25519::Public>("polymath_1"),
                     IdentityId::from(1),
                     IdentityId::from(4),
+                    get_investor_uid_from_seed("polymath_1"),
                     None,
                 ),
                 (
                     get_account_id_from_seed::<sr25519::Public>("polymath_2"),
                     IdentityId::from(2),
                     IdentityId::from(5),
+                    get_investor_uid_from_seed("polymath_2"),
                     None,
                 ),
                 (
                     get_account_id_from_seed::<sr25519::Public>("polymath_3"),
                     IdentityId::from(3),
                     IdentityId::from(6),
+                    get_investor_uid_from_seed("polymath_3"),
                     None,
                 ),
             ];
@@ -611,6 +650,7 @@ fn v1_testnet_genesis(
                         x.1.clone(),
                         IdentityId::from(1),
                         IdentityId::from(identity_counter),
+                        get_investor_uid_from_seed(&format!("authority_{}", identity_counter)),
                         None,
                     )
                 })
@@ -707,6 +747,8 @@ fn v1_testnet_genesis(
             proposal_duration: V1Time::DAYS * 7,
             proposal_cool_off_period: V1Time::HOURS * 6,
             default_enactment_period: V1Time::DAYS * 7,
+            conviction_lock_period: V1Time::DAYS * 7,
+            technical_review_period: V1Time::DAYS * 7,
         }),
         pallet_im_online: Some(V1Config::ImOnlineConfig {
             slashing_params: v1::OfflineSlashingParams {
@@ -739,6 +781,15 @@ fn v1_testnet_genesis(
             members: vec![],
             phantom: Default::default(),
         }),
+        group_Instance3: Some(v1::runtime::TechnicalCommitteeMembershipConfig {
+            active_members: vec![IdentityId::from(4), IdentityId::from(5)],
+            phantom: Default::default(),
+        }),
+        committee_Instance2: Some(v1::runtime::TechnicalCommitteeConfig {
+            vote_threshold: (2, 3),
+            members: vec![],
+            phantom: Default::default(),
+        }),
         group_Instance2: Some(v1::runtime::CddServiceProvidersConfig {
             // sp1, sp2, sp3
             active_members: vec![
@@ -752,6 +803,14 @@ fn v1_testnet_genesis(
             base_fees: vec![
                 (ProtocolOp::AssetCreateAsset, 10_000 * 1_000_000),
                 (ProtocolOp::AssetRegisterTicker, 2_500 * 1_000_000),
+                (ProtocolOp::AssetAddExtension, 250 * 1_000_000),
+                (ProtocolOp::StoCappedLaunchSto, 1_000 * 1_000_000),
+                (ProtocolOp::BridgeProposeBridgeTx, 1_000_000),
+                (ProtocolOp::MultiSigCreateMultiSig, 500 * 1_000_000),
+                (
+                    ProtocolOp::ComplianceManagerAddDefaultTrustedClaimIssuer,
+                    50 * 1_000_000,
+                ),
             ],
             coefficient: PosRatio(1, 1),
         }),
@@ -50,7 +50,7 @@ native_executor_instance!(
 );
 
 /// A set of APIs that polkadot-like runtimes must implement.
-pub trait RuntimeApiCollection<Extrinsic: codec::Codec + Send + Sync + 'static>:
+pub trait RuntimeApiCollection<Extrinsic: RuntimeExtrinsic>:
     sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
     + sp_api::ApiExt<Block, Error = sp_blockchain::Error>
     + sp_consensus_babe::BabeApi<Block>
@@ -74,6 +74,10 @@ pub trait RuntimeApiCollection<Extrinsic: codec::Codec + Send + Sync + 'static>:
         Moment,
     > + pallet_protocol_fee_rpc_runtime_api::ProtocolFeeApi<Block>
     + node_rpc_runtime_api::asset::AssetApi<Block, AccountId, Balance>
+    + node_rpc_runtime_api::multisig::MultiSigApi<Block, AccountId, Moment, Extrinsic::Call>
+    + node_rpc_runtime_api::treasury::TreasuryApi<Block, Balance>
+    + node_rpc_runtime_api::bridge::BridgeApi<Block, AccountId, Balance, BlockNumber>
+    + node_rpc_runtime_api::im_online::ImOnlineApi<Block, AccountId>
     + pallet_group_rpc_runtime_api::GroupApi<Block>
     + pallet_compliance_manager_rpc_runtime_api::ComplianceManagerApi<Block, AccountId, Balance>
 where
@@ -107,6 +111,10 @@ where
             Moment,
         > + pallet_protocol_fee_rpc_runtime_api::ProtocolFeeApi<Block>
         + node_rpc_runtime_api::asset::AssetApi<Block, AccountId, Balance>
+        + node_rpc_runtime_api::multisig::MultiSigApi<Block, AccountId, Moment, Extrinsic::Call>
+        + node_rpc_runtime_api::treasury::TreasuryApi<Block, Balance>
+        + node_rpc_runtime_api::bridge::BridgeApi<Block, AccountId, Balance, BlockNumber>
+        + node_rpc_runtime_api::im_online::ImOnlineApi<Block, AccountId>
         + pallet_group_rpc_runtime_api::GroupApi<Block>
         + pallet_compliance_manager_rpc_runtime_api::ComplianceManagerApi<Block, AccountId, Balance>,
     Extrinsic: RuntimeExtrinsic,
@@ -114,9 +122,18 @@ where
 {
 }
 
-pub trait RuntimeExtrinsic: codec::Codec + Send + Sync + 'static {}
+pub trait RuntimeExtrinsic: codec::Codec + Send + Sync + 'static {
+    /// The runtime call of the extrinsic.
+    type Call: codec::Codec + std::fmt::Debug + Send + Sync + 'static;
+}
 
-impl<E> RuntimeExtrinsic for E where E: codec::Codec + Send + Sync + 'static {}
+impl<E> RuntimeExtrinsic for E
+where
+    E: codec::Codec + sp_runtime::traits::Extrinsic + Send + Sync + 'static,
+    E::Call: codec::Codec + std::fmt::Debug + Send + Sync + 'static,
+{
+    type Call = E::Call;
+}
 
 // Using prometheus, use a registry with a prefix of `polymesh`.
 fn set_prometheus_registry(config: &mut Configuration) -> Result<(), ServiceError> {
@@ -185,7 +202,11 @@ macro_rules! new_full_start {
             use contracts_rpc::{Contracts, ContractsApi};
             use node_rpc::{
                 asset::{Asset, AssetApi},
+                bridge::{Bridge, BridgeApi},
+                im_online::{ImOnline, ImOnlineApi},
+                multisig::{MultiSig, MultiSigApi},
                 pips::{Pips, PipsApi},
+                treasury::{Treasury, TreasuryApi},
             };
             use pallet_compliance_manager_rpc::{ComplianceManager, ComplianceManagerApi};
             use pallet_group_rpc::{Group, GroupApi};
@@ -213,6 +234,22 @@ macro_rules! new_full_start {
                 builder.client().clone(),
             )));
             io.extend_with(AssetApi::to_delegate(Asset::new(builder.client().clone())));
+            io.extend_with(MultiSigApi::to_delegate(MultiSig::<
+                _,
+                _,
+                <Extrinsic as RuntimeExtrinsic>::Call,
+            >::new(
+                builder.client().clone()
+            )));
+            io.extend_with(TreasuryApi::to_delegate(Treasury::new(
+                builder.client().clone(),
+            )));
+            io.extend_with(BridgeApi::to_delegate(Bridge::new(
+                builder.client().clone(),
+            )));
+            io.extend_with(ImOnlineApi::to_delegate(ImOnline::new(
+                builder.client().clone(),
+            )));
             io.extend_with(GroupApi::to_delegate(Group::from(builder.client().clone())));
             io.extend_with(ComplianceManagerApi::to_delegate(ComplianceManager::new(
                 builder.client().clone(),